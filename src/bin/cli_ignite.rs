@@ -3,28 +3,60 @@
 //! Command-line interface for Ignition authority chain management.
 //! Uses RSB bootstrap/dispatch pattern per docs/ref/rsb/CLI_RSB_USAGE.md
 
-use ignite::ignite::authority::{AuthorityKey, KeyFormat, KeyMaterial, KeyMetadata, KeyType};
-use ignite::ignite::security::policy::PolicyEngine;
+use ignite::ignite::authority::approvals::{self, ApprovalAction, OperationRequest};
+use ignite::ignite::authority::{
+    sealing, AuthorityChain, AuthorityKey, KeyFingerprint, KeyMaterial, KeyMetadata, KeyType,
+};
+use ignite::ignite::correlation;
+use ignite::ignite::logging::{self, AuditOutcome};
+use ignite::ignite::security::danger::{self, DangerGuard, DangerousOperation};
+use ignite::ignite::security::passphrase::{PassphraseProvider, PassphraseRequest};
+use ignite::ignite::security::policy::{PolicyEngine, PolicyReport};
 use ignite::IgniteResult;
 use rsb::prelude::*;
 
 fn main() {
     let args = bootstrap!();
     options!(&args);
+    let _operation = correlation::begin_from_env();
+    sealing::use_vault_passphrases(vault_passphrase_provider());
 
     dispatch!(&args, {
         "create" => create_command, desc: "Create a new authority key",
         "list" => list_command, desc: "List authority keys",
         "ls" => list_command, desc: "Alias for list",
         "status" => status_command, desc: "Show authority chain status",
-        "verify" => verify_command, desc: "Verify a proof or manifest file"
+        "rotate" => rotate_command, desc: "Rotate a key and invalidate its descendants",
+        "revoke" => revoke_command, desc: "Revoke a key and tombstone its fingerprint",
+        "verify" => verify_command, desc: "Verify a proof or manifest file",
+        "verify-chain" => verify_chain_command, desc: "Verify a key's lineage up to the Skull",
+        "migrate" => migrate_command, desc: "Backfill parent links and seal legacy plaintext keys",
+        "proof" => proof_command, desc: "Manage authority proofs (renew, purge)",
+        "passphrase" => passphrase_command, desc: "Generate passphrases and build breach filters",
+        "policy" => policy_command, desc: "Show, check or explain the effective policy",
+        "approval" => approval_command, desc: "Request and sign M-of-N approvals for Skull/Master operations",
+        "skull" => skull_command, desc: "Split the Skull key into recovery shares or recombine them",
+        "audit" => audit_command, desc: "Verify or search the hash-chained audit log"
     });
 }
 
 fn create_command(args: Args) -> i32 {
     match handle_create(&args) {
-        Ok(()) => 0,
+        Ok(fingerprint) => {
+            match logging::record("create", &[&fingerprint], AuditOutcome::Success, "") {
+                Ok(()) => 0,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    1
+                }
+            }
+        }
         Err(e) => {
+            if let Err(audit) =
+                logging::record("create", &[], AuditOutcome::Failure, &e.to_string())
+            {
+                eprintln!("Error: {}", audit);
+            }
             eprintln!("Error: {}", e);
             1
         }
@@ -61,17 +93,117 @@ fn verify_command(args: Args) -> i32 {
     }
 }
 
-fn handle_create(args: &Args) -> IgniteResult<()> {
-    use ed25519_dalek::{SecretKey, SigningKey};
-    use hub::random_ext::rand::{rng, Rng};
-    use hub::time_ext::chrono::{Duration, Utc};
-    use ignite::ignite::authority::KeyFingerprint;
+fn verify_chain_command(args: Args) -> i32 {
+    match handle_verify_chain(&args) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            1
+        }
+    }
+}
+
+fn rotate_command(args: Args) -> i32 {
+    match handle_rotate(&args) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            1
+        }
+    }
+}
+
+fn revoke_command(args: Args) -> i32 {
+    match handle_revoke(&args) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            1
+        }
+    }
+}
+
+fn migrate_command(_args: Args) -> i32 {
+    match handle_migrate() {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            1
+        }
+    }
+}
+
+fn proof_command(args: Args) -> i32 {
+    match handle_proof(&args) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            1
+        }
+    }
+}
+
+fn passphrase_command(args: Args) -> i32 {
+    match handle_passphrase(&args) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            1
+        }
+    }
+}
+
+fn policy_command(args: Args) -> i32 {
+    match handle_policy(&args) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            1
+        }
+    }
+}
+
+fn approval_command(args: Args) -> i32 {
+    match handle_approval(&args) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            1
+        }
+    }
+}
+
+fn skull_command(args: Args) -> i32 {
+    match handle_skull(&args) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            1
+        }
+    }
+}
+
+fn audit_command(args: Args) -> i32 {
+    match handle_audit(&args) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            1
+        }
+    }
+}
+
+fn handle_create(args: &Args) -> IgniteResult<KeyFingerprint> {
+    use hub::time_ext::chrono::Utc;
     use ignite::ignite::authority::{
-        proofs::{AuthorityClaim, ProofBundle},
+        ignition,
+        proof_archive::{self, RetirementEvent},
+        proofs::{AuthorityClaim, ProofPair},
         storage,
     };
 
     // Parse arguments: ignite create <key_type> [--description=...] [--parent=...]
+    // --parent is required for every tier below Skull
     let key_type_str = args.get_or(1, "");
     if key_type_str.is_empty() {
         return Err(ignite::IgniteError::InvalidOperation {
@@ -81,7 +213,7 @@ fn handle_create(args: &Args) -> IgniteResult<()> {
     }
 
     let key_type = KeyType::from_str(&key_type_str)?;
-    let policy_engine = PolicyEngine::with_defaults();
+    let policy_engine = PolicyEngine::load()?;
     println!("Creating {} key...", key_type.description());
 
     // Get optional description from --description=... flag
@@ -92,37 +224,119 @@ fn handle_create(args: &Args) -> IgniteResult<()> {
         Some(description)
     };
 
-    // Get optional parent fingerprint from --parent=... flag
+    // Parent fingerprint from --parent=... flag; required below Skull
     let parent_fp_str = get_var("opt_parent");
-    let parent_fp_str = if parent_fp_str.is_empty() {
-        None
-    } else {
-        Some(parent_fp_str)
+    let parent_key = match (key_type.parent_type(), parent_fp_str.is_empty()) {
+        (None, true) => None,
+        (None, false) => {
+            return Err(ignite::IgniteError::InvalidOperation {
+                operation: "create".to_string(),
+                reason: format!("{} keys cannot have a parent", key_type.description()),
+            });
+        }
+        (Some(parent_type), true) => {
+            return Err(ignite::IgniteError::InvalidOperation {
+                operation: "create".to_string(),
+                reason: format!(
+                    "{} keys require --parent=<{} fingerprint>",
+                    key_type.description(),
+                    parent_type
+                ),
+            });
+        }
+        (Some(_), false) => {
+            let parent_fingerprint = KeyFingerprint::from_string(&parent_fp_str)?;
+
+            // Resolve parent from the chain rebuilt out of the vault
+            let chain = AuthorityChain::load_from_vault()?;
+            let parent_key = chain.get_key(&parent_fingerprint).cloned().ok_or_else(|| {
+                ignite::IgniteError::InvalidKey {
+                    reason: format!(
+                        "Parent key not found with fingerprint: {}",
+                        parent_fingerprint
+                    ),
+                }
+            })?;
+
+            if !parent_key.is_active() {
+                return Err(ignite::IgniteError::InvalidOperation {
+                    operation: "create_with_authority".to_string(),
+                    reason: format!(
+                        "Parent key is {} and cannot issue new keys",
+                        parent_key.status()
+                    ),
+                });
+            }
+
+            let report = policy_engine.validate_key(&parent_key)?;
+            print_policy_notes(&report, "  ");
+
+            // Validate parent can control child
+            if !parent_key.can_control(key_type) {
+                return Err(ignite::IgniteError::InvalidOperation {
+                    operation: "create_with_authority".to_string(),
+                    reason: format!(
+                        "{} cannot control {}",
+                        parent_key.key_type().description(),
+                        key_type.description()
+                    ),
+                });
+            }
+
+            Some(parent_key)
+        }
     };
 
-    // Generate Ed25519 key material
-    let mut random = rng();
-    let secret_bytes: [u8; 32] = random.random();
-    let secret_key = SecretKey::from(secret_bytes);
-    let signing_key = SigningKey::from(&secret_key);
-    let public_key = signing_key.verifying_key().to_bytes().to_vec();
-    let private_key = Some(signing_key.to_bytes().to_vec());
+    require_approval(
+        &policy_engine,
+        ApprovalAction::Create,
+        key_type,
+        None,
+        parent_key.as_ref().map(|parent| parent.fingerprint()),
+    )?;
 
-    let key_material = KeyMaterial::new(public_key, private_key, KeyFormat::Ed25519);
+    // Generate Ed25519 key material
+    let key_material = KeyMaterial::generate_ed25519();
 
     // Create metadata
-    let mut metadata = KeyMetadata::default();
-    metadata.creation_time = Utc::now();
-    metadata.creator = whoami::username();
-    metadata.description = description.unwrap_or_else(|| "Created via CLI".to_string());
+    let metadata = KeyMetadata {
+        creation_time: Utc::now(),
+        creator: whoami::username(),
+        description: description.unwrap_or_else(|| "Created via CLI".to_string()),
+        ..Default::default()
+    };
 
     // Create authority key
     let mut authority_key = AuthorityKey::new(key_material, key_type, None, Some(metadata))?;
+    if let Some(parent_key) = &parent_key {
+        authority_key.set_parent(parent_key.fingerprint().clone())?;
+    }
 
     policy_engine.apply_key_defaults(&mut authority_key)?;
-    policy_engine.validate_key(&authority_key)?;
+    let report = policy_engine.validate_key(&authority_key)?;
+    print_policy_notes(&report, "  ");
 
     let child_fingerprint = authority_key.fingerprint().clone();
+    let child_signing_key = authority_key.key_material().signing_key()?;
+
+    // Unlock the parent and wrap ignition keys (X, I, D) under a
+    // policy-checked passphrase before anything is written
+    let passphrases = passphrase_provider()?;
+    let parent_signing_key = match &parent_key {
+        Some(parent_key) => Some(ignition::signing_key(
+            parent_key,
+            &policy_engine,
+            &passphrases,
+        )?),
+        None => None,
+    };
+    if key_type.is_ignition_key() {
+        let passphrase = passphrases.obtain(&PassphraseRequest::new_key(
+            key_type,
+            Some(&child_fingerprint),
+        ))?;
+        ignition::wrap(&mut authority_key, passphrase.expose(), &policy_engine)?;
+    }
 
     // Save to storage
     let saved_path = storage::save_key(&authority_key)?;
@@ -130,66 +344,17 @@ fn handle_create(args: &Args) -> IgniteResult<()> {
     println!("✓ {} key created successfully", key_type.description());
     println!("  Fingerprint: {}", authority_key.fingerprint());
     println!("  Saved to: {}", saved_path.display());
+    if authority_key.is_passphrase_wrapped() {
+        println!("  Private key wrapped under passphrase (Argon2id)");
+    }
 
-    // Generate and save authority proof if parent specified
-    if let Some(parent_fp_str) = parent_fp_str {
-        let parent_fingerprint = KeyFingerprint::from_string(&parent_fp_str)?;
+    // Generate and save authority proof from the parent
+    if let (Some(parent_key), Some(parent_signing_key)) = (parent_key, parent_signing_key) {
+        let parent_fingerprint = parent_key.fingerprint().clone();
 
         println!("\nGenerating authority proof...");
 
-        // Load parent key - need to determine parent's key type from storage
-        let parent_key = {
-            let mut found_key = None;
-            for parent_type in [
-                KeyType::Skull,
-                KeyType::Master,
-                KeyType::Repo,
-                KeyType::Ignition,
-                KeyType::Distro,
-            ] {
-                if let Ok(key) = storage::load_key(parent_type, &parent_fingerprint) {
-                    found_key = Some(key);
-                    break;
-                }
-            }
-            found_key.ok_or_else(|| ignite::IgniteError::InvalidKey {
-                reason: format!(
-                    "Parent key not found with fingerprint: {}",
-                    parent_fingerprint
-                ),
-            })?
-        };
-
-        policy_engine.validate_key(&parent_key)?;
-
-        // Validate parent can control child
-        if !parent_key.can_control(key_type) {
-            return Err(ignite::IgniteError::InvalidOperation {
-                operation: "create_with_authority".to_string(),
-                reason: format!(
-                    "{} cannot control {}",
-                    parent_key.key_type().description(),
-                    key_type.description()
-                ),
-            });
-        }
-
-        // Extract parent's signing key
-        let parent_signing_key = {
-            let private_key_bytes = parent_key.key_material().private_key().ok_or_else(|| {
-                ignite::IgniteError::InvalidKey {
-                    reason: "Parent key has no private key material".to_string(),
-                }
-            })?;
-
-            SigningKey::from_bytes(private_key_bytes.try_into().map_err(|_| {
-                ignite::IgniteError::InvalidKey {
-                    reason: "Invalid parent key length".to_string(),
-                }
-            })?)
-        };
-
-        // Create and sign authority claim
+        // Parent signs the claim, child countersigns the receipt
         let claim = AuthorityClaim::new(
             parent_fingerprint.clone(),
             child_fingerprint.clone(),
@@ -199,15 +364,21 @@ fn handle_create(args: &Args) -> IgniteResult<()> {
             ),
         );
 
-        let expires_at = Utc::now() + Duration::hours(24);
-        let proof = ProofBundle::sign_claim(&claim, &parent_signing_key, expires_at)?;
+        let expires_at = Utc::now() + policy_engine.proof_ttl();
+        let pair = ProofPair::issue(&claim, &parent_signing_key, &child_signing_key, expires_at)?;
+        pair.verify()?;
 
-        // Save proof
-        let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
-        let proof_path = storage::save_proof(&proof, &parent_fingerprint, &timestamp)?;
+        // Save claim with the receipt next to it and make it the edge's active proof
+        let activated = proof_archive::activate(
+            &pair,
+            &proof_archive::proof_timestamp(&child_fingerprint),
+            RetirementEvent::Superseded,
+        )?;
+        let (proof_path, receipt_path) = (activated.claim_path, activated.receipt_path);
 
         println!("✓ Authority proof generated and saved");
         println!("  Proof saved to: {}", proof_path.display());
+        println!("  Receipt saved to: {}", receipt_path.display());
         println!(
             "  Expires at: {}",
             expires_at.format("%Y-%m-%d %H:%M:%S UTC")
@@ -222,16 +393,142 @@ fn handle_create(args: &Args) -> IgniteResult<()> {
         println!("✓ Parent-child relationship recorded");
     }
 
+    Ok(authority_key.fingerprint().clone())
+}
+
+/// Enforce the M-of-N approval threshold for a Skull or Master operation
+///
+/// The request is consumed before the operation runs, so a failed or
+/// interrupted operation needs a fresh request rather than replaying this one.
+/// The vault's first Skull is exempt: the approver registry is founded by it.
+fn require_approval(
+    policy_engine: &PolicyEngine,
+    action: ApprovalAction,
+    key_type: KeyType,
+    target: Option<&KeyFingerprint>,
+    parent: Option<&KeyFingerprint>,
+) -> IgniteResult<()> {
+    use ignite::ignite::authority::storage;
+
+    let threshold = policy_engine.approval_threshold(key_type);
+    if threshold == 0 {
+        return Ok(());
+    }
+    if action == ApprovalAction::Create
+        && key_type == KeyType::Skull
+        && AuthorityChain::load_from_vault()?
+            .get_keys_by_type(KeyType::Skull)
+            .is_empty()
+        && storage::load_approver_registry()?.founding.is_none()
+    {
+        println!("  First Skull key in the vault: found the approver registry with it next (`ignite approval register`)");
+        return Ok(());
+    }
+
+    let request_id = get_var("opt_request");
+    if request_id.is_empty() {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: action.to_string(),
+            reason: format!(
+                "{} {} requires {} approval(s); file one with `ignite approval request` and pass --request=<id>",
+                action,
+                key_type.description(),
+                threshold
+            ),
+        });
+    }
+
+    let status = approvals::authorize(&request_id, action, key_type, target, parent, threshold)?;
+    println!(
+        "✓ Request {} approved by {} ({} of {} required)",
+        status.request.id,
+        status.approved_by.join(", "),
+        status.approved_by.len(),
+        threshold
+    );
+    approvals::consume(&status.request, &whoami::username())?;
     Ok(())
 }
 
+/// Pass the danger-mode guard for `operation` on `key`, confirmed by its short fingerprint
+fn require_danger_mode(operation: DangerousOperation, key: &AuthorityKey) -> IgniteResult<()> {
+    let short = key.fingerprint().short();
+    danger_guard().authorize(
+        operation,
+        &format!("{} key {}", key.key_type(), short),
+        &short,
+        danger::read_confirmation,
+    )
+}
+
+fn danger_guard() -> DangerGuard {
+    DangerGuard::new(
+        get_var("opt_danger_mode") == "true",
+        get_var("opt_i_am_sure") == "true",
+    )
+}
+
+/// Print a report's warnings and denials, one per line
+fn print_policy_notes(report: &PolicyReport, indent: &str) {
+    for verdict in report.denials() {
+        println!("{}✗ {}", indent, verdict);
+    }
+    for verdict in report.warnings() {
+        println!("{}⚠ {}", indent, verdict);
+    }
+}
+
+/// Vault passphrase provider: `IGNITE_VAULT_PASSPHRASE`, --passphrase-helper or a prompt
+///
+/// --passphrase-fd and --passphrase-stdin stay reserved for key passphrases.
+fn vault_passphrase_provider() -> PassphraseProvider {
+    let provider = PassphraseProvider::from_env().interactive(true);
+    match get_var("opt_passphrase_helper") {
+        helper if helper.is_empty() => provider,
+        helper => provider.with_helper(helper),
+    }
+}
+
+/// Passphrase provider from --passphrase-fd/--passphrase-stdin/--passphrase-helper
+///
+/// Passphrases themselves are never accepted as arguments.
+fn passphrase_provider() -> IgniteResult<PassphraseProvider> {
+    if !get_var("opt_passphrase").is_empty() {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "passphrase".to_string(),
+            reason: "Passphrases are never accepted as arguments; use the prompt, PADLOCK_<FP>_PASSPHRASE, --passphrase-fd, --passphrase-stdin or --passphrase-helper".to_string(),
+        });
+    }
+
+    let mut provider = PassphraseProvider::from_env().interactive(true);
+    let fd = get_var("opt_passphrase_fd");
+    if !fd.is_empty() {
+        let fd = fd
+            .parse()
+            .map_err(|_| ignite::IgniteError::InvalidOperation {
+                operation: "passphrase".to_string(),
+                reason: format!("Invalid --passphrase-fd value: {}", fd),
+            })?;
+        provider = provider.with_fd(fd);
+    }
+    if get_var("opt_passphrase_stdin") == "true" {
+        provider = provider.with_stdin();
+    }
+    let helper = get_var("opt_passphrase_helper");
+    if !helper.is_empty() {
+        provider = provider.with_helper(helper);
+    }
+
+    Ok(provider)
+}
+
 fn handle_list(args: &Args) -> IgniteResult<()> {
     use ignite::ignite::authority::storage;
 
     // Parse arguments: ignite list [<key_type>] [--key-type=<type>] [--role=<type>]
     // Support multiple ways to specify the filter for backward compatibility
     let remaining = args.remaining();
-    let key_type_arg = remaining.get(0).map(|s| s.as_str()).unwrap_or("");
+    let key_type_arg = remaining.first().map(|s| s.as_str()).unwrap_or("");
     let key_type_opt = get_var("opt_key_type");
     let role_opt = get_var("opt_role");
 
@@ -248,27 +545,29 @@ fn handle_list(args: &Args) -> IgniteResult<()> {
         None
     };
 
+    let policy_engine = PolicyEngine::load()?;
+    let print_key = |key_path: &std::path::Path| {
+        println!("  {}", key_path.display());
+        if let Ok(key) = storage::load_key_from_path(key_path) {
+            print_policy_notes(&policy_engine.evaluate_key(&key), "    ");
+        }
+    };
+
     if let Some(filter) = key_type_filter {
         let key_type = KeyType::from_str(&filter)?;
         let keys = storage::list_keys(key_type)?;
         println!("Found {} {} keys:", keys.len(), key_type.description());
         for key_path in keys {
-            println!("  {}", key_path.display());
+            print_key(&key_path);
         }
     } else {
         // List all key types
-        for key_type in [
-            KeyType::Skull,
-            KeyType::Master,
-            KeyType::Repo,
-            KeyType::Ignition,
-            KeyType::Distro,
-        ] {
+        for key_type in KeyType::all() {
             let keys = storage::list_keys(key_type)?;
             if !keys.is_empty() {
                 println!("{} keys ({})", key_type.description(), keys.len());
                 for key_path in keys {
-                    println!("  {}", key_path.display());
+                    print_key(&key_path);
                 }
             }
         }
@@ -278,26 +577,19 @@ fn handle_list(args: &Args) -> IgniteResult<()> {
 }
 
 fn handle_status() -> IgniteResult<()> {
-    use ignite::ignite::{authority::storage, utils};
+    use ignite::ignite::utils;
 
     println!("Ignition Authority Chain Status");
     println!("==============================");
     println!("Data root: {}", utils::data_root().display());
     println!();
 
+    let chain = AuthorityChain::load_from_vault()?;
+
     // Key counts by type
     println!("Authority Keys:");
-    let mut total_keys = 0;
-    for key_type in [
-        KeyType::Skull,
-        KeyType::Master,
-        KeyType::Repo,
-        KeyType::Ignition,
-        KeyType::Distro,
-    ] {
-        let keys = storage::list_keys(key_type)?;
-        let count = keys.len();
-        total_keys += count;
+    for key_type in KeyType::all() {
+        let count = chain.get_keys_by_type(key_type).len();
         println!(
             "  {} {}: {}",
             if count > 0 { "✓" } else { "✗" },
@@ -307,111 +599,1445 @@ fn handle_status() -> IgniteResult<()> {
     }
 
     println!();
-    println!("Total keys: {}", total_keys);
+    println!("Total keys: {}", chain.len());
+    println!("Tombstoned keys: {}", chain.tombstones().count());
 
-    if total_keys == 0 {
+    if chain.is_empty() {
         println!();
         println!("No authority keys found. Use 'ignite create' to get started.");
+        return Ok(());
+    }
+
+    println!();
+    match chain.validate_integrity() {
+        Ok(()) => println!("✓ Chain integrity verified"),
+        Err(e) => println!("✗ Chain integrity check failed: {}", e),
+    }
+
+    // Policy verdicts for keys still in service
+    let policy_engine = PolicyEngine::load()?;
+    let reports: Vec<_> = chain
+        .keys()
+        .filter(|key| key.is_active())
+        .map(|key| (key, policy_engine.evaluate_key(key)))
+        .collect();
+    let denied = reports.iter().filter(|(_, r)| r.is_denied()).count();
+    let warned = reports
+        .iter()
+        .filter(|(_, r)| !r.is_denied() && r.warnings().next().is_some())
+        .count();
+    if denied == 0 && warned == 0 {
+        println!("✓ All active keys satisfy policy");
+    } else {
+        println!(
+            "Policy: {} key(s) denied, {} key(s) with warnings",
+            denied, warned
+        );
+        for (key, report) in reports
+            .iter()
+            .filter(|(_, r)| r.is_denied() || r.warnings().next().is_some())
+        {
+            println!(
+                "  {} {}",
+                key.key_type().description(),
+                key.fingerprint().short()
+            );
+            print_policy_notes(report, "    ");
+        }
     }
 
     Ok(())
 }
 
-fn handle_verify(args: &Args) -> IgniteResult<()> {
-    use ignite::ignite::authority::proofs::ProofBundle;
-    use ignite::IgniteError;
-    use std::fs;
-    use std::path::Path;
+fn handle_rotate(args: &Args) -> IgniteResult<()> {
+    use ignite::ignite::authority::rotation;
 
-    // Parse arguments: ignite verify <file>
-    let file = args.get_or(1, "");
-    if file.is_empty() {
-        return Err(IgniteError::InvalidOperation {
-            operation: "verify".to_string(),
-            reason: "Missing file argument. Usage: ignite verify <file>".to_string(),
+    // Parse arguments: ignite rotate <fingerprint>
+    let fingerprint_str = args.get_or(1, "");
+    if fingerprint_str.is_empty() {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "rotate".to_string(),
+            reason: "Missing fingerprint argument. Usage: ignite rotate <fingerprint>".to_string(),
         });
     }
 
-    let path = Path::new(&file);
-    if !path.exists() {
-        return Err(IgniteError::InvalidOperation {
-            operation: "verify_file".to_string(),
-            reason: format!("File '{}' does not exist", file),
-        });
+    let fingerprint = KeyFingerprint::from_string(&fingerprint_str)?;
+    let policy_engine = PolicyEngine::load()?;
+    let chain = AuthorityChain::load_from_vault()?;
+    let key = chain
+        .get_key(&fingerprint)
+        .ok_or_else(|| ignite::IgniteError::InvalidKey {
+            reason: format!("Key not found with fingerprint: {}", fingerprint),
+        })?;
+    if DangerousOperation::guards_key(key.key_type()) {
+        require_danger_mode(DangerousOperation::RotateKey, key)?;
     }
+    require_approval(
+        &policy_engine,
+        ApprovalAction::Rotate,
+        key.key_type(),
+        Some(&fingerprint),
+        None,
+    )?;
 
-    println!("Verifying file: {}", file);
+    println!("Rotating key {}...", fingerprint);
+    let outcome = rotation::rotate_key(&fingerprint, &policy_engine, &passphrase_provider()?)?;
 
-    // Try to read the file
-    let content = fs::read_to_string(path).map_err(|e| IgniteError::InvalidOperation {
-        operation: "read_file".to_string(),
-        reason: format!("Failed to read file '{}': {}", file, e),
-    })?;
+    println!(
+        "✓ {} key rotated",
+        outcome.replacement.key_type().description()
+    );
+    println!("  Archived: {}", outcome.retired);
+    println!("  Replacement: {}", outcome.replacement.fingerprint());
+    println!("  Proof saved to: {}", outcome.proof_path.display());
 
-    // Try to parse as ProofBundle first
-    if let Ok(proof) = hub::data_ext::serde_json::from_str::<ProofBundle>(&content) {
-        println!("✓ File is a valid proof bundle");
+    if outcome.invalidated.is_empty() {
+        println!("  No descendant keys affected");
+    } else {
         println!(
-            "  Expires at: {}",
-            proof.expires_at.format("%Y-%m-%d %H:%M:%S UTC")
+            "  Invalidated {} descendant key(s):",
+            outcome.invalidated.len()
         );
-        println!("  Digest: {}", proof.digest);
+        for fingerprint in &outcome.invalidated {
+            println!("    ✗ {}", fingerprint);
+        }
+    }
+    println!("✓ Manifest saved to: {}", outcome.manifest_path.display());
 
-        // Verify the proof
-        match proof.verify() {
-            Ok(()) => {
-                println!("✓ Proof signature verification passed");
+    Ok(())
+}
 
-                // Try to parse the payload to show more details
-                if let Ok(claim) = hub::data_ext::serde_json::from_str::<
-                    ignite::ignite::authority::proofs::AuthorityClaim,
-                >(&proof.payload_json)
-                {
-                    println!("\nAuthority Claim Details:");
-                    println!("  Parent: {}", claim.parent_fp);
-                    println!("  Child: {}", claim.child_fp);
-                    println!("  Purpose: {}", claim.purpose);
-                    println!(
-                        "  Issued at: {}",
-                        claim.issued_at.format("%Y-%m-%d %H:%M:%S UTC")
-                    );
-                }
+fn handle_revoke(args: &Args) -> IgniteResult<()> {
+    use ignite::ignite::authority::{revocation, RevocationReason};
 
-                return Ok(());
-            }
-            Err(e) => {
-                return Err(IgniteError::InvalidOperation {
-                    operation: "verify_proof".to_string(),
-                    reason: format!("Proof verification failed: {}", e),
-                });
-            }
-        }
+    // Parse arguments: ignite revoke <fingerprint> --reason=<code>
+    let fingerprint_str = args.get_or(1, "");
+    let reason_str = get_var("opt_reason");
+    if fingerprint_str.is_empty() || reason_str.is_empty() {
+        let codes = RevocationReason::all()
+            .iter()
+            .map(|reason| reason.as_str())
+            .collect::<Vec<_>>()
+            .join("|");
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "revoke".to_string(),
+            reason: format!(
+                "Missing arguments. Usage: ignite revoke <fingerprint> --reason=<{}>",
+                codes
+            ),
+        });
     }
 
-    // Try to parse as manifest
-    if let Ok(manifest) = hub::data_ext::serde_json::from_str::<
-        ignite::ignite::authority::manifests::AffectedKeyManifest,
-    >(&content)
-    {
-        println!("✓ File is a valid manifest");
-        println!("  Schema version: {}", manifest.schema_version);
-        println!("  Event type: {:?}", manifest.event.event_type);
+    let fingerprint = KeyFingerprint::from_string(&fingerprint_str)?;
+    let reason = RevocationReason::from_str(&reason_str)?;
+    let policy_engine = PolicyEngine::load()?;
+    let chain = AuthorityChain::load_from_vault()?;
+    let key = chain
+        .get_key(&fingerprint)
+        .ok_or_else(|| ignite::IgniteError::InvalidKey {
+            reason: format!("Key not found with fingerprint: {}", fingerprint),
+        })?;
+    if DangerousOperation::guards_key(key.key_type()) {
+        require_danger_mode(DangerousOperation::RevokeKey, key)?;
+    }
+    require_approval(
+        &policy_engine,
+        ApprovalAction::Revoke,
+        key.key_type(),
+        Some(&fingerprint),
+        None,
+    )?;
+
+    println!("Revoking key {} ({})...", fingerprint, reason);
+    let outcome = revocation::revoke_key(
+        &fingerprint,
+        reason,
+        &whoami::username(),
+        &policy_engine,
+        &passphrase_provider()?,
+    )?;
+
+    println!(
+        "✓ {} key revoked by {}",
+        outcome.tombstone.role.description(),
+        outcome.tombstone.revoked_by
+    );
+    println!("  Tombstone saved to: {}", outcome.tombstone_path.display());
+
+    if outcome.invalidated.is_empty() {
+        println!("  No descendant keys affected");
+    } else {
         println!(
-            "  Parent fingerprint: {}",
-            manifest.event.parent_fingerprint
+            "  Invalidated {} descendant key(s):",
+            outcome.invalidated.len()
         );
-        println!("  Children count: {}", manifest.children.len());
+        for fingerprint in &outcome.invalidated {
+            println!("    ✗ {}", fingerprint);
+        }
+    }
+    println!("✓ Manifest saved to: {}", outcome.manifest_path.display());
 
-        // Verify digest - this must succeed for verification to pass
-        match manifest.verify_digest() {
-            Ok(()) => {
-                println!("✓ Digest verification passed");
+    Ok(())
+}
+
+fn handle_migrate() -> IgniteResult<()> {
+    // Backfilling rewrites every stored key file
+    danger_guard().authorize(
+        DangerousOperation::OverwriteKey,
+        "every stored key",
+        "migrate",
+        danger::read_confirmation,
+    )?;
+
+    println!("Backfilling parent links and sealing legacy keys...");
+
+    let updated = AuthorityChain::backfill_vault()?;
+    for fingerprint in &updated {
+        println!("  ✓ {}", fingerprint);
+    }
+    println!("✓ {} key(s) updated", updated.len());
+
+    let chain = AuthorityChain::load_from_vault()?;
+    match chain.validate_integrity() {
+        Ok(()) => println!("✓ Chain integrity verified"),
+        Err(e) => println!("✗ Chain integrity check failed: {}", e),
+    }
+
+    Ok(())
+}
+
+fn handle_proof(args: &Args) -> IgniteResult<()> {
+    // Parse arguments: ignite proof <subcommand> ...
+    match args.get_or(1, "").as_str() {
+        "renew" => handle_proof_renew(args),
+        "purge" => handle_proof_purge(),
+        _ => Err(ignite::IgniteError::InvalidOperation {
+            operation: "proof".to_string(),
+            reason: "Unknown proof subcommand. Usage: ignite proof renew [--all|<fingerprint>] [--dry-run] | purge --older-than=<days> [--dry-run]".to_string(),
+        }),
+    }
+}
+
+fn handle_proof_purge() -> IgniteResult<()> {
+    use hub::time_ext::chrono::{Duration, Utc};
+    use ignite::ignite::authority::proof_archive;
+
+    // Parse arguments: ignite proof purge --older-than=<days> [--dry-run]
+    let days = get_var("opt_older_than").parse::<u32>().map_err(|_| {
+        ignite::IgniteError::InvalidOperation {
+            operation: "proof_purge".to_string(),
+            reason: "Missing or invalid --older-than=<days>. Usage: ignite proof purge --older-than=<days> [--dry-run]".to_string(),
+        }
+    })?;
+    let dry_run = get_var("opt_dry_run") == "true";
+
+    let cutoff = Utc::now() - Duration::days(days as i64);
+    let records = proof_archive::archived_before(cutoff)?;
+    if records.is_empty() {
+        println!("No archived proofs retired more than {} day(s) ago", days);
+        return Ok(());
+    }
+
+    for record in &records {
+        println!(
+            "  {} {} -> {} ({}, retired {})",
+            if dry_run { "·" } else { "✗" },
+            record.parent_fp.short(),
+            record.child_fp.short(),
+            record.event,
+            record.retired_at.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+    }
+    if dry_run {
+        println!(
+            "Dry run: {} archived proof pair(s) would be purged",
+            records.len()
+        );
+        return Ok(());
+    }
+
+    danger_guard().authorize(
+        DangerousOperation::PurgeArchive,
+        &format!("{} archived proof pair(s)", records.len()),
+        "purge",
+        danger::read_confirmation,
+    )?;
+    let removed = proof_archive::purge(&records)?;
+    println!(
+        "✓ Purged {} archived proof pair(s) ({} files)",
+        records.len(),
+        removed
+    );
+    Ok(())
+}
+
+fn handle_proof_renew(args: &Args) -> IgniteResult<()> {
+    use hub::time_ext::chrono::Utc;
+    use ignite::ignite::authority::renewal;
+
+    // Parse arguments: ignite proof renew [--all|<fingerprint>] [--dry-run]
+    let fingerprint_str = args.get_or(2, "");
+    let all = get_var("opt_all") == "true";
+    let dry_run = get_var("opt_dry_run") == "true";
+    if all != fingerprint_str.is_empty() {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "proof_renew".to_string(),
+            reason: "Specify exactly one of --all or <fingerprint>. Usage: ignite proof renew [--all|<fingerprint>] [--dry-run]".to_string(),
+        });
+    }
+
+    let filter = if all {
+        None
+    } else {
+        Some(KeyFingerprint::from_string(&fingerprint_str)?)
+    };
+
+    let chain = AuthorityChain::load_from_vault()?;
+    let policy_engine = PolicyEngine::load()?;
+    let passphrases = passphrase_provider()?;
+    let due = renewal::due_for_renewal(
+        &chain,
+        filter.as_ref(),
+        Utc::now(),
+        policy_engine.renewal_window(),
+    )?;
+    if due.is_empty() {
+        println!(
+            "✓ No proofs due for renewal (window: {}h)",
+            policy_engine.renewal_window().num_hours()
+        );
+        return Ok(());
+    }
+
+    println!("{} proof(s) due for renewal:", due.len());
+    let mut failures = 0;
+    for candidate in &due {
+        let expiry = candidate
+            .expires_at
+            .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+            .unwrap_or_else(|| "expired or incomplete".to_string());
+        println!(
+            "  {} -> {} ({})",
+            candidate.parent.short(),
+            candidate.child.short(),
+            expiry
+        );
+
+        if dry_run {
+            continue;
+        }
+        match renewal::renew_proof(&chain, candidate, &policy_engine, &passphrases) {
+            Ok(renewed) => {
+                println!("    ✓ Renewed: {}", renewed.proof_path.display());
+                println!(
+                    "    Expires at: {}",
+                    renewed.proof.expires_at().format("%Y-%m-%d %H:%M:%S UTC")
+                );
+                if let Some(archived) = &renewed.archived {
+                    println!("    Archived: {}", archived.claim);
+                }
+            }
+            Err(e) => {
+                println!("    ✗ Renewal failed: {}", e);
+                failures += 1;
+            }
+        }
+    }
+
+    if dry_run {
+        println!("Dry run: no proofs were changed");
+    } else if failures > 0 {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "proof_renew".to_string(),
+            reason: format!("{} of {} renewal(s) failed", failures, due.len()),
+        });
+    } else {
+        println!("✓ {} proof(s) renewed", due.len());
+    }
+
+    Ok(())
+}
+
+fn handle_passphrase(args: &Args) -> IgniteResult<()> {
+    // Parse arguments: ignite passphrase <subcommand> ...
+    match args.get_or(1, "").as_str() {
+        "generate" => handle_passphrase_generate(),
+        "build-filter" => handle_passphrase_build_filter(args),
+        _ => Err(ignite::IgniteError::InvalidOperation {
+            operation: "passphrase".to_string(),
+            reason: "Unknown passphrase subcommand. Usage: ignite passphrase generate [--words=N] [--key-type=<type>] | ignite passphrase build-filter <corpus> [--fp-rate=R]".to_string(),
+        }),
+    }
+}
+
+fn handle_passphrase_generate() -> IgniteResult<()> {
+    use ignite::ignite::security::{generator, strength};
+
+    // Parse arguments: ignite passphrase generate [--words=N] [--key-type=<type>]
+    let words = match get_var("opt_words") {
+        w if w.is_empty() => generator::DEFAULT_WORD_COUNT,
+        w => w
+            .parse()
+            .map_err(|_| ignite::IgniteError::InvalidOperation {
+                operation: "passphrase_generate".to_string(),
+                reason: format!("Invalid --words value: {}", w),
+            })?,
+    };
+    let key_type = match get_var("opt_key_type") {
+        t if t.is_empty() => KeyType::Ignition,
+        t => KeyType::from_str(&t)?,
+    };
+
+    let policy_engine = PolicyEngine::load()?;
+    let generated = generator::generate_for_policy(&policy_engine, key_type, words)?;
+    let estimate = strength::estimate(generated.expose());
+
+    println!("{}", generated.expose());
+    eprintln!(
+        "{} words, {:.0} bits generated ({:.0} bits estimated); satisfies {} policy",
+        generated.words,
+        generated.entropy_bits,
+        estimate.entropy_bits,
+        key_type.description()
+    );
+    Ok(())
+}
+
+fn handle_passphrase_build_filter(args: &Args) -> IgniteResult<()> {
+    use ignite::ignite::security::breach::{self, BreachFilter, DEFAULT_FALSE_POSITIVE_RATE};
+
+    // Parse arguments: ignite passphrase build-filter <corpus> [--fp-rate=R]
+    let corpus = args.get_or(2, "");
+    if corpus.is_empty() {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "passphrase_build_filter".to_string(),
+            reason: "Missing corpus argument. Usage: ignite passphrase build-filter <corpus> [--fp-rate=R]".to_string(),
+        });
+    }
+    let rate = match get_var("opt_fp_rate") {
+        r if r.is_empty() => DEFAULT_FALSE_POSITIVE_RATE,
+        r => r
+            .parse()
+            .ok()
+            .filter(|rate: &f64| *rate > 0.0 && *rate < 1.0)
+            .ok_or_else(|| ignite::IgniteError::InvalidOperation {
+                operation: "passphrase_build_filter".to_string(),
+                reason: format!("Invalid --fp-rate value: {}", r),
+            })?,
+    };
+
+    println!("Building breach filter from {}...", corpus);
+    let filter = BreachFilter::from_corpus(std::path::Path::new(&corpus), rate)?;
+    let path = breach::installed_path();
+    filter.save(&path)?;
+
+    println!("✓ Breach filter installed: {}", path.display());
+    println!("  Size: {} bytes", filter.to_bytes().len());
+    println!("  False-positive rate: {}", rate);
+    Ok(())
+}
+
+fn handle_policy(args: &Args) -> IgniteResult<()> {
+    // Parse arguments: ignite policy <subcommand> ...
+    match args.get_or(1, "").as_str() {
+        "show" => handle_policy_show(),
+        "check" => handle_policy_check(args),
+        "explain" => handle_policy_explain(args),
+        _ => Err(ignite::IgniteError::InvalidOperation {
+            operation: "policy".to_string(),
+            reason: "Unknown policy subcommand. Usage: ignite policy show | ignite policy check <file> | ignite policy explain <fingerprint> [--rotate] | ignite policy explain --create=<key_type> [--parent=<fingerprint>]".to_string(),
+        }),
+    }
+}
+
+fn handle_policy_show() -> IgniteResult<()> {
+    use ignite::ignite::security::PolicyConfig;
+
+    let config = PolicyConfig::load()?;
+    println!("# Effective policy (built-in defaults, then each file below)");
+    for path in PolicyConfig::search_paths() {
+        let state = if path.exists() { "loaded" } else { "absent" };
+        println!("#   {} ({})", path.display(), state);
+    }
+    println!();
+    print!("{}", config.to_toml()?);
+    Ok(())
+}
+
+fn handle_policy_check(args: &Args) -> IgniteResult<()> {
+    use ignite::ignite::security::PolicyConfig;
+
+    // Parse arguments: ignite policy check <file>
+    let file = args.get_or(2, "");
+    if file.is_empty() {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "policy_check".to_string(),
+            reason: "Missing policy file argument. Usage: ignite policy check <file>".to_string(),
+        });
+    }
+
+    let config = PolicyConfig::from_layers(&[std::path::PathBuf::from(&file)])?;
+    let engine = PolicyEngine::from_config(&config);
+    println!("✓ Policy file is valid: {}", file);
+    println!("  Policies: {}", engine.policy_names().join(", "));
+    print_policy_notes(&engine.check_setup(), "  ");
+    println!();
+    print!("{}", config.to_toml()?);
+    Ok(())
+}
+
+fn handle_policy_explain(args: &Args) -> IgniteResult<()> {
+    use hub::time_ext::chrono::Utc;
+    use ignite::ignite::authority::rotation;
+    use ignite::ignite::security::policy::{ExplainStep, Outcome};
+
+    // Parse arguments: ignite policy explain <fingerprint> [--rotate] [--check-passphrase]
+    //              or: ignite policy explain --create=<key_type> [--parent=<fp>] [--check-passphrase]
+    let policy_engine = PolicyEngine::load()?;
+    let chain = AuthorityChain::load_from_vault()?;
+    let find = |fp_str: &str| -> IgniteResult<AuthorityKey> {
+        let fingerprint = KeyFingerprint::from_string(fp_str)?;
+        chain
+            .get_key(&fingerprint)
+            .cloned()
+            .ok_or_else(|| ignite::IgniteError::InvalidKey {
+                reason: format!("Key not found with fingerprint: {}", fingerprint),
+            })
+    };
+
+    let create_type = get_var("opt_create");
+    let fingerprint_str = args.get_or(2, "");
+    let (subject_label, subject, parent) = if !create_type.is_empty() {
+        let key_type = KeyType::from_str(&create_type)?;
+        let parent_fp = get_var("opt_parent");
+        let parent = if parent_fp.is_empty() {
+            None
+        } else {
+            Some(find(&parent_fp)?)
+        };
+
+        let metadata = KeyMetadata {
+            creation_time: Utc::now(),
+            creator: whoami::username(),
+            description: "Created via CLI".to_string(),
+            ..Default::default()
+        };
+        let mut key = AuthorityKey::new(
+            KeyMaterial::generate_ed25519(),
+            key_type,
+            None,
+            Some(metadata),
+        )?;
+        if let Some(parent) = &parent {
+            key.set_parent(parent.fingerprint().clone())?;
+        }
+        (format!("proposed {} key", key_type), key, parent)
+    } else if fingerprint_str.is_empty() {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "policy_explain".to_string(),
+            reason: "Missing fingerprint argument. Usage: ignite policy explain <fingerprint> [--rotate] | ignite policy explain --create=<key_type> [--parent=<fingerprint>]".to_string(),
+        });
+    } else {
+        let key = find(&fingerprint_str)?;
+        if get_var("opt_rotate") == "true" {
+            let parent = key.parent().and_then(|fp| chain.get_key(fp)).cloned();
+            let replacement = rotation::replacement_for(&key, parent.as_ref())?;
+            (
+                format!("rotation of {}", key.fingerprint().short()),
+                replacement,
+                parent,
+            )
+        } else {
+            (format!("key {}", key.fingerprint().short()), key, None)
+        }
+    };
+
+    // Candidate passphrase from the usual sources, never from arguments
+    let passphrase =
+        if get_var("opt_check_passphrase") == "true" && subject.key_type().is_ignition_key() {
+            Some(passphrase_provider()?.obtain(&PassphraseRequest::new_key(
+                subject.key_type(),
+                Some(subject.fingerprint()),
+            ))?)
+        } else {
+            None
+        };
+
+    let explanation = policy_engine.explain_key(&subject, passphrase.as_ref().map(|p| p.expose()));
+
+    println!(
+        "Policy explain: {} (dry run, nothing is written)",
+        subject_label
+    );
+    println!("  Policies: {}", policy_engine.policy_names().join(", "));
+    print_policy_notes(&policy_engine.check_setup(), "  ");
+    if let Some(parent) = &parent {
+        let report = policy_engine.evaluate_key(parent);
+        println!(
+            "  Parent {}: {}",
+            parent.fingerprint().short(),
+            report.outcome()
+        );
+        print_policy_notes(&report, "    ");
+    }
+    println!();
+
+    let detail = |step: &ExplainStep| {
+        if !step.defaults.is_empty() {
+            let fields: Vec<String> = step
+                .defaults
+                .iter()
+                .map(|(field, value)| format!("{} = {}", field, value))
+                .collect();
+            format!("sets {}", fields.join(", "))
+        } else {
+            match step.outcome {
+                Some(Outcome::Allow) => "-".to_string(),
+                None => step.message.clone(),
+                Some(_) => format!("{}: {}", step.code, step.message),
+            }
+        }
+    };
+    println!("{:<20} {:<20} {:<8} DETAIL", "POLICY", "PHASE", "OUTCOME");
+    for step in &explanation.steps {
+        println!(
+            "{:<20} {:<20} {:<8} {}",
+            step.policy,
+            step.phase.as_str(),
+            step.outcome.map(|o| o.as_str()).unwrap_or("skipped"),
+            detail(step)
+        );
+    }
+
+    println!();
+    println!("Overall: {}", explanation.outcome());
+    Ok(())
+}
+
+fn handle_approval(args: &Args) -> IgniteResult<()> {
+    // Parse arguments: ignite approval <subcommand> ...
+    match args.get_or(1, "").as_str() {
+        "keygen" => handle_approval_keygen(args),
+        "register" => handle_approval_register(args),
+        "request" => handle_approval_request(args),
+        "sign" => handle_approval_sign(args),
+        "status" => handle_approval_status(args),
+        _ => Err(ignite::IgniteError::InvalidOperation {
+            operation: "approval".to_string(),
+            reason: "Unknown approval subcommand. Usage: ignite approval keygen <key-file> | register <name> <public-key> | request <create|rotate|revoke> <key_type|fingerprint> | sign <request-id> --approver=<name> --key-file=<path> | status <request-id>".to_string(),
+        }),
+    }
+}
+
+fn handle_approval_keygen(args: &Args) -> IgniteResult<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    // Parse arguments: ignite approval keygen <key-file>
+    let file = args.get_or(2, "");
+    if file.is_empty() {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "approval_keygen".to_string(),
+            reason: "Missing key file argument. Usage: ignite approval keygen <key-file>"
+                .to_string(),
+        });
+    }
+
+    let path = std::path::PathBuf::from(&file);
+    let signing_key = KeyMaterial::generate_ed25519().signing_key()?;
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(&path)
+        .and_then(|mut out| writeln!(out, "{}", approvals::encode_hex(&signing_key.to_bytes())))
+        .map_err(|e| ignite::IgniteError::io_error("write_approver_key", path.clone(), e))?;
+
+    println!("✓ Approver key written to: {}", path.display());
+    println!(
+        "  Public key: {}",
+        approvals::encode_hex(signing_key.verifying_key().as_bytes())
+    );
+    println!("  Register it with: ignite approval register <name> <public key>");
+    Ok(())
+}
+
+fn handle_approval_register(args: &Args) -> IgniteResult<()> {
+    use ignite::ignite::authority::approvals::ApproverRegistry;
+    use ignite::ignite::authority::{ignition, storage};
+
+    // Parse arguments: ignite approval register <name> <public-key-hex> [<name> <public-key-hex>...]
+    //                  [--skull=<fp>] | [--request=<id>] [--danger-mode --i-am-sure]
+    let usage = || {
+        ignite::IgniteError::InvalidOperation {
+        operation: "approval_register".to_string(),
+        reason: "Missing arguments. Usage: ignite approval register <name> <public-key> [<name> <public-key>...]"
+            .to_string(),
+    }
+    };
+    let positional = |index: usize| {
+        Some(args.get_or(index, "")).filter(|arg| !arg.is_empty() && !arg.starts_with("--"))
+    };
+    let mut approvers = Vec::new();
+    while let Some(name) = positional(2 + 2 * approvers.len()) {
+        let public_key = positional(3 + 2 * approvers.len()).ok_or_else(usage)?;
+        approvers.push((name, approvals::parse_public_key(&public_key)?));
+    }
+    if approvers.is_empty() {
+        return Err(usage());
+    }
+
+    let policy_engine = PolicyEngine::load()?;
+    let threshold = policy_engine.approval_threshold(KeyType::Skull);
+    let mut registry = storage::load_approver_registry()?;
+
+    // The first approvers are signed in together by the Skull key
+    if registry.founding.is_none() {
+        if !registry.approvers.is_empty() {
+            return Err(ignite::IgniteError::InvalidOperation {
+                operation: "approval_register".to_string(),
+                reason: "Approver registry holds approvers but no Skull founding signature; refusing to extend it".to_string(),
+            });
+        }
+        let skull_fp = get_var("opt_skull");
+        let skull_fp = if skull_fp.is_empty() {
+            None
+        } else {
+            Some(KeyFingerprint::from_string(&skull_fp)?)
+        };
+        let skull = resolve_skull(&AuthorityChain::load_from_vault()?, skull_fp.as_ref())?;
+        let signing_key = ignition::signing_key(&skull, &policy_engine, &passphrase_provider()?)?;
+        let registry = ApproverRegistry::found(&approvers, &skull, &signing_key, threshold)?;
+        let path = storage::save_approver_registry(&registry)?;
+
+        println!(
+            "✓ Approver registry founded by Skull {}",
+            skull.fingerprint().short()
+        );
+        for approver in &registry.approvers {
+            println!("  • {}", approver.name);
+        }
+        println!("  Registry: {}", path.display());
+        return Ok(());
+    }
+
+    // Once founded, approvers join one at a time with the quorum's approval
+    let [(name, verifying_key)] = approvers.as_slice() else {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "approval_register".to_string(),
+            reason: "The registry is founded; register one approver at a time".to_string(),
+        });
+    };
+    danger_guard().authorize(
+        DangerousOperation::ModifyApprovers,
+        &format!("approver registry ({} approvers)", registry.approvers.len()),
+        name,
+        danger::read_confirmation,
+    )?;
+    if threshold > 0 {
+        let request_id = get_var("opt_request");
+        if request_id.is_empty() {
+            return Err(ignite::IgniteError::InvalidOperation {
+                operation: "approval_register".to_string(),
+                reason: format!(
+                    "Registering an approver requires {} approval(s); file one with `ignite approval request register-approver <name> <public-key>` and pass --request=<id>",
+                    threshold
+                ),
+            });
+        }
+        let status =
+            approvals::authorize_registration(&request_id, name, verifying_key, threshold)?;
+        println!(
+            "✓ Request {} approved by {} ({} of {} required)",
+            status.request.id,
+            status.approved_by.join(", "),
+            status.approved_by.len(),
+            threshold
+        );
+        approvals::consume(&status.request, &whoami::username())?;
+    }
+
+    registry.register(name, verifying_key)?;
+    let path = storage::save_approver_registry(&registry)?;
+
+    println!("✓ Approver '{}' registered", name);
+    println!(
+        "  Registry: {} ({} approvers)",
+        path.display(),
+        registry.approvers.len()
+    );
+    Ok(())
+}
+
+fn handle_approval_request(args: &Args) -> IgniteResult<()> {
+    // Parse arguments: ignite approval request <create|rotate|revoke> <key_type|fingerprint>
+    //                  [--parent=<fp>] [--reason=<text>]
+    //                  | ignite approval request register-approver <name> <public-key-hex>
+    let action_str = args.get_or(2, "");
+    let subject = args.get_or(3, "");
+    if action_str.is_empty() || subject.is_empty() {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "approval_request".to_string(),
+            reason: "Missing arguments. Usage: ignite approval request create <key_type> [--parent=<fingerprint>] | ignite approval request <rotate|revoke> <fingerprint> | ignite approval request register-approver <name> <public-key>".to_string(),
+        });
+    }
+
+    let action = ApprovalAction::from_str(&action_str)?;
+    let policy_engine = PolicyEngine::load()?;
+    let (key_type, target, parent) = match action {
+        ApprovalAction::Create => {
+            let parent = get_var("opt_parent");
+            let parent = if parent.is_empty() {
+                None
+            } else {
+                Some(KeyFingerprint::from_string(&parent)?)
+            };
+            (KeyType::from_str(&subject)?, None, parent)
+        }
+        ApprovalAction::Rotate | ApprovalAction::Revoke => {
+            let fingerprint = KeyFingerprint::from_string(&subject)?;
+            let chain = AuthorityChain::load_from_vault()?;
+            let key =
+                chain
+                    .get_key(&fingerprint)
+                    .ok_or_else(|| ignite::IgniteError::InvalidKey {
+                        reason: format!("Key not found with fingerprint: {}", fingerprint),
+                    })?;
+            (key.key_type(), Some(fingerprint), None)
+        }
+        ApprovalAction::RegisterApprover => {
+            let public_key = approvals::parse_public_key(&args.get_or(4, ""))?;
+            let request = OperationRequest::register_approver(
+                &subject,
+                &public_key,
+                get_var("opt_reason"),
+                whoami::username(),
+                policy_engine.approval_ttl(),
+            )?;
+            let threshold = policy_engine.approval_threshold(KeyType::Skull);
+            return print_filed_request(&request, threshold);
+        }
+    };
+
+    let request = OperationRequest::new(
+        action,
+        key_type,
+        target,
+        parent,
+        get_var("opt_reason"),
+        whoami::username(),
+        policy_engine.approval_ttl(),
+    )?;
+    print_filed_request(&request, policy_engine.approval_threshold(key_type))
+}
+
+/// Submit a request and print its id, digest, expiry and required approvals
+fn print_filed_request(request: &OperationRequest, threshold: usize) -> IgniteResult<()> {
+    let path = approvals::submit(request)?;
+
+    println!("✓ Request filed: {}", request.describe());
+    println!("  Request id: {}", request.id);
+    println!("  Digest: {}", request.compute_digest()?);
+    println!(
+        "  Expires at: {}",
+        request.expires_at.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    println!("  Approvals required: {}", threshold);
+    println!("  Saved to: {}", path.display());
+    Ok(())
+}
+
+fn handle_approval_sign(args: &Args) -> IgniteResult<()> {
+    use ignite::ignite::authority::{storage, Approval};
+
+    // Parse arguments: ignite approval sign <request-id> --approver=<name> --key-file=<path>
+    let request_id = args.get_or(2, "");
+    let approver = get_var("opt_approver");
+    let key_file = get_var("opt_key_file");
+    if request_id.is_empty() || approver.is_empty() || key_file.is_empty() {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "approval_sign".to_string(),
+            reason: "Missing arguments. Usage: ignite approval sign <request-id> --approver=<name> --key-file=<path>".to_string(),
+        });
+    }
+
+    let request = storage::load_operation_request(&request_id)?;
+    let registry = storage::load_approver_registry()?;
+    let registered =
+        registry
+            .get(&approver)
+            .ok_or_else(|| ignite::IgniteError::InvalidOperation {
+                operation: "approval_sign".to_string(),
+                reason: format!("'{}' is not a registered approver", approver),
+            })?;
+
+    let path = std::path::PathBuf::from(&key_file);
+    let secret = std::fs::read_to_string(&path)
+        .map_err(|e| ignite::IgniteError::io_error("read_approver_key", path.clone(), e))?;
+    let signing_key = approvals::parse_signing_key(&secret)?;
+    if signing_key.verifying_key() != registered.verifying_key()? {
+        return Err(ignite::IgniteError::InvalidKey {
+            reason: format!("Key file does not match approver '{}'", approver),
+        });
+    }
+
+    let policy_engine = PolicyEngine::load()?;
+    let approval = Approval::sign(
+        &request,
+        &approver,
+        &signing_key,
+        policy_engine.approval_ttl(),
+    )?;
+    approval.verify(&request, &registry, hub::time_ext::chrono::Utc::now())?;
+    let saved = storage::save_approval(&approval)?;
+
+    println!("✓ {} approved {}", approver, request.describe());
+    println!("  Request digest: {}", approval.request_digest);
+    println!(
+        "  Valid until: {}",
+        approval.expires_at.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    println!("  Saved to: {}", saved.display());
+    Ok(())
+}
+
+fn handle_approval_status(args: &Args) -> IgniteResult<()> {
+    use hub::time_ext::chrono::Utc;
+    use ignite::ignite::authority::approvals::ApprovalStatus;
+    use ignite::ignite::authority::storage;
+
+    // Parse arguments: ignite approval status <request-id>
+    let request_id = args.get_or(2, "");
+    if request_id.is_empty() {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "approval_status".to_string(),
+            reason: "Missing request id. Usage: ignite approval status <request-id>".to_string(),
+        });
+    }
+
+    let request = storage::load_operation_request(&request_id)?;
+    let threshold = PolicyEngine::load()?.approval_threshold(request.key_type);
+    let now = Utc::now();
+    let status = ApprovalStatus::evaluate(request, threshold, now)?;
+    let request = &status.request;
+
+    println!("Request {}", request.id);
+    println!("  Operation: {}", request.describe());
+    if !request.reason.is_empty() {
+        println!("  Reason: {}", request.reason);
+    }
+    println!(
+        "  Requested by {} at {}",
+        request.requested_by,
+        request.requested_at.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    println!(
+        "  Expires at: {}{}",
+        request.expires_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        if request.is_expired_at(now) {
+            " (expired)"
+        } else {
+            ""
+        }
+    );
+    println!();
+    for approver in &status.approved_by {
+        println!("  ✓ {}", approver);
+    }
+    for (approver, reason) in &status.rejected {
+        println!("  ✗ {}: {}", approver, reason);
+    }
+    println!(
+        "  {} of {} required approvals",
+        status.approved_by.len(),
+        threshold
+    );
+
+    match &status.consumed {
+        Some(consumed) => println!(
+            "  Used by {} at {}",
+            consumed.consumed_by,
+            consumed.consumed_at.format("%Y-%m-%d %H:%M:%S UTC")
+        ),
+        None if status.is_satisfied() && !request.is_expired_at(now) => {
+            println!("✓ Ready: pass --request={} to the operation", request.id)
+        }
+        None => println!("✗ Not yet authorized"),
+    }
+    Ok(())
+}
+
+fn handle_skull(args: &Args) -> IgniteResult<()> {
+    // Parse arguments: ignite skull <subcommand> ...
+    match args.get_or(1, "").as_str() {
+        "split" => handle_skull_split(args),
+        "combine" => handle_skull_combine(args),
+        _ => Err(ignite::IgniteError::InvalidOperation {
+            operation: "skull".to_string(),
+            reason: "Unknown skull subcommand. Usage: ignite skull split [fingerprint] --shares=<n> --threshold=<k> [--out-dir=<dir>] | combine <share-file>... [--rewrap]".to_string(),
+        }),
+    }
+}
+
+/// The Skull named by `fingerprint`, or the vault's only active Skull
+fn resolve_skull(
+    chain: &AuthorityChain,
+    fingerprint: Option<&KeyFingerprint>,
+) -> IgniteResult<AuthorityKey> {
+    let key = if let Some(fingerprint) = fingerprint {
+        chain.get_key(fingerprint)
+    } else {
+        let active: Vec<&AuthorityKey> = chain
+            .get_keys_by_type(KeyType::Skull)
+            .into_iter()
+            .filter(|key| key.is_active())
+            .collect();
+        match active.as_slice() {
+            [key] => Some(*key),
+            [] => None,
+            _ => {
+                return Err(ignite::IgniteError::InvalidOperation {
+                    operation: "skull".to_string(),
+                    reason: "Several active Skull keys; pass the fingerprint to use".to_string(),
+                })
+            }
+        }
+    };
+
+    key.filter(|key| key.key_type() == KeyType::Skull)
+        .cloned()
+        .ok_or_else(|| ignite::IgniteError::InvalidOperation {
+            operation: "skull".to_string(),
+            reason: "No Skull key found in the vault".to_string(),
+        })
+}
+
+fn handle_skull_split(args: &Args) -> IgniteResult<()> {
+    use ignite::ignite::authority::{ignition, skull_shares};
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    // Parse arguments: ignite skull split [fingerprint] --shares=<n> --threshold=<k>
+    let count = |name: &str| {
+        get_var(&format!("opt_{}", name))
+            .parse::<u8>()
+            .map_err(|_| ignite::IgniteError::InvalidOperation {
+                operation: "skull_split".to_string(),
+                reason: format!(
+                    "--{} must be a number from 2 to 255. Usage: ignite skull split [fingerprint] --shares=<n> --threshold=<k>",
+                    name
+                ),
+            })
+    };
+    let shares = count("shares")?;
+    let threshold = count("threshold")?;
+
+    let fingerprint = match args.get_or(2, "") {
+        fingerprint if fingerprint.is_empty() || fingerprint.starts_with("--") => None,
+        fingerprint => Some(KeyFingerprint::from_string(&fingerprint)?),
+    };
+    let skull = resolve_skull(&AuthorityChain::load_from_vault()?, fingerprint.as_ref())?;
+    let policy_engine = PolicyEngine::load()?;
+    let signing_key = ignition::signing_key(&skull, &policy_engine, &passphrase_provider()?)?;
+    let split = skull_shares::split(&skull, &signing_key, shares, threshold)?;
+
+    let out_dir = get_var("opt_out_dir");
+    if out_dir.is_empty() {
+        for share in &split {
+            println!("{}", share.to_text().as_str());
+        }
+    } else {
+        let dir = std::path::PathBuf::from(&out_dir);
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| ignite::IgniteError::io_error("create_share_dir", dir.clone(), e))?;
+        for share in &split {
+            let path = dir.join(format!(
+                "skull-{}-share-{}-of-{}.txt",
+                share.set_id, share.index, share.shares
+            ));
+            std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .mode(0o600)
+                .open(&path)
+                .and_then(|mut out| out.write_all(share.to_text().as_bytes()))
+                .map_err(|e| ignite::IgniteError::io_error("write_skull_share", path.clone(), e))?;
+            println!("  {}", path.display());
+        }
+    }
+
+    eprintln!(
+        "✓ Skull {} split into {} shares; any {} recover it (set {})",
+        skull.fingerprint().short(),
+        shares,
+        threshold,
+        split[0].set_id
+    );
+    eprintln!("  Store each share separately; they are only safe apart");
+    Ok(())
+}
+
+fn handle_skull_combine(args: &Args) -> IgniteResult<()> {
+    use ignite::ignite::authority::skull_shares::{self, SkullShare};
+
+    // Parse arguments: ignite skull combine <share-file>... [--rewrap]
+    let files: Vec<String> = (2..)
+        .map(|i| args.get_or(i, ""))
+        .take_while(|file| !file.is_empty())
+        .filter(|file| !file.starts_with("--"))
+        .collect();
+    if files.is_empty() {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "skull_combine".to_string(),
+            reason: "Missing share files. Usage: ignite skull combine <share-file>... [--rewrap]"
+                .to_string(),
+        });
+    }
+
+    let mut shares = Vec::new();
+    for file in &files {
+        let path = std::path::PathBuf::from(file);
+        let text = std::fs::read_to_string(&path)
+            .map_err(|e| ignite::IgniteError::io_error("read_skull_share", path.clone(), e))?;
+        shares.extend(SkullShare::parse_all(&text)?);
+    }
+
+    let recovered = skull_shares::combine(&shares)?;
+    let mut skull = resolve_skull(
+        &AuthorityChain::load_from_vault()?,
+        Some(&recovered.fingerprint),
+    )?;
+    println!(
+        "✓ {} share(s) rebuild Skull {}",
+        shares.len(),
+        skull.fingerprint()
+    );
+
+    if get_var("opt_rewrap") == "true" {
+        require_danger_mode(DangerousOperation::OverwriteKey, &skull)?;
+        let policy_engine = PolicyEngine::load()?;
+        let passphrase = passphrase_provider()?.obtain(&PassphraseRequest::new_key(
+            KeyType::Skull,
+            Some(skull.fingerprint()),
+        ))?;
+        skull_shares::rewrap(&mut skull, &recovered, passphrase.expose(), &policy_engine)?;
+        skull.save()?;
+        println!("✓ Skull re-wrapped under the new passphrase");
+    } else {
+        println!("  Key material verified and discarded; pass --rewrap to set a new passphrase");
+    }
+    Ok(())
+}
+
+fn handle_audit(args: &Args) -> IgniteResult<()> {
+    // Parse arguments: ignite audit <subcommand>
+    match args.get_or(1, "").as_str() {
+        "verify" => handle_audit_verify(),
+        "log" => handle_audit_log(),
+        _ => Err(ignite::IgniteError::InvalidOperation {
+            operation: "audit".to_string(),
+            reason: "Unknown audit subcommand. Usage: ignite audit verify | log [--fingerprint=<prefix>] [--operation=<op>] [--actor=<user>] [--since=<time>] [--until=<time>] [--outcome=<outcome>] [--operation-id=<uuid>] [--json]".to_string(),
+        }),
+    }
+}
+
+fn handle_audit_log() -> IgniteResult<()> {
+    use hub::data_ext::serde_json;
+    use hub::time_ext::chrono::Utc;
+    use ignite::ignite::logging::AuditQuery;
+
+    // Parse arguments: ignite audit log [filters] [--json]
+    let now = Utc::now();
+    let flag = |name: &str| Some(get_var(&format!("opt_{}", name))).filter(|v| !v.is_empty());
+    let time = |name: &str| {
+        flag(name)
+            .map(|value| logging::parse_time(&value, now))
+            .transpose()
+    };
+    let mut query = AuditQuery {
+        operation: flag("operation"),
+        actor: flag("actor"),
+        since: time("since")?,
+        until: time("until")?,
+        outcome: flag("outcome").map(AuditOutcome::try_from).transpose()?,
+        operation_id: flag("operation_id"),
+        ..Default::default()
+    };
+    if let Some(prefix) = flag("fingerprint") {
+        query = query.with_key_tree(&prefix, &AuthorityChain::load_from_vault()?)?;
+    }
+
+    let entries = logging::query(&query)?;
+    if get_var("opt_json") == "true" {
+        let json = serde_json::to_string_pretty(&entries).map_err(|e| {
+            ignite::IgniteError::crypto_error("serialize_audit_entries", e.to_string())
+        })?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    for entry in &entries {
+        let keys = entry
+            .fingerprints
+            .iter()
+            .map(|fingerprint| {
+                KeyFingerprint::from_string(fingerprint)
+                    .map_or_else(|_| fingerprint.clone(), |fingerprint| fingerprint.short())
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let line = format!(
+            "{}  {:<8} {:<8} {:<16} {:<8} {:<17} {}",
+            entry.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            entry
+                .operation_id
+                .as_deref()
+                .map_or("-", |id| &id[..id.len().min(8)]),
+            entry.actor,
+            entry.operation,
+            entry.outcome.as_str(),
+            if keys.is_empty() { "-" } else { &keys },
+            entry.message
+        );
+        println!("{}", line.trim_end());
+    }
+    println!(
+        "{} matching entr{}",
+        entries.len(),
+        if entries.len() == 1 { "y" } else { "ies" }
+    );
+    Ok(())
+}
+
+fn handle_audit_verify() -> IgniteResult<()> {
+    use ignite::ignite::utils;
+
+    let verification = logging::verify()?;
+    println!("Audit log: {}", utils::audit_log_path().display());
+    match &verification.first_break {
+        None => {
+            println!("✓ {} chained entries verified", verification.entries);
+            Ok(())
+        }
+        Some(chain_break) => {
+            println!(
+                "  {} chained entries verified before the break",
+                verification.entries
+            );
+            println!("✗ Chain broken at line {}", chain_break.line);
+            Err(ignite::IgniteError::CryptoError {
+                operation: "verify_audit_log".to_string(),
+                reason: chain_break.reason.clone(),
+            })
+        }
+    }
+}
+
+fn handle_verify_chain(args: &Args) -> IgniteResult<()> {
+    use ignite::ignite::authority::lineage::{self, HopStatus};
+
+    // Parse arguments: ignite verify-chain <fingerprint>
+    let fingerprint_str = args.get_or(1, "");
+    if fingerprint_str.is_empty() {
+        return Err(ignite::IgniteError::InvalidOperation {
+            operation: "verify_chain".to_string(),
+            reason: "Missing fingerprint argument. Usage: ignite verify-chain <fingerprint>"
+                .to_string(),
+        });
+    }
+
+    let fingerprint = KeyFingerprint::from_string(&fingerprint_str)?;
+    let chain = AuthorityChain::load_from_vault()?;
+    let report = lineage::verify_lineage(&chain, &fingerprint)?;
+
+    println!("Lineage for {}", report.leaf);
+    for hop in &report.hops {
+        let marker = if hop.status == HopStatus::Valid {
+            "✓"
+        } else {
+            "✗"
+        };
+        let parent = match (&hop.parent, hop.parent_type) {
+            (Some(fp), Some(key_type)) => format!("{} {}", key_type, fp.short()),
+            (None, Some(key_type)) => format!("{} <none>", key_type),
+            _ => "<none>".to_string(),
+        };
+        println!(
+            "  {} {:<8} {} → {} {}: {}",
+            marker,
+            hop.status,
+            parent,
+            hop.child_type,
+            hop.child.short(),
+            hop.detail
+        );
+        if let Some(proof_path) = &hop.proof_path {
+            println!("      proof: {}", proof_path.display());
+        }
+    }
+
+    if report.is_valid() {
+        let root = report
+            .root
+            .as_ref()
+            .map(|fp| fp.to_string())
+            .unwrap_or_default();
+        println!("✓ Lineage verified: rooted at skull {}", root);
+        return Ok(());
+    }
+
+    let failed = report
+        .hops
+        .iter()
+        .filter(|hop| hop.status != HopStatus::Valid)
+        .count();
+    Err(ignite::IgniteError::InvalidOperation {
+        operation: "verify_chain".to_string(),
+        reason: if report.root.is_none() {
+            "lineage does not reach a skull key".to_string()
+        } else {
+            format!(
+                "{} of {} hop(s) failed verification",
+                failed,
+                report.hops.len()
+            )
+        },
+    })
+}
+
+fn handle_verify(args: &Args) -> IgniteResult<()> {
+    use ignite::ignite::authority::proofs::{ProofBundle, ProofPair};
+    use ignite::ignite::authority::storage;
+    use ignite::IgniteError;
+    use std::fs;
+    use std::path::Path;
+
+    // Parse arguments: ignite verify <file>
+    let file = args.get_or(1, "");
+    if file.is_empty() {
+        return Err(IgniteError::InvalidOperation {
+            operation: "verify".to_string(),
+            reason: "Missing file argument. Usage: ignite verify <file>".to_string(),
+        });
+    }
+
+    let path = Path::new(&file);
+    if !path.exists() {
+        return Err(IgniteError::InvalidOperation {
+            operation: "verify_file".to_string(),
+            reason: format!("File '{}' does not exist", file),
+        });
+    }
+
+    println!("Verifying file: {}", file);
+
+    // Try to read the file
+    let content = fs::read_to_string(path).map_err(|e| IgniteError::InvalidOperation {
+        operation: "read_file".to_string(),
+        reason: format!("Failed to read file '{}': {}", file, e),
+    })?;
+
+    // Try to parse as ProofBundle first
+    if let Ok(proof) = hub::data_ext::serde_json::from_str::<ProofBundle>(&content) {
+        println!("✓ File is a valid proof bundle");
+        println!(
+            "  Expires at: {}",
+            proof.expires_at.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+        println!("  Digest: {}", proof.digest);
+
+        // Both directions must be present: locate the counterpart bundle
+        let counterpart_path = storage::counterpart_proof_path(path)
+            .filter(|p| p.exists())
+            .ok_or_else(|| IgniteError::InvalidOperation {
+                operation: "verify_proof".to_string(),
+                reason: format!(
+                    "Proof verification failed: no counterpart claim/receipt found for '{}'",
+                    file
+                ),
+            })?;
+        let counterpart_content =
+            fs::read_to_string(&counterpart_path).map_err(|e| IgniteError::InvalidOperation {
+                operation: "read_file".to_string(),
+                reason: format!(
+                    "Failed to read file '{}': {}",
+                    counterpart_path.display(),
+                    e
+                ),
+            })?;
+        let counterpart = hub::data_ext::serde_json::from_str::<ProofBundle>(&counterpart_content)
+            .map_err(|e| IgniteError::InvalidOperation {
+                operation: "verify_proof".to_string(),
+                reason: format!("Counterpart is not a valid proof bundle: {}", e),
+            })?;
+
+        let pair = if proof.claim().is_ok() {
+            ProofPair::new(proof, counterpart)
+        } else {
+            ProofPair::new(counterpart, proof)
+        };
+
+        // Verify the proof pair
+        match pair.verify() {
+            Ok((claim, receipt)) => {
+                println!("✓ Proof signature verification passed");
+                println!("✓ Subject receipt verification passed");
+
+                println!("\nAuthority Claim Details:");
+                println!("  Parent: {}", claim.parent_fp);
+                println!("  Child: {}", claim.child_fp);
+                println!("  Purpose: {}", claim.purpose);
+                println!(
+                    "  Issued at: {}",
+                    claim.issued_at.format("%Y-%m-%d %H:%M:%S UTC")
+                );
+                println!(
+                    "  Acknowledged at: {}",
+                    receipt.acknowledged_at.format("%Y-%m-%d %H:%M:%S UTC")
+                );
+
+                return Ok(());
+            }
+            Err(e) => {
+                return Err(IgniteError::InvalidOperation {
+                    operation: "verify_proof".to_string(),
+                    reason: format!("Proof verification failed: {}", e),
+                });
+            }
+        }
+    }
+
+    // Try to parse as manifest
+    if let Ok(manifest) = hub::data_ext::serde_json::from_str::<
+        ignite::ignite::authority::manifests::AffectedKeyManifest,
+    >(&content)
+    {
+        println!("✓ File is a valid manifest");
+        println!("  Schema version: {}", manifest.schema_version);
+        println!("  Event type: {:?}", manifest.event.event_type);
+        println!(
+            "  Parent fingerprint: {}",
+            manifest.event.parent_fingerprint
+        );
+        println!("  Children count: {}", manifest.children.len());
+
+        // Digest, signature and the signer's vault key must all check out
+        match manifest.verify_full(&AuthorityChain::load_from_vault()?) {
+            Ok(warnings) => {
+                println!("✓ Digest verification passed");
+                println!(
+                    "✓ Signature by parent {} verified against the vault",
+                    manifest.event.parent_fingerprint.short()
+                );
+                for warning in warnings {
+                    println!("⚠ {}", warning);
+                }
                 return Ok(());
             }
             Err(e) => {
                 return Err(IgniteError::InvalidOperation {
-                    operation: "verify_digest".to_string(),
-                    reason: format!("Digest verification failed: {}", e),
+                    operation: "verify_manifest".to_string(),
+                    reason: format!("Manifest verification failed: {}", e),
                 });
             }
         }
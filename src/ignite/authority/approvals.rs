@@ -0,0 +1,1042 @@
+//! Dual control (M-of-N approvals) for Skull and Master operations.
+//!
+//! Security invariant #5: no single operator may create, rotate or revoke a
+//! Skull or Master key. The operation is first written down as an
+//! `OperationRequest`; registered approvers then each sign the request's
+//! digest with their own Ed25519 key. The operation proceeds only while at
+//! least the configured threshold of distinct, unexpired approvals verify
+//! against the exact request, and the request is marked consumed as it is
+//! used so its approvals cannot be replayed.
+//!
+//! The approver registry is itself under dual control. It is founded by the
+//! Skull key, which signs the first approvers into it together, at least as
+//! many as the Skull threshold. Every approver after that takes a
+//! `register-approver` request approved by that many existing approvers, and
+//! no request is authorized unless the founding signature verifies against
+//! the Skull key in the vault.
+//!
+//! Layout: `approvals/<request id>/request.json`, one
+//! `approvals/<request id>/approvals/<approver>.json` per signature, and a
+//! write-once `consumed.json`. Approver public keys live in
+//! `metadata/approvers.json`.
+
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use hub::data_ext::serde::{Deserialize, Serialize};
+use hub::data_ext::uuid::Uuid;
+use hub::time_ext::chrono::{DateTime, Duration, Utc};
+use sha2::{Digest, Sha256};
+
+use super::canonical;
+use super::chain::{AuthorityChain, AuthorityKey, KeyFingerprint, KeyType};
+use super::storage;
+use crate::ignite::error::{IgniteError, Result};
+
+/// Default lifetime of an operation request and its approvals
+pub const APPROVAL_TTL_HOURS: i64 = 24;
+
+/// Default approvals required for Skull and Master operations (dual control)
+pub const DEFAULT_APPROVAL_THRESHOLD: usize = 2;
+
+/// Domain-separation tag prefixed to the request digest before signing
+const APPROVAL_DOMAIN: &str = "ignite-approval-v1";
+
+/// Domain-separation tag for the Skull signature founding the approver registry
+const REGISTRY_FOUNDING_DOMAIN: &str = "ignite-approver-registry-v1";
+
+/// Operation an approval authorizes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum ApprovalAction {
+    Create,
+    Rotate,
+    Revoke,
+    RegisterApprover,
+}
+
+impl ApprovalAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApprovalAction::Create => "create",
+            ApprovalAction::Rotate => "rotate",
+            ApprovalAction::Revoke => "revoke",
+            ApprovalAction::RegisterApprover => "register-approver",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "create" => Ok(ApprovalAction::Create),
+            "rotate" => Ok(ApprovalAction::Rotate),
+            "revoke" => Ok(ApprovalAction::Revoke),
+            "register-approver" => Ok(ApprovalAction::RegisterApprover),
+            _ => Err(IgniteError::InvalidOperation {
+                operation: "parse_approval_action".to_string(),
+                reason: format!(
+                    "Unknown action: {} (expected create|rotate|revoke|register-approver)",
+                    s
+                ),
+            }),
+        }
+    }
+}
+
+impl From<ApprovalAction> for String {
+    fn from(action: ApprovalAction) -> String {
+        action.as_str().to_string()
+    }
+}
+
+impl TryFrom<String> for ApprovalAction {
+    type Error = IgniteError;
+
+    fn try_from(s: String) -> Result<Self> {
+        Self::from_str(&s)
+    }
+}
+
+impl std::fmt::Display for ApprovalAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Approver a `register-approver` request would add
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProposedApprover {
+    pub name: String,
+    /// Hex-encoded Ed25519 public key
+    pub public_key: String,
+}
+
+/// Description of an intended Skull or Master operation, or approver registration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperationRequest {
+    pub schema_version: String,
+    pub id: String,
+    pub action: ApprovalAction,
+    pub key_type: KeyType,
+    /// Key being rotated or revoked
+    pub target: Option<KeyFingerprint>,
+    /// Parent the new key will be issued under (Master create)
+    pub parent: Option<KeyFingerprint>,
+    /// Approver being added (`register-approver` only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approver: Option<ProposedApprover>,
+    pub reason: String,
+    pub requested_by: String,
+    #[serde(with = "canonical::timestamp")]
+    pub requested_at: DateTime<Utc>,
+    #[serde(with = "canonical::timestamp")]
+    pub expires_at: DateTime<Utc>,
+}
+
+impl OperationRequest {
+    pub fn new(
+        action: ApprovalAction,
+        key_type: KeyType,
+        target: Option<KeyFingerprint>,
+        parent: Option<KeyFingerprint>,
+        reason: impl Into<String>,
+        requested_by: impl Into<String>,
+        ttl: Duration,
+    ) -> Result<Self> {
+        let invalid = |reason: String| IgniteError::InvalidOperation {
+            operation: "request_approval".to_string(),
+            reason,
+        };
+        if !matches!(key_type, KeyType::Skull | KeyType::Master) {
+            return Err(invalid(format!(
+                "Approvals cover Skull and Master operations, not {} keys",
+                key_type
+            )));
+        }
+        match (action, &target) {
+            (ApprovalAction::RegisterApprover, _) => {
+                return Err(invalid(
+                    "Approver registrations are filed with OperationRequest::register_approver"
+                        .to_string(),
+                ));
+            }
+            (ApprovalAction::Create, Some(_)) => {
+                return Err(invalid("A create request has no target key".to_string()));
+            }
+            (ApprovalAction::Rotate | ApprovalAction::Revoke, None) => {
+                return Err(invalid(format!(
+                    "A {} request must name the target key",
+                    action
+                )));
+            }
+            _ => {}
+        }
+
+        let requested_at = Utc::now();
+        Ok(Self {
+            schema_version: "1.0".to_string(),
+            id: Uuid::new_v4().to_string(),
+            action,
+            key_type,
+            target,
+            parent,
+            approver: None,
+            reason: reason.into(),
+            requested_by: requested_by.into(),
+            requested_at,
+            expires_at: requested_at + ttl,
+        })
+    }
+
+    /// Request to add `name` with `public_key` to the approver registry
+    ///
+    /// Registry changes are approved at the Skull threshold, so the request is
+    /// filed against the Skull tier.
+    pub fn register_approver(
+        name: &str,
+        public_key: &VerifyingKey,
+        reason: impl Into<String>,
+        requested_by: impl Into<String>,
+        ttl: Duration,
+    ) -> Result<Self> {
+        if !is_valid_approver_name(name) {
+            return Err(IgniteError::InvalidOperation {
+                operation: "request_approval".to_string(),
+                reason: format!(
+                    "Approver name '{}' must be 1-64 characters of [A-Za-z0-9._-]",
+                    name
+                ),
+            });
+        }
+
+        let requested_at = Utc::now();
+        Ok(Self {
+            schema_version: "1.0".to_string(),
+            id: Uuid::new_v4().to_string(),
+            action: ApprovalAction::RegisterApprover,
+            key_type: KeyType::Skull,
+            target: None,
+            parent: None,
+            approver: Some(ProposedApprover {
+                name: name.to_string(),
+                public_key: encode_hex(public_key.as_bytes()),
+            }),
+            reason: reason.into(),
+            requested_by: requested_by.into(),
+            requested_at,
+            expires_at: requested_at + ttl,
+        })
+    }
+
+    /// Compute SHA256 digest of canonical payload
+    pub fn compute_digest(&self) -> Result<String> {
+        let canonical = canonical::to_canonical_json(self)?;
+        let mut hasher = Sha256::new();
+        hasher.update(canonical.as_bytes());
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        now > self.expires_at
+    }
+
+    /// True if this request authorizes exactly the given operation
+    pub fn covers(
+        &self,
+        action: ApprovalAction,
+        key_type: KeyType,
+        target: Option<&KeyFingerprint>,
+        parent: Option<&KeyFingerprint>,
+    ) -> bool {
+        self.action == action
+            && self.key_type == key_type
+            && self.target.as_ref() == target
+            && self.parent.as_ref() == parent
+            && self.approver.is_none()
+    }
+
+    /// True if this request authorizes registering exactly `name` with `public_key`
+    pub fn covers_registration(&self, name: &str, public_key: &VerifyingKey) -> bool {
+        self.action == ApprovalAction::RegisterApprover
+            && self.approver.as_ref().is_some_and(|approver| {
+                approver.name == name && approver.public_key == encode_hex(public_key.as_bytes())
+            })
+    }
+
+    /// One-line summary, e.g. `rotate skull 9ff0bae5`
+    pub fn describe(&self) -> String {
+        if let Some(approver) = &self.approver {
+            return format!("{} {}", self.action, approver.name);
+        }
+        let subject = self.target.as_ref().or(self.parent.as_ref());
+        match (self.action, subject) {
+            (ApprovalAction::Create, Some(parent)) => {
+                format!("create {} under {}", self.key_type, parent.short())
+            }
+            (_, Some(target)) => format!("{} {} {}", self.action, self.key_type, target.short()),
+            (_, None) => format!("{} {}", self.action, self.key_type),
+        }
+    }
+}
+
+/// A person allowed to approve operations, identified by their own Ed25519 key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Approver {
+    pub name: String,
+    pub public_key: Vec<u8>,
+    #[serde(with = "canonical::timestamp")]
+    pub registered_at: DateTime<Utc>,
+}
+
+impl Approver {
+    pub fn verifying_key(&self) -> Result<VerifyingKey> {
+        let bytes: [u8; 32] = self.public_key.as_slice().try_into().map_err(|_| {
+            IgniteError::crypto_error("parse_approver_key", "Invalid public key length")
+        })?;
+        VerifyingKey::from_bytes(&bytes)
+            .map_err(|e| IgniteError::crypto_error("parse_approver_key", e.to_string()))
+    }
+}
+
+/// Skull signature admitting the founding approvers to the registry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegistryFounding {
+    pub skull: KeyFingerprint,
+    /// Founding approvers, in registration order
+    pub approvers: Vec<String>,
+    #[serde(with = "canonical::timestamp")]
+    pub signed_at: DateTime<Utc>,
+    pub signature: Vec<u8>,
+}
+
+/// Registered approvers, stored in `metadata/approvers.json`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApproverRegistry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub founding: Option<RegistryFounding>,
+    pub approvers: Vec<Approver>,
+}
+
+impl ApproverRegistry {
+    /// Registry whose first approvers are signed in by the Skull key `skull`
+    ///
+    /// Registrations need approvals from the first approver on, so at least
+    /// `threshold` approvers (and never fewer than one) are founded together.
+    pub fn found(
+        founders: &[(String, VerifyingKey)],
+        skull: &AuthorityKey,
+        signing_key: &SigningKey,
+        threshold: usize,
+    ) -> Result<Self> {
+        let invalid = |reason: String| IgniteError::InvalidOperation {
+            operation: "found_approver_registry".to_string(),
+            reason,
+        };
+        if skull.key_type() != KeyType::Skull || !skull.is_active() {
+            return Err(invalid(format!(
+                "{} is not an active Skull key",
+                skull.fingerprint()
+            )));
+        }
+        if signing_key.verifying_key().to_bytes().as_slice() != skull.key_material().public_key() {
+            return Err(invalid(format!(
+                "Signing key does not belong to Skull {}",
+                skull.fingerprint()
+            )));
+        }
+        if founders.len() < threshold.max(1) {
+            return Err(invalid(format!(
+                "The registry must be founded with at least {} approver(s) so later registrations can be approved; got {}",
+                threshold.max(1),
+                founders.len()
+            )));
+        }
+
+        let mut registry = Self::default();
+        for (name, public_key) in founders {
+            registry.add(name, public_key)?;
+        }
+        let signed_at = Utc::now();
+        let message = Self::founding_message(skull.fingerprint(), &registry.approvers, &signed_at);
+        registry.founding = Some(RegistryFounding {
+            skull: skull.fingerprint().clone(),
+            approvers: registry.approvers.iter().map(|a| a.name.clone()).collect(),
+            signed_at,
+            signature: signing_key.sign(&message).to_bytes().to_vec(),
+        });
+        Ok(registry)
+    }
+
+    /// Check the founding signature against the Skull key in `chain`
+    ///
+    /// The Skull may have been rotated since; it must only still be in the
+    /// vault and not have been tombstoned before it signed.
+    pub fn verify_founding(&self, chain: &AuthorityChain) -> Result<()> {
+        let invalid = |reason: String| IgniteError::InvalidOperation {
+            operation: "verify_approver_registry".to_string(),
+            reason,
+        };
+        let founding = self.founding.as_ref().ok_or_else(|| {
+            invalid(
+                "Approver registry has no Skull founding signature; found it with `ignite approval register`"
+                    .to_string(),
+            )
+        })?;
+        let founders = self
+            .approvers
+            .get(..founding.approvers.len())
+            .filter(|founders| {
+                founders
+                    .iter()
+                    .map(|approver| &approver.name)
+                    .eq(founding.approvers.iter())
+            })
+            .ok_or_else(|| invalid("Founding approvers do not match the registry".to_string()))?;
+
+        let skull = chain
+            .get_key(&founding.skull)
+            .filter(|key| key.key_type() == KeyType::Skull)
+            .ok_or_else(|| {
+                invalid(format!(
+                    "Founding Skull {} is not in the vault",
+                    founding.skull
+                ))
+            })?;
+        if let Some(tombstone) = chain.tombstone(&founding.skull) {
+            if tombstone.revoked_at <= founding.signed_at {
+                return Err(invalid(format!(
+                    "Founding Skull {} was tombstoned before it signed the registry",
+                    founding.skull
+                )));
+            }
+        }
+
+        let public_key: [u8; 32] = skull.key_material().public_key().try_into().map_err(|_| {
+            IgniteError::crypto_error(
+                "verify_approver_registry",
+                "Invalid Skull public key length",
+            )
+        })?;
+        let signature =
+            Signature::from_bytes(founding.signature.as_slice().try_into().map_err(|_| {
+                IgniteError::crypto_error("parse_signature", "Invalid signature length")
+            })?);
+        VerifyingKey::from_bytes(&public_key)
+            .and_then(|key| {
+                key.verify_strict(
+                    &Self::founding_message(&founding.skull, founders, &founding.signed_at),
+                    &signature,
+                )
+            })
+            .map_err(|e| IgniteError::crypto_error("verify_approver_registry", e.to_string()))
+    }
+
+    /// Bytes the Skull signs: domain, Skull, time and every founder's name and key
+    fn founding_message(
+        skull: &KeyFingerprint,
+        founders: &[Approver],
+        signed_at: &DateTime<Utc>,
+    ) -> Vec<u8> {
+        let founders: Vec<String> = founders
+            .iter()
+            .map(|approver| format!("{}={}", approver.name, encode_hex(&approver.public_key)))
+            .collect();
+        format!(
+            "{}:{}:{}:{}",
+            REGISTRY_FOUNDING_DOMAIN,
+            skull,
+            canonical::timestamp::format(signed_at),
+            founders.join(",")
+        )
+        .into_bytes()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Approver> {
+        self.approvers.iter().find(|approver| approver.name == name)
+    }
+
+    /// Add an approver to a founded registry; names and keys must both be unique
+    ///
+    /// Callers authorize the addition with `authorize_registration` first.
+    pub fn register(&mut self, name: &str, public_key: &VerifyingKey) -> Result<&Approver> {
+        if self.founding.is_none() {
+            return Err(IgniteError::InvalidOperation {
+                operation: "register_approver".to_string(),
+                reason: "Approver registry has not been founded by the Skull key".to_string(),
+            });
+        }
+        self.add(name, public_key)
+    }
+
+    fn add(&mut self, name: &str, public_key: &VerifyingKey) -> Result<&Approver> {
+        let invalid = |reason: String| IgniteError::InvalidOperation {
+            operation: "register_approver".to_string(),
+            reason,
+        };
+        if !is_valid_approver_name(name) {
+            return Err(invalid(format!(
+                "Approver name '{}' must be 1-64 characters of [A-Za-z0-9._-]",
+                name
+            )));
+        }
+        if self.get(name).is_some() {
+            return Err(invalid(format!(
+                "Approver '{}' is already registered",
+                name
+            )));
+        }
+        let public_key = public_key.to_bytes().to_vec();
+        if let Some(existing) = self
+            .approvers
+            .iter()
+            .find(|approver| approver.public_key == public_key)
+        {
+            return Err(invalid(format!(
+                "That key is already registered to '{}'",
+                existing.name
+            )));
+        }
+
+        self.approvers.push(Approver {
+            name: name.to_string(),
+            public_key,
+            registered_at: Utc::now(),
+        });
+        Ok(self.approvers.last().expect("just pushed"))
+    }
+}
+
+/// One approver's signature over a request digest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Approval {
+    pub schema_version: String,
+    pub request_id: String,
+    pub request_digest: String,
+    pub approver: String,
+    #[serde(with = "canonical::timestamp")]
+    pub signed_at: DateTime<Utc>,
+    #[serde(with = "canonical::timestamp")]
+    pub expires_at: DateTime<Utc>,
+    pub signature: Vec<u8>,
+}
+
+impl Approval {
+    /// Sign `request` as `approver`; the approval never outlives the request
+    pub fn sign(
+        request: &OperationRequest,
+        approver: &str,
+        signing_key: &SigningKey,
+        ttl: Duration,
+    ) -> Result<Self> {
+        let signed_at = Utc::now();
+        let mut approval = Self {
+            schema_version: "1.0".to_string(),
+            request_id: request.id.clone(),
+            request_digest: request.compute_digest()?,
+            approver: approver.to_string(),
+            signed_at,
+            expires_at: (signed_at + ttl).min(request.expires_at),
+            signature: Vec::new(),
+        };
+        approval.signature = signing_key
+            .sign(&approval.signing_message())
+            .to_bytes()
+            .to_vec();
+        Ok(approval)
+    }
+
+    /// Bytes actually signed: domain, request digest, approver and validity window
+    fn signing_message(&self) -> Vec<u8> {
+        format!(
+            "{}:{}:{}:{}:{}",
+            APPROVAL_DOMAIN,
+            self.request_digest,
+            self.approver,
+            canonical::timestamp::format(&self.signed_at),
+            canonical::timestamp::format(&self.expires_at)
+        )
+        .into_bytes()
+    }
+
+    /// Check binding to `request`, the validity window and the signature
+    pub fn verify(
+        &self,
+        request: &OperationRequest,
+        registry: &ApproverRegistry,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        let invalid = |reason: String| IgniteError::InvalidOperation {
+            operation: "verify_approval".to_string(),
+            reason,
+        };
+        if self.request_id != request.id || self.request_digest != request.compute_digest()? {
+            return Err(invalid("approval is for a different request".to_string()));
+        }
+        if self.expires_at > request.expires_at || self.signed_at > self.expires_at {
+            return Err(invalid("approval validity window is malformed".to_string()));
+        }
+        if now > self.expires_at {
+            return Err(invalid(format!(
+                "approval expired at {}",
+                self.expires_at.format("%Y-%m-%d %H:%M:%S UTC")
+            )));
+        }
+
+        let approver = registry
+            .get(&self.approver)
+            .ok_or_else(|| invalid(format!("'{}' is not a registered approver", self.approver)))?;
+        let signature =
+            Signature::from_bytes(self.signature.as_slice().try_into().map_err(|_| {
+                IgniteError::crypto_error("parse_signature", "Invalid signature length")
+            })?);
+        approver
+            .verifying_key()?
+            .verify_strict(&self.signing_message(), &signature)
+            .map_err(|e| IgniteError::crypto_error("verify_approval", e.to_string()))
+    }
+}
+
+/// Marker written when a request is spent on its operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApprovalConsumption {
+    pub request_id: String,
+    pub consumed_by: String,
+    #[serde(with = "canonical::timestamp")]
+    pub consumed_at: DateTime<Utc>,
+}
+
+/// Approval state of one request against a threshold
+#[derive(Debug, Clone)]
+pub struct ApprovalStatus {
+    pub request: OperationRequest,
+    pub threshold: usize,
+    /// Approvers whose signatures verify, sorted by name
+    pub approved_by: Vec<String>,
+    /// Attached approvals that do not count, with the reason
+    pub rejected: Vec<(String, String)>,
+    pub consumed: Option<ApprovalConsumption>,
+}
+
+impl ApprovalStatus {
+    /// Evaluate every approval attached to `request` at `now`
+    pub fn evaluate(
+        request: OperationRequest,
+        threshold: usize,
+        now: DateTime<Utc>,
+    ) -> Result<Self> {
+        let registry = storage::load_approver_registry()?;
+        let mut approved_by = Vec::new();
+        let mut rejected = Vec::new();
+        for approval in storage::load_approvals(&request.id)? {
+            match approval.verify(&request, &registry, now) {
+                Ok(()) => approved_by.push(approval.approver),
+                Err(e) => rejected.push((approval.approver, e.to_string())),
+            }
+        }
+        approved_by.sort();
+        approved_by.dedup();
+
+        let consumed = storage::load_approval_consumption(&request.id)?;
+        Ok(Self {
+            request,
+            threshold,
+            approved_by,
+            rejected,
+            consumed,
+        })
+    }
+
+    pub fn is_satisfied(&self) -> bool {
+        self.approved_by.len() >= self.threshold
+    }
+}
+
+/// File a new request in the vault
+pub fn submit(request: &OperationRequest) -> Result<std::path::PathBuf> {
+    storage::save_operation_request(request)
+}
+
+/// Verify `request_id` authorizes the operation and enough approvals are attached
+///
+/// Fails if the approver registry's founding signature does not verify, or
+/// the request names a different operation, has expired, was already
+/// consumed, or carries fewer than `threshold` valid approvals.
+pub fn authorize(
+    request_id: &str,
+    action: ApprovalAction,
+    key_type: KeyType,
+    target: Option<&KeyFingerprint>,
+    parent: Option<&KeyFingerprint>,
+    threshold: usize,
+) -> Result<ApprovalStatus> {
+    authorize_matching(request_id, threshold, |request| {
+        request.covers(action, key_type, target, parent)
+    })
+}
+
+/// Verify `request_id` authorizes registering `name` with `public_key`
+pub fn authorize_registration(
+    request_id: &str,
+    name: &str,
+    public_key: &VerifyingKey,
+    threshold: usize,
+) -> Result<ApprovalStatus> {
+    authorize_matching(request_id, threshold, |request| {
+        request.covers_registration(name, public_key)
+    })
+}
+
+fn authorize_matching<F>(request_id: &str, threshold: usize, covers: F) -> Result<ApprovalStatus>
+where
+    F: FnOnce(&OperationRequest) -> bool,
+{
+    storage::load_approver_registry()?.verify_founding(&AuthorityChain::load_from_vault()?)?;
+    let request = storage::load_operation_request(request_id)?;
+    let denied = |reason: String| IgniteError::InvalidOperation {
+        operation: "authorize".to_string(),
+        reason,
+    };
+
+    if !covers(&request) {
+        return Err(denied(format!(
+            "Request {} approves '{}', not this operation",
+            request.id,
+            request.describe()
+        )));
+    }
+    let now = Utc::now();
+    if request.is_expired_at(now) {
+        return Err(denied(format!("Request {} has expired", request.id)));
+    }
+
+    let status = ApprovalStatus::evaluate(request, threshold, now)?;
+    if let Some(consumed) = &status.consumed {
+        return Err(denied(format!(
+            "Request {} was already used at {}",
+            status.request.id,
+            consumed.consumed_at.format("%Y-%m-%d %H:%M:%S UTC")
+        )));
+    }
+    if !status.is_satisfied() {
+        return Err(denied(format!(
+            "Request {} has {} of {} required approvals",
+            status.request.id,
+            status.approved_by.len(),
+            threshold
+        )));
+    }
+    Ok(status)
+}
+
+/// Mark `request` as spent so its approvals cannot authorize another operation
+///
+/// Call this right after `authorize` and before the operation runs; a request
+/// can be consumed only once.
+pub fn consume(request: &OperationRequest, consumed_by: &str) -> Result<std::path::PathBuf> {
+    storage::save_approval_consumption(&ApprovalConsumption {
+        request_id: request.id.clone(),
+        consumed_by: consumed_by.to_string(),
+        consumed_at: Utc::now(),
+    })
+}
+
+/// Parse a 32-byte Ed25519 public key from hex
+pub fn parse_public_key(hex: &str) -> Result<VerifyingKey> {
+    let bytes: [u8; 32] = decode_hex(hex.trim())
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| IgniteError::InvalidKey {
+            reason: "Approver public key must be 64 hex characters".to_string(),
+        })?;
+    VerifyingKey::from_bytes(&bytes)
+        .map_err(|e| IgniteError::crypto_error("parse_approver_key", e.to_string()))
+}
+
+/// Parse a 32-byte Ed25519 secret key from hex
+pub fn parse_signing_key(hex: &str) -> Result<SigningKey> {
+    let bytes: [u8; 32] = decode_hex(hex.trim())
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| IgniteError::InvalidKey {
+            reason: "Approver key file must hold 64 hex characters".to_string(),
+        })?;
+    Ok(SigningKey::from_bytes(&bytes))
+}
+
+pub fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Request ids are UUIDs; anything else never reaches the filesystem
+pub fn is_valid_request_id(id: &str) -> bool {
+    Uuid::parse_str(id).is_ok()
+}
+
+/// Approver names double as file names, so they are kept to a safe alphabet
+pub fn is_valid_approver_name(name: &str) -> bool {
+    (1..=64).contains(&name.len())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ignite::test_support::{save_chain, VaultEnvironment};
+    use serial_test::serial;
+
+    fn fingerprint(byte: u8) -> KeyFingerprint {
+        KeyFingerprint::from_string(&format!("SHA256:{}", encode_hex(&[byte; 32]))).unwrap()
+    }
+
+    fn approver_key() -> SigningKey {
+        SigningKey::from_bytes(&hub::random_ext::rand::random())
+    }
+
+    /// Save a Skull to the vault and found the registry with `names` under it
+    fn register(names: &[&str]) -> Vec<SigningKey> {
+        let skull = save_chain(&[KeyType::Skull]).remove(0);
+        let keys: Vec<SigningKey> = names.iter().map(|_| approver_key()).collect();
+        let founders: Vec<(String, VerifyingKey)> = names
+            .iter()
+            .zip(&keys)
+            .map(|(name, key)| (name.to_string(), key.verifying_key()))
+            .collect();
+        let registry = ApproverRegistry::found(
+            &founders,
+            &skull,
+            &skull.key_material().signing_key().unwrap(),
+            names.len(),
+        )
+        .unwrap();
+        storage::save_approver_registry(&registry).unwrap();
+        keys
+    }
+
+    #[test]
+    #[serial]
+    fn test_threshold_of_bound_approvals() {
+        let _env = VaultEnvironment::new();
+        let keys = register(&["alice", "bob", "carol"]);
+        let target = fingerprint(7);
+        let ttl = Duration::hours(APPROVAL_TTL_HOURS);
+
+        let request = OperationRequest::new(
+            ApprovalAction::Rotate,
+            KeyType::Skull,
+            Some(target.clone()),
+            None,
+            "scheduled rotation",
+            "ops",
+            ttl,
+        )
+        .unwrap();
+        submit(&request).unwrap();
+        let authorize_rotation = || {
+            authorize(
+                &request.id,
+                ApprovalAction::Rotate,
+                KeyType::Skull,
+                Some(&target),
+                None,
+                2,
+            )
+        };
+
+        storage::save_approval(&Approval::sign(&request, "alice", &keys[0], ttl).unwrap()).unwrap();
+        assert!(authorize_rotation().is_err());
+
+        // Bob signing with Carol's key does not count
+        storage::save_approval(&Approval::sign(&request, "bob", &keys[2], ttl).unwrap()).unwrap();
+        assert!(authorize_rotation().is_err());
+
+        storage::save_approval(&Approval::sign(&request, "bob", &keys[1], ttl).unwrap()).unwrap();
+        let status = authorize_rotation().unwrap();
+        assert_eq!(status.approved_by, ["alice", "bob"]);
+
+        // Approvals are bound to this exact operation
+        assert!(authorize(
+            &request.id,
+            ApprovalAction::Revoke,
+            KeyType::Skull,
+            Some(&target),
+            None,
+            2
+        )
+        .is_err());
+
+        // ...and to this request only, and only once
+        consume(&request, "ops").unwrap();
+        assert!(authorize_rotation().is_err());
+        assert!(consume(&request, "ops").is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_approvals_cannot_be_replayed_or_stretched() {
+        let _env = VaultEnvironment::new();
+        let keys = register(&["alice"]);
+        let registry = storage::load_approver_registry().unwrap();
+        let ttl = Duration::hours(1);
+        let now = Utc::now();
+
+        let request = OperationRequest::new(
+            ApprovalAction::Create,
+            KeyType::Master,
+            None,
+            Some(fingerprint(1)),
+            "",
+            "ops",
+            ttl,
+        )
+        .unwrap();
+        let approval = Approval::sign(&request, "alice", &keys[0], Duration::days(7)).unwrap();
+        assert_eq!(approval.expires_at, request.expires_at);
+        assert!(approval.verify(&request, &registry, now).is_ok());
+        assert!(approval
+            .verify(&request, &registry, now + Duration::hours(2))
+            .is_err());
+
+        // Same parameters, new request id: the old approval does not transfer
+        let mut sibling = request.clone();
+        sibling.id = Uuid::new_v4().to_string();
+        let mut replayed = approval.clone();
+        replayed.request_id = sibling.id.clone();
+        assert!(replayed.verify(&sibling, &registry, now).is_err());
+
+        // Extending the window invalidates the signature
+        let mut stretched = approval.clone();
+        stretched.signed_at -= Duration::minutes(5);
+        assert!(stretched.verify(&request, &registry, now).is_err());
+
+        assert!(OperationRequest::new(
+            ApprovalAction::Rotate,
+            KeyType::Repo,
+            Some(fingerprint(2)),
+            None,
+            "",
+            "ops",
+            ttl
+        )
+        .is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_registry_is_founded_by_the_skull() {
+        let _env = VaultEnvironment::new();
+        let skull = save_chain(&[KeyType::Skull]).remove(0);
+        let skull_key = skull.key_material().signing_key().unwrap();
+        let founders: Vec<(String, VerifyingKey)> = ["alice", "bob"]
+            .iter()
+            .map(|name| (name.to_string(), approver_key().verifying_key()))
+            .collect();
+
+        // A quorum is founded together, by the Skull's own key
+        assert!(ApproverRegistry::found(&founders[..1], &skull, &skull_key, 2).is_err());
+        assert!(ApproverRegistry::found(&founders, &skull, &approver_key(), 2).is_err());
+        let registry = ApproverRegistry::found(&founders, &skull, &skull_key, 2).unwrap();
+        let chain = AuthorityChain::load_from_vault().unwrap();
+        assert!(registry.verify_founding(&chain).is_ok());
+
+        // Unfounded or edited registries do not verify
+        let mut unfounded = ApproverRegistry::default();
+        assert!(unfounded
+            .register("mallory", &approver_key().verifying_key())
+            .is_err());
+        assert!(unfounded.verify_founding(&chain).is_err());
+
+        let mut swapped = registry.clone();
+        swapped.approvers[1].public_key = approver_key().verifying_key().to_bytes().to_vec();
+        assert!(swapped.verify_founding(&chain).is_err());
+        let mut renamed = registry.clone();
+        renamed.approvers[0].name = "mallory".to_string();
+        assert!(renamed.verify_founding(&chain).is_err());
+        assert!(registry.verify_founding(&AuthorityChain::new()).is_err());
+
+        // Nothing is authorized against a registry the Skull did not found
+        storage::save_approver_registry(&swapped).unwrap();
+        let request = OperationRequest::new(
+            ApprovalAction::Create,
+            KeyType::Master,
+            None,
+            None,
+            "",
+            "ops",
+            Duration::hours(1),
+        )
+        .unwrap();
+        submit(&request).unwrap();
+        let error = authorize(
+            &request.id,
+            ApprovalAction::Create,
+            KeyType::Master,
+            None,
+            None,
+            0,
+        )
+        .unwrap_err();
+        assert!(
+            error.to_string().contains("verify_approver_registry"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_registry_changes_need_the_quorum() {
+        let _env = VaultEnvironment::new();
+        let keys = register(&["alice", "bob"]);
+
+        let ttl = Duration::hours(APPROVAL_TTL_HOURS);
+        let newcomer = approver_key().verifying_key();
+        let other = approver_key().verifying_key();
+        let request =
+            OperationRequest::register_approver("mallory", &newcomer, "new on-call", "ops", ttl)
+                .unwrap();
+        submit(&request).unwrap();
+        assert_eq!(request.describe(), "register-approver mallory");
+
+        storage::save_approval(&Approval::sign(&request, "alice", &keys[0], ttl).unwrap()).unwrap();
+        assert!(authorize_registration(&request.id, "mallory", &newcomer, 2).is_err());
+        storage::save_approval(&Approval::sign(&request, "bob", &keys[1], ttl).unwrap()).unwrap();
+        let status = authorize_registration(&request.id, "mallory", &newcomer, 2).unwrap();
+
+        // Later approvers extend the founded registry without disturbing its signature
+        let mut registry = storage::load_approver_registry().unwrap();
+        registry.register("mallory", &newcomer).unwrap();
+        let chain = AuthorityChain::load_from_vault().unwrap();
+        assert!(registry.verify_founding(&chain).is_ok());
+        consume(&status.request, "ops").unwrap();
+
+        // Bound to the proposed name and key, and useless for key operations
+        assert!(authorize_registration(&request.id, "mallory", &other, 2).is_err());
+        assert!(authorize_registration(&request.id, "eve", &newcomer, 2).is_err());
+        assert!(authorize(
+            &request.id,
+            ApprovalAction::Create,
+            KeyType::Skull,
+            None,
+            None,
+            2
+        )
+        .is_err());
+        assert!(OperationRequest::new(
+            ApprovalAction::RegisterApprover,
+            KeyType::Skull,
+            None,
+            None,
+            "",
+            "ops",
+            ttl
+        )
+        .is_err());
+    }
+}
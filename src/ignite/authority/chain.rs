@@ -7,8 +7,14 @@ use hub::data_ext::serde::{Deserialize, Serialize};
 use hub::time_ext::chrono::{DateTime, Utc};
 use std::fmt;
 use std::path::{Path, PathBuf};
+use zeroize::Zeroizing;
 
+use super::ignition::IgnitionKey;
+use super::sealing::{self, SealedSecret};
+use super::tombstones::Tombstone;
 use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::security::passphrase::PassphraseRequest;
+use crate::ignite::security::policy::PolicyEngine;
 
 /// Key types in the authority hierarchy (X→M→R→I→D)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -84,6 +90,17 @@ impl KeyType {
     pub fn is_ignition_key(&self) -> bool {
         matches!(self, KeyType::Skull | KeyType::Ignition | KeyType::Distro)
     }
+
+    /// All key types ordered from the top of the hierarchy down
+    pub fn all() -> [KeyType; 5] {
+        [
+            KeyType::Skull,
+            KeyType::Master,
+            KeyType::Repo,
+            KeyType::Ignition,
+            KeyType::Distro,
+        ]
+    }
 }
 
 impl From<KeyType> for String {
@@ -198,18 +215,30 @@ pub enum KeyFormat {
 }
 
 /// Cryptographic key material
+///
+/// On disk the private half is a versioned `sealed_private_key` record (see
+/// `sealing`); legacy plaintext `private_key` arrays still load but are never
+/// written. In memory the plaintext half is zeroized on drop.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyMaterial {
     public_key: Vec<u8>,
-    private_key: Option<Vec<u8>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    private_key: Option<Zeroizing<Vec<u8>>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sealed_private_key: Option<SealedSecret>,
     key_format: KeyFormat,
 }
 
 impl KeyMaterial {
-    pub fn new(public_key: Vec<u8>, private_key: Option<Vec<u8>>, format: KeyFormat) -> Self {
+    pub fn new(
+        public_key: Vec<u8>,
+        private_key: Option<Zeroizing<Vec<u8>>>,
+        format: KeyFormat,
+    ) -> Self {
         Self {
             public_key,
             private_key,
+            sealed_private_key: None,
             key_format: format,
         }
     }
@@ -218,12 +247,36 @@ impl KeyMaterial {
         &self.public_key
     }
 
+    /// Plaintext private key; `None` while the key is sealed
     pub fn private_key(&self) -> Option<&[u8]> {
-        self.private_key.as_deref()
+        self.private_key
+            .as_ref()
+            .map(|private_key| private_key.as_slice())
     }
 
     pub fn has_private_key(&self) -> bool {
-        self.private_key.is_some()
+        self.private_key.is_some() || self.sealed_private_key.is_some()
+    }
+
+    pub fn is_sealed(&self) -> bool {
+        self.sealed_private_key.is_some()
+    }
+
+    pub(crate) fn clear_private_key(&mut self) {
+        self.private_key = None;
+        self.sealed_private_key = None;
+    }
+
+    /// Wrap the plaintext private key under `passphrase` and drop the plaintext
+    pub fn seal(&mut self, passphrase: &str) -> Result<()> {
+        if let Some(private_key) = self.private_key.take() {
+            self.sealed_private_key = Some(SealedSecret::seal(
+                &private_key,
+                &self.public_key,
+                passphrase,
+            )?);
+        }
+        Ok(())
     }
 
     pub fn format(&self) -> KeyFormat {
@@ -233,6 +286,50 @@ impl KeyMaterial {
     pub fn fingerprint(&self) -> Result<KeyFingerprint> {
         KeyFingerprint::from_key_material(&self.public_key)
     }
+
+    /// Generate fresh Ed25519 key material
+    pub fn generate_ed25519() -> Self {
+        use ed25519_dalek::{SecretKey, SigningKey};
+        use hub::random_ext::rand::{rng, Rng};
+
+        let secret_bytes: [u8; 32] = rng().random();
+        let signing_key = SigningKey::from(&SecretKey::from(secret_bytes));
+        let public_key = signing_key.verifying_key().to_bytes().to_vec();
+        let private_key = Some(Zeroizing::new(signing_key.to_bytes().to_vec()));
+
+        Self::new(public_key, private_key, KeyFormat::Ed25519)
+    }
+
+    /// Reconstruct the Ed25519 signing key from private key material
+    ///
+    /// Sealed material is opened here, with the vault passphrase from
+    /// `sealing::vault_passphrase`, and never cached.
+    pub fn signing_key(&self) -> Result<ed25519_dalek::SigningKey> {
+        let opened;
+        let private_key_bytes = match (&self.private_key, &self.sealed_private_key) {
+            (Some(private_key), _) => private_key.as_slice(),
+            (None, Some(sealed)) => {
+                let fingerprint = self.fingerprint()?;
+                let passphrase =
+                    sealing::vault_passphrase(&PassphraseRequest::unseal(&fingerprint))?;
+                opened = sealed.open(&self.public_key, passphrase.expose())?;
+                opened.as_slice()
+            }
+            (None, None) => {
+                return Err(IgniteError::InvalidKey {
+                    reason: "Key has no private key material".to_string(),
+                })
+            }
+        };
+
+        let bytes: Zeroizing<[u8; 32]> = Zeroizing::new(private_key_bytes.try_into().map_err(
+            |_| IgniteError::InvalidKey {
+                reason: "Invalid private key length".to_string(),
+            },
+        )?);
+
+        Ok(ed25519_dalek::SigningKey::from_bytes(&bytes))
+    }
 }
 
 /// Metadata associated with authority keys
@@ -244,6 +341,9 @@ pub struct KeyMetadata {
     pub expiration: Option<DateTime<Utc>>,
     pub last_used: Option<DateTime<Utc>>,
     pub usage_count: u64,
+    /// Logical slot shared by a key and its rotation successors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lineage_id: Option<String>,
 }
 
 impl Default for KeyMetadata {
@@ -255,6 +355,7 @@ impl Default for KeyMetadata {
             expiration: None,
             last_used: None,
             usage_count: 0,
+            lineage_id: None,
         }
     }
 }
@@ -269,6 +370,61 @@ impl KeyMetadata {
     }
 }
 
+/// Lifecycle status of an authority key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum KeyStatus {
+    /// In service and able to exercise authority
+    #[default]
+    Active,
+    /// Replaced by rotation; retained for audit
+    Archived,
+    /// Descendant of a rotated or revoked key; must be re-issued
+    Invalid,
+    /// Revoked without replacement; fingerprint is tombstoned
+    Revoked,
+}
+
+impl KeyStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KeyStatus::Active => "active",
+            KeyStatus::Archived => "archived",
+            KeyStatus::Invalid => "invalid",
+            KeyStatus::Revoked => "revoked",
+        }
+    }
+}
+
+impl From<KeyStatus> for String {
+    fn from(status: KeyStatus) -> String {
+        status.as_str().to_string()
+    }
+}
+
+impl TryFrom<String> for KeyStatus {
+    type Error = IgniteError;
+
+    fn try_from(s: String) -> Result<Self> {
+        match s.as_str() {
+            "active" => Ok(KeyStatus::Active),
+            "archived" => Ok(KeyStatus::Archived),
+            "invalid" => Ok(KeyStatus::Invalid),
+            "revoked" => Ok(KeyStatus::Revoked),
+            _ => Err(IgniteError::InvalidOperation {
+                operation: "parse_key_status".to_string(),
+                reason: format!("Unknown key status: {}", s),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for KeyStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Authority key with metadata and relationships
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthorityKey {
@@ -277,8 +433,16 @@ pub struct AuthorityKey {
     fingerprint: KeyFingerprint,
     key_path: Option<PathBuf>,
     metadata: KeyMetadata,
+    #[serde(default)]
+    status: KeyStatus,
+    /// Fingerprint of the key holding authority over this key (None for Skull)
+    #[serde(default)]
+    parent: Option<KeyFingerprint>,
     /// Fingerprints of keys this key has authority over
     children: Vec<KeyFingerprint>,
+    /// Passphrase wrapper holding the private half of X/I/D keys
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ignition: Option<IgnitionKey>,
 }
 
 impl AuthorityKey {
@@ -296,7 +460,10 @@ impl AuthorityKey {
             fingerprint,
             key_path,
             metadata: metadata.unwrap_or_default(),
+            status: KeyStatus::Active,
+            parent: None,
             children: Vec::new(),
+            ignition: None,
         })
     }
 
@@ -312,6 +479,42 @@ impl AuthorityKey {
         &self.key_material
     }
 
+    /// Seal the private key material under `passphrase` (no-op if already sealed)
+    pub fn seal_private_key(&mut self, passphrase: &str) -> Result<()> {
+        self.key_material.seal(passphrase)
+    }
+
+    /// Lineage this key belongs to; a key that never rotated starts its own
+    pub fn lineage_id(&self) -> String {
+        self.metadata
+            .lineage_id
+            .clone()
+            .unwrap_or_else(|| self.fingerprint.hex().to_string())
+    }
+
+    pub fn ignition(&self) -> Option<&IgnitionKey> {
+        self.ignition.as_ref()
+    }
+
+    pub fn is_passphrase_wrapped(&self) -> bool {
+        self.ignition.is_some()
+    }
+
+    /// Install a passphrase wrapper and drop any unwrapped private material
+    pub(crate) fn set_ignition(&mut self, ignition: IgnitionKey) {
+        self.key_material.clear_private_key();
+        self.ignition = Some(ignition);
+    }
+
+    /// Unwrap an ignition key's private material
+    ///
+    /// Key policies (expiration included) are enforced before the passphrase
+    /// is checked.
+    pub fn unlock(&self, passphrase: &str, policy_engine: &PolicyEngine) -> Result<KeyMaterial> {
+        use crate::ignite::authority::ignition;
+        ignition::unlock(self, passphrase, policy_engine)
+    }
+
     pub fn metadata(&self) -> &KeyMetadata {
         &self.metadata
     }
@@ -320,6 +523,18 @@ impl AuthorityKey {
         &mut self.metadata
     }
 
+    pub fn status(&self) -> KeyStatus {
+        self.status
+    }
+
+    pub fn set_status(&mut self, status: KeyStatus) {
+        self.status = status;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == KeyStatus::Active
+    }
+
     /// Returns true if the key has an expiration timestamp in the past.
     pub fn is_expired(&self) -> bool {
         self.metadata
@@ -328,6 +543,38 @@ impl AuthorityKey {
             .unwrap_or(false)
     }
 
+    pub fn parent(&self) -> Option<&KeyFingerprint> {
+        self.parent.as_ref()
+    }
+
+    /// Record the parent holding authority over this key
+    ///
+    /// Lineage is immutable: a key that already references a different parent
+    /// cannot be re-parented, and Skull keys never have a parent.
+    pub fn set_parent(&mut self, parent_fp: KeyFingerprint) -> Result<()> {
+        if self.key_type == KeyType::Skull {
+            return Err(IgniteError::InvalidOperation {
+                operation: "set_parent".to_string(),
+                reason: "Skull keys cannot have a parent".to_string(),
+            });
+        }
+
+        match &self.parent {
+            Some(existing) if existing != &parent_fp => Err(IgniteError::InvalidOperation {
+                operation: "set_parent".to_string(),
+                reason: format!(
+                    "Key {} already has parent {}",
+                    self.fingerprint.short(),
+                    existing.short()
+                ),
+            }),
+            _ => {
+                self.parent = Some(parent_fp);
+                Ok(())
+            }
+        }
+    }
+
     pub fn children(&self) -> &[KeyFingerprint] {
         &self.children
     }
@@ -381,6 +628,7 @@ pub struct AuthorityChain {
     keys: std::collections::HashMap<KeyFingerprint, AuthorityKey>,
     relationships: std::collections::HashMap<KeyFingerprint, Vec<KeyFingerprint>>, // parent -> children
     reverse_relationships: std::collections::HashMap<KeyFingerprint, KeyFingerprint>, // child -> parent
+    tombstones: std::collections::HashMap<KeyFingerprint, Tombstone>,
 }
 
 impl AuthorityChain {
@@ -390,11 +638,157 @@ impl AuthorityChain {
             keys: std::collections::HashMap::new(),
             relationships: std::collections::HashMap::new(),
             reverse_relationships: std::collections::HashMap::new(),
+            tombstones: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Rebuild the authority chain from every key persisted in the vault
+    ///
+    /// Loads all keys across every `KeyType` and reconstructs the relationship
+    /// maps from each key's recorded `children` and `parent` link. Edges pointing
+    /// at keys missing from the vault are reported as `DanglingRelationship`;
+    /// edges that break the hierarchy or give a child two parents are
+    /// `InconsistentRelationship`. Keys written before parent links were
+    /// persisted are backfilled in memory from their parent's `children`.
+    pub fn load_from_vault() -> Result<Self> {
+        let mut chain = Self::load_unlinked_from_vault()?;
+        chain.backfill_parent_links();
+        Ok(chain)
+    }
+
+    /// Persist parent links for keys that predate lineage recording
+    ///
+    /// Compatibility path for existing vaults: rebuilds the chain, backfills
+    /// missing `parent` links from the parents' `children` lists, and saves the
+    /// updated keys along with any legacy plaintext keys, which are sealed on
+    /// the way back to disk. Returns the fingerprints of the keys that were
+    /// rewritten.
+    pub fn backfill_vault() -> Result<Vec<KeyFingerprint>> {
+        let mut chain = Self::load_unlinked_from_vault()?;
+        let mut updated = chain.backfill_parent_links();
+        for key in chain.keys.values() {
+            if key.key_material().private_key().is_some() && !updated.contains(key.fingerprint()) {
+                updated.push(key.fingerprint().clone());
+            }
+        }
+
+        for fingerprint in &updated {
+            if let Some(key) = chain.keys.get_mut(fingerprint) {
+                key.save()?;
+            }
+        }
+
+        Ok(updated)
+    }
+
+    fn load_unlinked_from_vault() -> Result<Self> {
+        use crate::ignite::authority::storage;
+
+        let mut chain = Self::new();
+        for tombstone in storage::load_tombstones()? {
+            chain.add_tombstone(tombstone);
+        }
+
+        // Revoked keys stay on disk for audit, so bypass the tombstone guard
+        for key_type in KeyType::all() {
+            for key in storage::load_keys(key_type)? {
+                chain.insert_key(key)?;
+            }
+        }
+
+        chain.rebuild_relationships()?;
+        Ok(chain)
+    }
+
+    /// Reconstruct relationship maps from recorded `children` and `parent` links
+    fn rebuild_relationships(&mut self) -> Result<()> {
+        let mut edges: Vec<(KeyFingerprint, KeyFingerprint)> = Vec::new();
+        for key in self.keys.values() {
+            for child in key.children() {
+                edges.push((key.fingerprint().clone(), child.clone()));
+            }
+            if let Some(parent) = key.parent() {
+                edges.push((parent.clone(), key.fingerprint().clone()));
+            }
+        }
+        edges.sort_by_key(|(parent, child)| (parent.to_string(), child.to_string()));
+        edges.dedup();
+
+        for (parent_fp, child_fp) in edges {
+            let (parent_key, child_key) =
+                match (self.keys.get(&parent_fp), self.keys.get(&child_fp)) {
+                    (Some(parent_key), Some(child_key)) => (parent_key, child_key),
+                    _ => {
+                        return Err(IgniteError::DanglingRelationship {
+                            parent: parent_fp.to_string(),
+                            child: child_fp.to_string(),
+                        })
+                    }
+                };
+
+            if !parent_key.key_type().can_control(child_key.key_type()) {
+                return Err(IgniteError::InconsistentRelationship {
+                    parent: parent_fp.to_string(),
+                    child: child_fp.to_string(),
+                    reason: format!(
+                        "{} cannot control {}",
+                        parent_key.key_type().description(),
+                        child_key.key_type().description()
+                    ),
+                });
+            }
+
+            if let Some(existing_parent) = self.reverse_relationships.get(&child_fp) {
+                return Err(IgniteError::InconsistentRelationship {
+                    parent: parent_fp.to_string(),
+                    child: child_fp.to_string(),
+                    reason: format!("child already claimed by {}", existing_parent),
+                });
+            }
+
+            self.relationships
+                .entry(parent_fp.clone())
+                .or_default()
+                .push(child_fp.clone());
+            self.reverse_relationships.insert(child_fp, parent_fp);
+        }
+
+        Ok(())
+    }
+
+    /// Set missing `parent` links from the relationship maps
+    ///
+    /// Returns the fingerprints of keys whose parent link was filled in.
+    pub fn backfill_parent_links(&mut self) -> Vec<KeyFingerprint> {
+        let mut updated = Vec::new();
+
+        for (child_fp, parent_fp) in &self.reverse_relationships {
+            if let Some(child_key) = self.keys.get_mut(child_fp) {
+                if child_key.parent.is_none() {
+                    child_key.parent = Some(parent_fp.clone());
+                    updated.push(child_fp.clone());
+                }
+            }
         }
+
+        updated.sort_by_key(|fp| fp.to_string());
+        updated
+    }
+
+    /// Iterate over every key in the chain
+    pub fn keys(&self) -> impl Iterator<Item = &AuthorityKey> {
+        self.keys.values()
     }
 
     /// Add key to authority chain
+    ///
+    /// Tombstoned fingerprints are rejected with `Tombstoned`.
     pub fn add_key(&mut self, key: AuthorityKey) -> Result<()> {
+        self.ensure_not_tombstoned(key.fingerprint())?;
+        self.insert_key(key)
+    }
+
+    fn insert_key(&mut self, key: AuthorityKey) -> Result<()> {
         let fingerprint = key.fingerprint().clone();
 
         if self.keys.contains_key(&fingerprint) {
@@ -418,12 +812,44 @@ impl AuthorityChain {
         self.keys.get_mut(fingerprint)
     }
 
+    /// Record a tombstone so the fingerprint can no longer be used
+    pub fn add_tombstone(&mut self, tombstone: Tombstone) {
+        self.tombstones
+            .insert(tombstone.fingerprint.clone(), tombstone);
+    }
+
+    /// Get the tombstone for a revoked fingerprint
+    pub fn tombstone(&self, fingerprint: &KeyFingerprint) -> Option<&Tombstone> {
+        self.tombstones.get(fingerprint)
+    }
+
+    pub fn is_tombstoned(&self, fingerprint: &KeyFingerprint) -> bool {
+        self.tombstones.contains_key(fingerprint)
+    }
+
+    /// Iterate over every tombstone known to the chain
+    pub fn tombstones(&self) -> impl Iterator<Item = &Tombstone> {
+        self.tombstones.values()
+    }
+
+    fn ensure_not_tombstoned(&self, fingerprint: &KeyFingerprint) -> Result<()> {
+        match self.tombstone(fingerprint) {
+            Some(tombstone) => Err(tombstone.to_error()),
+            None => Ok(()),
+        }
+    }
+
     /// Add authority relationship between parent and child keys
+    ///
+    /// Tombstoned parents or children are rejected with `Tombstoned`.
     pub fn add_authority_relationship(
         &mut self,
         parent: &KeyFingerprint,
         child: &KeyFingerprint,
     ) -> Result<()> {
+        self.ensure_not_tombstoned(parent)?;
+        self.ensure_not_tombstoned(child)?;
+
         let parent_key = self
             .get_key(parent)
             .ok_or_else(|| IgniteError::InvalidOperation {
@@ -461,6 +887,19 @@ impl AuthorityChain {
             }
         }
 
+        if let Some(existing_parent) = child_key.parent() {
+            if existing_parent != parent {
+                return Err(IgniteError::InvalidOperation {
+                    operation: "add_authority".to_string(),
+                    reason: format!(
+                        "Child key {} already has parent {}",
+                        child.short(),
+                        existing_parent.short()
+                    ),
+                });
+            }
+        }
+
         if self
             .relationships
             .get(parent)
@@ -479,7 +918,7 @@ impl AuthorityChain {
 
         self.relationships
             .entry(parent.clone())
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(child.clone());
         self.reverse_relationships
             .insert(child.clone(), parent.clone());
@@ -487,6 +926,9 @@ impl AuthorityChain {
         if let Some(parent_key) = self.get_key_mut(parent) {
             parent_key.add_child(child.clone())?;
         }
+        if let Some(child_key) = self.get_key_mut(child) {
+            child_key.set_parent(parent.clone())?;
+        }
 
         Ok(())
     }
@@ -500,6 +942,15 @@ impl AuthorityChain {
         }
     }
 
+    /// Check if `ancestor` is `descendant` itself or holds authority over it through the chain
+    pub fn has_transitive_authority(
+        &self,
+        ancestor: &KeyFingerprint,
+        descendant: &KeyFingerprint,
+    ) -> bool {
+        self.has_authority_path(ancestor, descendant)
+    }
+
     /// Check if child is subject to parent
     pub fn is_subject_to(&self, child: &KeyFingerprint, parent: &KeyFingerprint) -> bool {
         if let Some(actual_parent) = self.reverse_relationships.get(child) {
@@ -564,6 +1015,7 @@ impl AuthorityChain {
     /// - Authority cycles (which would violate the DAG structure)
     /// - Hierarchy rule violations (e.g., Master controlling Distro directly)
     /// - Orphaned keys in relationship maps
+    /// - Non-Skull keys without a valid parent link (security invariant #1)
     pub fn validate_integrity(&self) -> Result<()> {
         for (parent_fp, children) in &self.relationships {
             for child_fp in children {
@@ -605,6 +1057,36 @@ impl AuthorityChain {
             }
         }
 
+        for key in self.keys.values() {
+            if key.key_type() == KeyType::Skull {
+                continue;
+            }
+
+            let recorded = self.reverse_relationships.get(key.fingerprint());
+            match (key.parent(), recorded) {
+                (Some(linked), Some(recorded)) if linked == recorded => {}
+                (Some(linked), Some(recorded)) => {
+                    return Err(IgniteError::InconsistentRelationship {
+                        parent: recorded.to_string(),
+                        child: key.fingerprint().to_string(),
+                        reason: format!("key records a different parent {}", linked),
+                    });
+                }
+                (None, Some(_)) => {
+                    return Err(IgniteError::OrphanKey {
+                        fingerprint: key.fingerprint().to_string(),
+                        reason: "parent link not persisted on key".to_string(),
+                    });
+                }
+                (_, None) => {
+                    return Err(IgniteError::OrphanKey {
+                        fingerprint: key.fingerprint().to_string(),
+                        reason: "no parent holds authority over this key".to_string(),
+                    });
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -645,8 +1127,12 @@ impl Default for AuthorityChain {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ignite::authority::storage;
+    use crate::ignite::test_support::{new_child, VaultEnvironment};
     use ed25519_dalek::{SecretKey, SigningKey};
+    use hub::data_ext::serde_json;
     use hub::random_ext::rand::{rng, Rng};
+    use serial_test::serial;
 
     fn create_test_key_material() -> KeyMaterial {
         let mut random = rng();
@@ -654,7 +1140,7 @@ mod tests {
         let secret_key = SecretKey::from(secret_bytes);
         let signing_key = SigningKey::from(&secret_key);
         let public_key = signing_key.verifying_key().to_bytes().to_vec();
-        let private_key = Some(signing_key.to_bytes().to_vec());
+        let private_key = Some(Zeroizing::new(signing_key.to_bytes().to_vec()));
 
         KeyMaterial::new(public_key, private_key, KeyFormat::Ed25519)
     }
@@ -773,6 +1259,49 @@ mod tests {
         assert!(chain.get_key(&fingerprint).is_some());
     }
 
+    #[test]
+    fn test_authority_chain_rejects_tombstoned() {
+        use crate::ignite::authority::tombstones::RevocationReason;
+
+        let mut chain = AuthorityChain::new();
+
+        let skull =
+            AuthorityKey::new(create_test_key_material(), KeyType::Skull, None, None).unwrap();
+        let master =
+            AuthorityKey::new(create_test_key_material(), KeyType::Master, None, None).unwrap();
+        let revoked =
+            AuthorityKey::new(create_test_key_material(), KeyType::Master, None, None).unwrap();
+
+        let skull_fp = skull.fingerprint().clone();
+        let master_fp = master.fingerprint().clone();
+
+        chain.add_key(skull).unwrap();
+        chain.add_key(master).unwrap();
+        chain.add_tombstone(Tombstone::new(
+            revoked.fingerprint().clone(),
+            KeyType::Master,
+            RevocationReason::KeyCompromise,
+            "tester",
+        ));
+        chain.add_tombstone(Tombstone::new(
+            master_fp.clone(),
+            KeyType::Master,
+            RevocationReason::Superseded,
+            "tester",
+        ));
+
+        assert!(matches!(
+            chain.add_key(revoked),
+            Err(IgniteError::Tombstoned { .. })
+        ));
+        assert!(matches!(
+            chain.add_authority_relationship(&skull_fp, &master_fp),
+            Err(IgniteError::Tombstoned { .. })
+        ));
+        assert!(chain.is_tombstoned(&master_fp));
+        assert!(!chain.is_tombstoned(&skull_fp));
+    }
+
     #[test]
     fn test_authority_chain_add_relationship() {
         let mut chain = AuthorityChain::new();
@@ -1028,6 +1557,41 @@ mod tests {
             .unwrap();
 
         assert!(chain.validate_integrity().is_ok());
+        assert_eq!(chain.get_key(&master_fp).unwrap().parent(), Some(&skull_fp));
+    }
+
+    #[test]
+    fn test_authority_chain_validate_integrity_rejects_orphans() {
+        let mut chain = AuthorityChain::new();
+
+        let skull =
+            AuthorityKey::new(create_test_key_material(), KeyType::Skull, None, None).unwrap();
+        let master =
+            AuthorityKey::new(create_test_key_material(), KeyType::Master, None, None).unwrap();
+
+        chain.add_key(skull).unwrap();
+        chain.add_key(master).unwrap();
+
+        assert!(matches!(
+            chain.validate_integrity(),
+            Err(IgniteError::OrphanKey { .. })
+        ));
+    }
+
+    #[test]
+    fn test_authority_key_parent_link() {
+        let mut skull =
+            AuthorityKey::new(create_test_key_material(), KeyType::Skull, None, None).unwrap();
+        let mut master =
+            AuthorityKey::new(create_test_key_material(), KeyType::Master, None, None).unwrap();
+        let other_fp = KeyFingerprint::from_string("SHA256:other000").unwrap();
+
+        assert!(skull.set_parent(other_fp.clone()).is_err());
+
+        master.set_parent(skull.fingerprint().clone()).unwrap();
+        assert!(master.set_parent(skull.fingerprint().clone()).is_ok());
+        assert!(master.set_parent(other_fp).is_err());
+        assert_eq!(master.parent(), Some(skull.fingerprint()));
     }
 
     #[test]
@@ -1051,4 +1615,114 @@ mod tests {
         let skulls = chain.get_keys_by_type(KeyType::Skull);
         assert_eq!(skulls.len(), 1);
     }
+
+    #[test]
+    #[serial]
+    fn test_authority_chain_load_from_vault() {
+        let _env = VaultEnvironment::new();
+
+        let mut skull =
+            AuthorityKey::new(create_test_key_material(), KeyType::Skull, None, None).unwrap();
+        let mut master = new_child(KeyType::Master, &mut skull);
+        let mut repo = new_child(KeyType::Repo, &mut master);
+        let ignition = new_child(KeyType::Ignition, &mut repo);
+
+        let skull_fp = skull.fingerprint().clone();
+        let master_fp = master.fingerprint().clone();
+        let repo_fp = repo.fingerprint().clone();
+        let ignition_fp = ignition.fingerprint().clone();
+
+        storage::save_key(&skull).unwrap();
+        storage::save_key(&master).unwrap();
+        storage::save_key(&repo).unwrap();
+        storage::save_key(&ignition).unwrap();
+
+        let chain = AuthorityChain::load_from_vault().unwrap();
+        assert_eq!(chain.len(), 4);
+        assert!(chain.has_authority(&skull_fp, &master_fp));
+        assert!(chain.is_subject_to(&ignition_fp, &repo_fp));
+        assert_eq!(
+            chain.get_parent(&master_fp).unwrap().fingerprint(),
+            &skull_fp
+        );
+        assert_eq!(
+            chain.get_parent(&repo_fp).unwrap().fingerprint(),
+            &master_fp
+        );
+        assert_eq!(chain.find_dependent_keys(&skull_fp).unwrap().len(), 3);
+        assert!(chain.validate_integrity().is_ok());
+    }
+
+    #[test]
+    #[serial]
+    fn test_authority_chain_backfills_legacy_parent_links() {
+        let _env = VaultEnvironment::new();
+
+        let mut skull =
+            AuthorityKey::new(create_test_key_material(), KeyType::Skull, None, None).unwrap();
+        let mut master = new_child(KeyType::Master, &mut skull);
+        let master_fp = master.fingerprint().clone();
+        storage::save_key(&skull).unwrap();
+
+        // Keys written before lineage was persisted carry no parent link
+        master.parent = None;
+        let legacy_path = storage::key_path(KeyType::Master, &master_fp);
+        std::fs::create_dir_all(legacy_path.parent().unwrap()).unwrap();
+        std::fs::write(&legacy_path, serde_json::to_string_pretty(&master).unwrap()).unwrap();
+
+        let chain = AuthorityChain::load_from_vault().unwrap();
+        assert_eq!(
+            chain.get_key(&master_fp).unwrap().parent(),
+            Some(skull.fingerprint())
+        );
+        assert!(chain.validate_integrity().is_ok());
+
+        let updated = AuthorityChain::backfill_vault().unwrap();
+        assert_eq!(updated, vec![master_fp.clone()]);
+
+        let reloaded = storage::load_key(KeyType::Master, &master_fp).unwrap();
+        assert_eq!(reloaded.parent(), Some(skull.fingerprint()));
+        assert!(AuthorityChain::backfill_vault().unwrap().is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_authority_chain_load_reports_dangling_edge() {
+        let _env = VaultEnvironment::new();
+
+        let mut skull =
+            AuthorityKey::new(create_test_key_material(), KeyType::Skull, None, None).unwrap();
+        let missing = KeyFingerprint::from_string("SHA256:missing0").unwrap();
+        skull.add_child(missing).unwrap();
+        storage::save_key(&skull).unwrap();
+
+        let result = AuthorityChain::load_from_vault();
+        assert!(matches!(
+            result,
+            Err(IgniteError::DanglingRelationship { .. })
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn test_authority_chain_load_reports_inconsistent_edges() {
+        let _env = VaultEnvironment::new();
+
+        let mut skull1 =
+            AuthorityKey::new(create_test_key_material(), KeyType::Skull, None, None).unwrap();
+        let mut skull2 =
+            AuthorityKey::new(create_test_key_material(), KeyType::Skull, None, None).unwrap();
+        let master = new_child(KeyType::Master, &mut skull1);
+
+        skull2.add_child(master.fingerprint().clone()).unwrap();
+        storage::save_key(&skull1).unwrap();
+        storage::save_key(&skull2).unwrap();
+        storage::save_key(&master).unwrap();
+
+        let result = AuthorityChain::load_from_vault();
+        assert!(matches!(
+            result,
+            Err(IgniteError::InconsistentRelationship { .. })
+        ));
+    }
 }
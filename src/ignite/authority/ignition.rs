@@ -0,0 +1,312 @@
+//! Passphrase wrapping for ignition keys (X, I, D).
+//!
+//! Skull, Ignition and Distro keys never keep their private half in the key
+//! file. At creation the passphrase is checked against the `PolicyEngine`,
+//! an Argon2id hash of it is recorded, and the private key is sealed under it
+//! (see `sealing`). `unlock` runs the key policies, so an expired key yields
+//! no material, then verifies the passphrase before opening the seal.
+
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use hub::data_ext::serde::{Deserialize, Serialize};
+use hub::random_ext::rand::{rng, Rng};
+use hub::time_ext::chrono::{DateTime, Utc};
+use zeroize::Zeroizing;
+
+use super::chain::{AuthorityKey, KeyFingerprint, KeyMaterial};
+use super::sealing::SealedSecret;
+use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::security::passphrase::{PassphraseProvider, PassphraseRequest};
+use crate::ignite::security::policy::PolicyEngine;
+
+/// Argon2id hash of an ignition passphrase, in PHC string form
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PassphraseHash {
+    phc: String,
+}
+
+impl PassphraseHash {
+    pub fn new(passphrase: &str) -> Result<Self> {
+        let salt_bytes: [u8; 16] = rng().random();
+        let salt = SaltString::encode_b64(&salt_bytes)
+            .map_err(|e| IgniteError::crypto_error("hash_passphrase", e.to_string()))?;
+        let phc = Argon2::default()
+            .hash_password(passphrase.as_bytes(), &salt)
+            .map_err(|e| IgniteError::crypto_error("hash_passphrase", e.to_string()))?
+            .to_string();
+
+        Ok(Self { phc })
+    }
+
+    pub fn verify(&self, passphrase: &str) -> bool {
+        PasswordHash::new(&self.phc).is_ok_and(|hash| {
+            Argon2::default()
+                .verify_password(passphrase.as_bytes(), &hash)
+                .is_ok()
+        })
+    }
+}
+
+/// Passphrase-wrapped private half of an ignition key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IgnitionKey {
+    wrapped_key: SealedSecret,
+    passphrase_hash: PassphraseHash,
+    wrapped_at: DateTime<Utc>,
+}
+
+impl IgnitionKey {
+    /// Wrap the plaintext private key of `material` (belonging to `key`) under `passphrase`
+    pub fn create(
+        key: &AuthorityKey,
+        material: &KeyMaterial,
+        passphrase: &str,
+        policy_engine: &PolicyEngine,
+    ) -> Result<Self> {
+        if !key.key_type().is_ignition_key() {
+            return Err(IgniteError::InvalidOperation {
+                operation: "create_ignition_key".to_string(),
+                reason: format!(
+                    "{} keys are not ignition keys",
+                    key.key_type().description()
+                ),
+            });
+        }
+        policy_engine.validate_key_passphrase(key, passphrase)?;
+
+        let private_key = material
+            .private_key()
+            .ok_or_else(|| IgniteError::InvalidKey {
+                reason: "Key has no plaintext private key material to wrap".to_string(),
+            })?;
+
+        Ok(Self {
+            wrapped_key: SealedSecret::seal(private_key, material.public_key(), passphrase)?,
+            passphrase_hash: PassphraseHash::new(passphrase)?,
+            wrapped_at: Utc::now(),
+        })
+    }
+
+    pub fn wrapped_at(&self) -> DateTime<Utc> {
+        self.wrapped_at
+    }
+
+    pub fn verify_passphrase(&self, passphrase: &str) -> bool {
+        self.passphrase_hash.verify(passphrase)
+    }
+
+    fn open(&self, public_key: &[u8], passphrase: &str) -> Result<Zeroizing<Vec<u8>>> {
+        if !self.verify_passphrase(passphrase) {
+            return Err(IgniteError::InvalidKey {
+                reason: "Invalid passphrase".to_string(),
+            });
+        }
+        self.wrapped_key.open(public_key, passphrase)
+    }
+}
+
+/// One retired or current passphrase of a lineage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PassphraseHistoryEntry {
+    pub fingerprint: KeyFingerprint,
+    pub hash: PassphraseHash,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Passphrase hashes used across one key lineage, oldest first
+///
+/// Rotation successors share their predecessor's lineage id, so the history
+/// follows the logical slot rather than a single fingerprint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PassphraseHistory {
+    pub lineage_id: String,
+    pub entries: Vec<PassphraseHistoryEntry>,
+}
+
+impl PassphraseHistory {
+    /// Oldest entries beyond this are dropped
+    pub const MAX_ENTRIES: usize = 24;
+
+    pub fn new(lineage_id: impl Into<String>) -> Self {
+        Self {
+            lineage_id: lineage_id.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Record the passphrase `key` is wrapped under; false if already present
+    pub fn record(&mut self, key: &AuthorityKey) -> bool {
+        let Some(ignition) = key.ignition() else {
+            return false;
+        };
+        if self
+            .entries
+            .iter()
+            .any(|entry| entry.hash == ignition.passphrase_hash)
+        {
+            return false;
+        }
+
+        self.entries.push(PassphraseHistoryEntry {
+            fingerprint: key.fingerprint().clone(),
+            hash: ignition.passphrase_hash.clone(),
+            recorded_at: Utc::now(),
+        });
+        let excess = self.entries.len().saturating_sub(Self::MAX_ENTRIES);
+        self.entries.drain(..excess);
+        true
+    }
+
+    /// True if `passphrase` matches one of the `depth` most recent entries
+    pub fn contains_recent(&self, passphrase: &str, depth: usize) -> bool {
+        self.entries
+            .iter()
+            .rev()
+            .take(depth)
+            .any(|entry| entry.hash.verify(passphrase))
+    }
+}
+
+/// Wrap `key`'s private material under `passphrase` and drop the plaintext
+pub fn wrap(key: &mut AuthorityKey, passphrase: &str, policy_engine: &PolicyEngine) -> Result<()> {
+    let ignition = IgnitionKey::create(key, key.key_material(), passphrase, policy_engine)?;
+    key.set_ignition(ignition);
+    Ok(())
+}
+
+/// Return `key`'s private material, enforcing key policy before unwrapping
+pub fn unlock(
+    key: &AuthorityKey,
+    passphrase: &str,
+    policy_engine: &PolicyEngine,
+) -> Result<KeyMaterial> {
+    let ignition = key
+        .ignition()
+        .ok_or_else(|| IgniteError::InvalidOperation {
+            operation: "unlock_ignition_key".to_string(),
+            reason: format!(
+                "Key {} is not passphrase-wrapped",
+                key.fingerprint().short()
+            ),
+        })?;
+    policy_engine.validate_key(key)?;
+
+    let material = key.key_material();
+    let private_key = ignition.open(material.public_key(), passphrase)?;
+    Ok(KeyMaterial::new(
+        material.public_key().to_vec(),
+        Some(private_key),
+        material.format(),
+    ))
+}
+
+/// Re-wrap `key` under `new_passphrase` after unlocking it with `old_passphrase`
+pub fn change_passphrase(
+    key: &mut AuthorityKey,
+    old_passphrase: &str,
+    new_passphrase: &str,
+    policy_engine: &PolicyEngine,
+) -> Result<()> {
+    let material = unlock(key, old_passphrase, policy_engine)?;
+    let ignition = IgnitionKey::create(key, &material, new_passphrase, policy_engine)?;
+    key.set_ignition(ignition);
+    Ok(())
+}
+
+/// Signing key for `key`, unlocking it first if it is passphrase-wrapped
+pub fn signing_key(
+    key: &AuthorityKey,
+    policy_engine: &PolicyEngine,
+    passphrases: &PassphraseProvider,
+) -> Result<ed25519_dalek::SigningKey> {
+    if !key.is_passphrase_wrapped() {
+        return key.key_material().signing_key();
+    }
+
+    let passphrase = passphrases.obtain(&PassphraseRequest::unlock(
+        key.key_type(),
+        key.fingerprint(),
+    ))?;
+    unlock(key, passphrase.expose(), policy_engine)?.signing_key()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ignite::authority::chain::KeyType;
+    use crate::ignite::test_support::new_key;
+    use hub::time_ext::chrono::Duration;
+
+    const PASSPHRASE: &str = "Correct-Horse-42-Battery";
+
+    #[test]
+    fn test_passphrase_hash_verifies() {
+        let hash = PassphraseHash::new(PASSPHRASE).unwrap();
+        assert!(hash.phc.starts_with("$argon2id$"));
+        assert!(hash.verify(PASSPHRASE));
+        assert!(!hash.verify("Wrong-Horse-42-Battery"));
+    }
+
+    #[test]
+    fn test_wrap_unlock_round_trip() {
+        let policy_engine = PolicyEngine::with_defaults();
+        let mut key = new_key(KeyType::Distro);
+        let expected = key.key_material().signing_key().unwrap();
+
+        wrap(&mut key, PASSPHRASE, &policy_engine).unwrap();
+        assert!(key.is_passphrase_wrapped());
+        assert!(!key.key_material().has_private_key());
+        assert!(key.key_material().signing_key().is_err());
+
+        let material = unlock(&key, PASSPHRASE, &policy_engine).unwrap();
+        assert_eq!(
+            material.signing_key().unwrap().to_bytes(),
+            expected.to_bytes()
+        );
+        assert!(unlock(&key, "Wrong-Horse-42-Battery", &policy_engine).is_err());
+
+        // Serialized key carries the wrapper and no plaintext
+        let json = hub::data_ext::serde_json::to_string(&key).unwrap();
+        assert!(json.contains("\"ignition\""));
+        assert!(!json.contains("\"private_key\""));
+    }
+
+    #[test]
+    fn test_wrap_enforces_key_type_and_passphrase_policy() {
+        let policy_engine = PolicyEngine::with_defaults();
+
+        let mut master = new_key(KeyType::Master);
+        assert!(wrap(&mut master, PASSPHRASE, &policy_engine).is_err());
+
+        let mut ignition = new_key(KeyType::Ignition);
+        assert!(wrap(&mut ignition, "short", &policy_engine).is_err());
+        assert!(!ignition.is_passphrase_wrapped());
+    }
+
+    #[test]
+    fn test_unlock_rejects_expired_keys() {
+        let policy_engine = PolicyEngine::with_defaults();
+        let mut key = new_key(KeyType::Ignition);
+        wrap(&mut key, PASSPHRASE, &policy_engine).unwrap();
+
+        key.metadata_mut()
+            .set_expiration(Some(Utc::now() - Duration::hours(1)));
+        assert!(unlock(&key, PASSPHRASE, &policy_engine).is_err());
+    }
+
+    #[test]
+    fn test_change_passphrase() {
+        let policy_engine = PolicyEngine::with_defaults();
+        let mut key = new_key(KeyType::Skull);
+        wrap(&mut key, PASSPHRASE, &policy_engine).unwrap();
+
+        let next = "Staple-Battery-17-Horse";
+        assert!(
+            change_passphrase(&mut key, "Wrong-Horse-42-Battery", next, &policy_engine).is_err()
+        );
+        change_passphrase(&mut key, PASSPHRASE, next, &policy_engine).unwrap();
+
+        assert!(unlock(&key, PASSPHRASE, &policy_engine).is_err());
+        assert!(unlock(&key, next, &policy_engine).is_ok());
+    }
+}
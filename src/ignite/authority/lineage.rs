@@ -0,0 +1,336 @@
+//! Lineage verification from a leaf key up to its Skull root.
+//!
+//! Each parent/child edge is checked against its active proof in the index:
+//! the claim must be signed by the key whose fingerprint the vault records as
+//! parent, the child must countersign the receipt, the hierarchy rule must hold
+//! and neither direction may be expired.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::chain::{AuthorityChain, AuthorityKey, KeyFingerprint, KeyType};
+use super::proof_archive::ProofIndex;
+use super::proofs::ProofBundle;
+use super::storage;
+use crate::ignite::error::{IgniteError, Result};
+
+/// Verification outcome for a single parent/child hop
+///
+/// Variants are ordered best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HopStatus {
+    Valid,
+    Expired,
+    Missing,
+    Forged,
+}
+
+impl HopStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HopStatus::Valid => "valid",
+            HopStatus::Expired => "expired",
+            HopStatus::Missing => "missing",
+            HopStatus::Forged => "forged",
+        }
+    }
+}
+
+impl fmt::Display for HopStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// One edge of the lineage, from child up to its recorded parent
+#[derive(Debug, Clone)]
+pub struct LineageHop {
+    pub child: KeyFingerprint,
+    pub child_type: KeyType,
+    pub parent: Option<KeyFingerprint>,
+    pub parent_type: Option<KeyType>,
+    pub status: HopStatus,
+    pub detail: String,
+    pub proof_path: Option<PathBuf>,
+}
+
+/// Result of walking a key's lineage to the root
+#[derive(Debug, Clone)]
+pub struct LineageReport {
+    pub leaf: KeyFingerprint,
+    pub hops: Vec<LineageHop>,
+    /// Skull fingerprint the walk ended at, if it got that far
+    pub root: Option<KeyFingerprint>,
+}
+
+impl LineageReport {
+    /// True when the walk reached a Skull and every hop is valid
+    pub fn is_valid(&self) -> bool {
+        self.root.is_some() && self.hops.iter().all(|hop| hop.status == HopStatus::Valid)
+    }
+}
+
+/// Walk from `leaf` to the Skull, verifying the stored proofs for every edge
+pub fn verify_lineage(chain: &AuthorityChain, leaf: &KeyFingerprint) -> Result<LineageReport> {
+    let mut current = chain.get_key(leaf).ok_or_else(|| IgniteError::InvalidKey {
+        reason: format!("Key not found with fingerprint: {}", leaf),
+    })?;
+
+    let index = ProofIndex::load()?;
+    let mut hops = Vec::new();
+    let root = loop {
+        if current.key_type() == KeyType::Skull {
+            break Some(current.fingerprint().clone());
+        }
+
+        let parent = current.parent().and_then(|fp| chain.get_key(fp));
+        let Some(parent) = parent else {
+            hops.push(LineageHop {
+                child: current.fingerprint().clone(),
+                child_type: current.key_type(),
+                parent: current.parent().cloned(),
+                parent_type: current.key_type().parent_type(),
+                status: HopStatus::Missing,
+                detail: "parent key not found in vault".to_string(),
+                proof_path: None,
+            });
+            break None;
+        };
+
+        let (status, detail, proof_path) = assess_edge(&index, parent, current);
+        hops.push(LineageHop {
+            child: current.fingerprint().clone(),
+            child_type: current.key_type(),
+            parent: Some(parent.fingerprint().clone()),
+            parent_type: Some(parent.key_type()),
+            status,
+            detail,
+            proof_path,
+        });
+        current = parent;
+    };
+
+    Ok(LineageReport {
+        leaf: leaf.clone(),
+        hops,
+        root,
+    })
+}
+
+fn assess_edge(
+    index: &ProofIndex,
+    parent: &AuthorityKey,
+    child: &AuthorityKey,
+) -> (HopStatus, String, Option<PathBuf>) {
+    if !parent.can_control(child.key_type()) {
+        return (
+            HopStatus::Forged,
+            format!(
+                "{} cannot control {}",
+                parent.key_type().description(),
+                child.key_type().description()
+            ),
+            None,
+        );
+    }
+
+    let Some(entry) = index.current(parent.fingerprint(), child.fingerprint()) else {
+        return (
+            HopStatus::Missing,
+            "no authority claim stored for this edge".to_string(),
+            None,
+        );
+    };
+
+    let path = entry.claim_path();
+    let Ok(bundle) = storage::load_proof_from_path(&path) else {
+        return (
+            HopStatus::Missing,
+            "indexed authority claim missing".to_string(),
+            Some(path),
+        );
+    };
+    let names_edge = bundle.claim().is_ok_and(|claim| {
+        &claim.parent_fp == parent.fingerprint() && &claim.child_fp == child.fingerprint()
+    });
+    if !names_edge {
+        return (
+            HopStatus::Forged,
+            "indexed claim does not name this edge".to_string(),
+            Some(path),
+        );
+    }
+
+    let (status, detail) = assess_proof(&path, &bundle, parent, child);
+    (status, detail, Some(path))
+}
+
+fn assess_proof(
+    claim_path: &Path,
+    claim: &ProofBundle,
+    parent: &AuthorityKey,
+    child: &AuthorityKey,
+) -> (HopStatus, String) {
+    if let Err(e) = claim.verify_digest().and_then(|_| claim.verify_signature()) {
+        return (HopStatus::Forged, format!("claim signature invalid: {}", e));
+    }
+    if !signed_by(claim, parent.fingerprint()) {
+        return (
+            HopStatus::Forged,
+            "claim not signed by the recorded parent key".to_string(),
+        );
+    }
+
+    let receipt = match storage::load_proof_pair_from_path(claim_path) {
+        Ok(pair) => pair.receipt,
+        Err(_) => return (HopStatus::Missing, "subject receipt missing".to_string()),
+    };
+    if let Err(e) = receipt
+        .verify_digest()
+        .and_then(|_| receipt.verify_signature())
+    {
+        return (
+            HopStatus::Forged,
+            format!("receipt signature invalid: {}", e),
+        );
+    }
+    let receipt_names_edge = receipt.receipt().is_ok_and(|payload| {
+        &payload.parent_fp == parent.fingerprint() && &payload.child_fp == child.fingerprint()
+    });
+    if !receipt_names_edge || !signed_by(&receipt, child.fingerprint()) {
+        return (
+            HopStatus::Forged,
+            "receipt not countersigned by the child key".to_string(),
+        );
+    }
+
+    if claim.is_expired() || receipt.is_expired() {
+        let expired_at = claim.expires_at.min(receipt.expires_at);
+        return (
+            HopStatus::Expired,
+            format!("expired at {}", expired_at.format("%Y-%m-%d %H:%M:%S UTC")),
+        );
+    }
+
+    (HopStatus::Valid, "claim and receipt verified".to_string())
+}
+
+fn signed_by(bundle: &ProofBundle, fingerprint: &KeyFingerprint) -> bool {
+    bundle
+        .signer_fingerprint()
+        .is_ok_and(|signer| &signer == fingerprint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ignite::test_support::{
+        activate_proofs, issue_proof, issue_proof_signed_by, new_child, new_key, save_chain,
+        VaultEnvironment,
+    };
+    use hub::time_ext::chrono::Duration;
+    use serial_test::serial;
+
+    /// Persist skull → master → repo → ignition → distro with valid proofs
+    fn seed_lineage() -> Vec<AuthorityKey> {
+        let keys = save_chain(&[
+            KeyType::Skull,
+            KeyType::Master,
+            KeyType::Repo,
+            KeyType::Ignition,
+            KeyType::Distro,
+        ]);
+        activate_proofs(&keys, "test lineage", Duration::hours(1));
+        keys
+    }
+
+    fn statuses(report: &LineageReport) -> Vec<HopStatus> {
+        report.hops.iter().map(|hop| hop.status).collect()
+    }
+
+    #[test]
+    #[serial]
+    fn test_verify_lineage_valid_chain() {
+        let _env = VaultEnvironment::new();
+        let keys = seed_lineage();
+
+        let chain = AuthorityChain::load_from_vault().unwrap();
+        let report = verify_lineage(&chain, keys[4].fingerprint()).unwrap();
+
+        assert_eq!(report.hops.len(), 4);
+        assert_eq!(statuses(&report), vec![HopStatus::Valid; 4]);
+        assert_eq!(report.root.as_ref(), Some(keys[0].fingerprint()));
+        assert!(report.is_valid());
+
+        let skull_report = verify_lineage(&chain, keys[0].fingerprint()).unwrap();
+        assert!(skull_report.hops.is_empty());
+        assert!(skull_report.is_valid());
+    }
+
+    #[test]
+    #[serial]
+    fn test_verify_lineage_flags_missing_links() {
+        let _env = VaultEnvironment::new();
+        let keys = seed_lineage();
+
+        // Drop the repo -> ignition receipt and every master -> repo proof
+        let ignition_claim =
+            storage::proof_path(keys[2].fingerprint(), &keys[3].fingerprint().short());
+        std::fs::remove_file(storage::counterpart_proof_path(&ignition_claim).unwrap()).unwrap();
+        std::fs::remove_dir_all(
+            crate::ignite::utils::proofs_dir().join(keys[1].fingerprint().short()),
+        )
+        .unwrap();
+
+        let chain = AuthorityChain::load_from_vault().unwrap();
+        let report = verify_lineage(&chain, keys[4].fingerprint()).unwrap();
+
+        assert_eq!(
+            statuses(&report),
+            vec![
+                HopStatus::Valid,
+                HopStatus::Missing,
+                HopStatus::Missing,
+                HopStatus::Valid
+            ]
+        );
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    #[serial]
+    fn test_verify_lineage_flags_expired_and_forged() {
+        let _env = VaultEnvironment::new();
+        let mut skull = new_key(KeyType::Skull);
+        let mut master = new_child(KeyType::Master, &mut skull);
+        let repo = new_child(KeyType::Repo, &mut master);
+        let impostor = new_key(KeyType::Master);
+
+        for key in [&skull, &master, &repo] {
+            storage::save_key(key).unwrap();
+        }
+        let expired = issue_proof(&skull, &master, "test lineage", Duration::seconds(-1));
+        let forged = issue_proof_signed_by(
+            &impostor,
+            &master,
+            &repo,
+            "test lineage",
+            Duration::hours(1),
+        );
+        for (pair, child) in [(expired, &master), (forged, &repo)] {
+            let claim = pair.claim.claim().unwrap();
+            storage::save_proof_pair(&pair, &claim.parent_fp, &child.fingerprint().short())
+                .unwrap();
+        }
+
+        let chain = AuthorityChain::load_from_vault().unwrap();
+        let report = verify_lineage(&chain, repo.fingerprint()).unwrap();
+
+        assert_eq!(
+            statuses(&report),
+            vec![HopStatus::Forged, HopStatus::Expired]
+        );
+        assert!(report.root.is_some());
+        assert!(!report.is_valid());
+    }
+}
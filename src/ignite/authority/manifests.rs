@@ -2,13 +2,19 @@
 //!
 //! Implements manifest tracking for rotation/revocation events per IGNITE_MANIFEST.md.
 //! Manifests record descendants invalidated by authority operations to enable
-//! downstream automation.
+//! downstream automation. Each manifest is signed with Ed25519 by the key named
+//! in `event.parent_fingerprint`, so a recomputed digest alone cannot pass
+//! verification.
 
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
 use hub::data_ext::serde::{Deserialize, Serialize};
+use hub::data_ext::serde_json;
 use hub::time_ext::chrono::{DateTime, Utc};
 use sha2::{Digest, Sha256};
 
-use super::chain::{KeyFingerprint, KeyType};
+use super::canonical;
+use super::chain::{AuthorityChain, AuthorityKey, KeyFingerprint, KeyType};
+use crate::ignite::correlation;
 use crate::ignite::error::{IgniteError, Result};
 
 /// Type of manifest event
@@ -57,9 +63,13 @@ impl ManifestEventType {
 pub struct ManifestEvent {
     pub event_type: ManifestEventType,
     pub parent_fingerprint: KeyFingerprint,
+    #[serde(with = "canonical::timestamp")]
     pub initiated_at: DateTime<Utc>,
     pub initiated_by: String,
     pub reason: String,
+    /// Correlation ID of the rotation or revocation that produced the manifest
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
 }
 
 impl ManifestEvent {
@@ -74,6 +84,7 @@ impl ManifestEvent {
             initiated_at: Utc::now(),
             initiated_by: "ignite-cli".to_string(),
             reason: reason.into(),
+            operation_id: correlation::current(),
         }
     }
 }
@@ -100,6 +111,17 @@ impl ManifestDigest {
     }
 }
 
+/// Domain-separation tag prefixed to the manifest digest before signing
+pub const MANIFEST_SIGNATURE_DOMAIN: &str = "ignite-manifest-v1";
+
+/// Ed25519 signature over the manifest digest by the event's parent key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestSignature {
+    pub algorithm: String,
+    pub public_key: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
 /// Scope metadata for affected keys
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManifestScope {
@@ -122,9 +144,17 @@ pub struct ManifestChild {
     pub fingerprint: KeyFingerprint,
     pub role: KeyType,
     pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ciphertext_md5: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scope: Option<ManifestScope>,
+    #[serde(with = "canonical::timestamp")]
     pub issued_at: DateTime<Utc>,
+    #[serde(
+        default,
+        with = "canonical::timestamp::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub revoked_at: Option<DateTime<Utc>>,
 }
 
@@ -168,6 +198,8 @@ pub struct AffectedKeyManifest {
     pub schema_version: String,
     pub event: ManifestEvent,
     pub digest: Option<ManifestDigest>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<ManifestSignature>,
     pub children: Vec<ManifestChild>,
 }
 
@@ -177,6 +209,7 @@ impl AffectedKeyManifest {
             schema_version: "1.0".to_string(),
             event,
             digest: None,
+            signature: None,
             children: Vec::new(),
         }
     }
@@ -185,65 +218,17 @@ impl AffectedKeyManifest {
         self.children.push(child);
     }
 
-    /// Serialize to canonical JSON (sorted keys, excluding digest object)
+    /// Serialize to canonical JSON (RFC 8785), excluding the digest and signature objects
     pub fn to_canonical_json(&self) -> Result<String> {
-        // TODO: Implement proper canonical JSON serialization with sorted keys
-        // For now, manually construct in alphabetical order per spec
-        let children_json: Vec<String> = self
-            .children
-            .iter()
-            .map(|c| {
-                let revoked_at = c
-                    .revoked_at
-                    .map(|t| format!(r#","revoked_at":"{}""#, t.to_rfc3339()))
-                    .unwrap_or_default();
-                let ciphertext = c
-                    .ciphertext_md5
-                    .as_ref()
-                    .map(|md5| format!(r#","ciphertext_md5":"{}""#, md5))
-                    .unwrap_or_default();
-                let scope = c
-                    .scope
-                    .as_ref()
-                    .map(|s| {
-                        let paths = s
-                            .paths
-                            .iter()
-                            .map(|p| format!(r#""{}""#, p))
-                            .collect::<Vec<_>>()
-                            .join(",");
-                        format!(r#","scope":{{"env":"{}","paths":[{}]}}"#, s.env, paths)
-                    })
-                    .unwrap_or_default();
-
-                format!(
-                    r#"{{"fingerprint":"{}","issued_at":"{}","role":"{}","status":"{}"{}{}{}}}"#,
-                    c.fingerprint,
-                    c.issued_at.to_rfc3339(),
-                    c.role,
-                    c.status,
-                    ciphertext,
-                    scope,
-                    revoked_at
-                )
-            })
-            .collect();
-
-        let event_json = format!(
-            r#"{{"event_type":"{}","initiated_at":"{}","initiated_by":"{}","parent_fingerprint":"{}","reason":"{}"}}"#,
-            self.event.event_type.as_str(),
-            self.event.initiated_at.to_rfc3339(),
-            self.event.initiated_by,
-            self.event.parent_fingerprint,
-            self.event.reason
-        );
-
-        Ok(format!(
-            r#"{{"children":[{}],"event":{},"schema_version":"{}"}}"#,
-            children_json.join(","),
-            event_json,
-            self.schema_version
-        ))
+        let mut body = serde_json::to_value(self).map_err(|e| IgniteError::InvalidOperation {
+            operation: "serialize_manifest".to_string(),
+            reason: e.to_string(),
+        })?;
+        if let Some(object) = body.as_object_mut() {
+            object.remove("digest");
+            object.remove("signature");
+        }
+        canonical::canonicalize(&body)
     }
 
     /// Compute and set digest for this manifest
@@ -253,39 +238,16 @@ impl AffectedKeyManifest {
         Ok(())
     }
 
-    /// Serialize to complete JSON including digest
+    /// Serialize to complete canonical JSON including digest
     pub fn to_json_with_digest(&self) -> Result<String> {
-        let canonical = self.to_canonical_json()?;
-        let digest = self
-            .digest
-            .as_ref()
-            .ok_or_else(|| IgniteError::InvalidOperation {
+        if self.digest.is_none() {
+            return Err(IgniteError::InvalidOperation {
                 operation: "serialize_manifest".to_string(),
                 reason: "Digest not computed - call compute_digest() first".to_string(),
-            })?;
-
-        let digest_json = format!(
-            r#"{{"algorithm":"{}","manifest_body":"{}","value":"{}"}}"#,
-            digest.algorithm, digest.manifest_body, digest.value
-        );
-
-        // Insert digest into canonical JSON
-        // Find the position after "children":[...] and before "event":
-        let insert_pos =
-            canonical
-                .find(",\"event\":")
-                .ok_or_else(|| IgniteError::InvalidOperation {
-                    operation: "insert_digest".to_string(),
-                    reason: "Could not find event field in JSON".to_string(),
-                })?;
-
-        let mut result = String::with_capacity(canonical.len() + digest_json.len() + 20);
-        result.push_str(&canonical[..insert_pos]);
-        result.push_str(",\"digest\":");
-        result.push_str(&digest_json);
-        result.push_str(&canonical[insert_pos..]);
+            });
+        }
 
-        Ok(result)
+        canonical::to_canonical_json(self)
     }
 
     /// Verify digest matches canonical payload
@@ -311,6 +273,198 @@ impl AffectedKeyManifest {
         Ok(())
     }
 
+    /// Bytes actually signed: `<domain>:<hex digest>`
+    fn signing_message(digest: &ManifestDigest) -> Vec<u8> {
+        format!("{}:{}", MANIFEST_SIGNATURE_DOMAIN, digest.value).into_bytes()
+    }
+
+    /// Compute the digest and sign it with the event's parent key
+    pub fn sign(&mut self, signing_key: &SigningKey) -> Result<()> {
+        let public_key = signing_key.verifying_key().to_bytes().to_vec();
+        let signer = KeyFingerprint::from_key_material(&public_key)?;
+        if signer != self.event.parent_fingerprint {
+            return Err(IgniteError::InvalidOperation {
+                operation: "sign_manifest".to_string(),
+                reason: format!(
+                    "Manifest names parent {} but signing key is {}",
+                    self.event.parent_fingerprint, signer
+                ),
+            });
+        }
+
+        let digest = ManifestDigest::compute(&self.to_canonical_json()?);
+        let signature = signing_key.sign(&Self::signing_message(&digest));
+        self.digest = Some(digest);
+        self.signature = Some(ManifestSignature {
+            algorithm: "Ed25519".to_string(),
+            public_key,
+            signature: signature.to_bytes().to_vec(),
+        });
+        Ok(())
+    }
+
+    fn stored_signature(&self) -> Result<&ManifestSignature> {
+        self.signature.as_ref().ok_or_else(|| {
+            IgniteError::crypto_error("verify_manifest_signature", "Manifest is not signed")
+        })
+    }
+
+    /// Fingerprint of the key that produced the signature
+    pub fn signer_fingerprint(&self) -> Result<KeyFingerprint> {
+        KeyFingerprint::from_key_material(&self.stored_signature()?.public_key)
+    }
+
+    /// Verify the digest, then the Ed25519 signature by `event.parent_fingerprint`
+    ///
+    /// Uses strict verification and rejects weak (small-order) public keys.
+    pub fn verify_signature(&self) -> Result<()> {
+        self.verify_digest()?;
+
+        let stored = self.stored_signature()?;
+        let signer = self.signer_fingerprint()?;
+        if signer != self.event.parent_fingerprint {
+            return Err(IgniteError::crypto_error(
+                "verify_manifest_signature",
+                format!(
+                    "Manifest signed by {} but names parent {}",
+                    signer, self.event.parent_fingerprint
+                ),
+            ));
+        }
+
+        if stored.algorithm != "Ed25519" {
+            return Err(IgniteError::crypto_error(
+                "verify_manifest_signature",
+                format!("Unsupported signature algorithm: {}", stored.algorithm),
+            ));
+        }
+
+        let public_key =
+            VerifyingKey::from_bytes(stored.public_key.as_slice().try_into().map_err(|_| {
+                IgniteError::crypto_error("parse_public_key", "Invalid key length")
+            })?)
+            .map_err(|e| IgniteError::crypto_error("parse_public_key", e.to_string()))?;
+        if public_key.is_weak() {
+            return Err(IgniteError::crypto_error(
+                "parse_public_key",
+                "Weak (small-order) public key rejected",
+            ));
+        }
+
+        let signature =
+            Signature::from_bytes(stored.signature.as_slice().try_into().map_err(|_| {
+                IgniteError::crypto_error("parse_signature", "Invalid signature length")
+            })?);
+        public_key
+            .verify_strict(
+                &Self::signing_message(&ManifestDigest::compute(&self.to_canonical_json()?)),
+                &signature,
+            )
+            .map_err(|e| IgniteError::crypto_error("verify_manifest_signature", e.to_string()))
+    }
+
+    /// Full verification against `chain`: digest and signature, then the signer's authority
+    ///
+    /// The signer must be the vault key with the embedded public key, and must
+    /// be an ancestor of (or equal to) every affected key. Its standing is
+    /// judged at `event.initiated_at`: a signer created later, already
+    /// expired, tombstoned or rotated out by then is rejected. Losing authority
+    /// after signing does not invalidate the manifest and is returned as a
+    /// warning instead. A root key's record of its own retirement is accepted,
+    /// since nothing else could have signed it.
+    pub fn verify_full(&self, chain: &AuthorityChain) -> Result<Vec<String>> {
+        self.verify_signature()?;
+
+        let signer_fp = &self.event.parent_fingerprint;
+        let signed_at = self.event.initiated_at;
+        let rejected = |reason: String| IgniteError::crypto_error("verify_manifest_signer", reason);
+        let signer = chain
+            .get_key(signer_fp)
+            .ok_or_else(|| rejected(format!("Signer {} is not in the vault", signer_fp)))?;
+        if self.stored_signature()?.public_key != signer.key_material().public_key() {
+            return Err(rejected(format!(
+                "Embedded public key does not match vault key {}",
+                signer_fp
+            )));
+        }
+
+        let mut warnings = Vec::new();
+        let retires_signer = signer.parent().is_none()
+            && self.children.iter().any(|child| {
+                &child.fingerprint == signer_fp && child.status == signer.status().as_str()
+            });
+        if !retires_signer {
+            if signer.metadata().creation_time > signed_at {
+                return Err(rejected(format!(
+                    "Signer {} did not exist when the manifest was signed",
+                    signer_fp
+                )));
+            }
+            if let Some(expiration) = signer.metadata().expiration() {
+                if expiration <= signed_at {
+                    return Err(rejected(format!(
+                        "Signer {} had expired at {} when the manifest was signed",
+                        signer_fp, expiration
+                    )));
+                }
+            }
+            if let Some(tombstone) = chain.tombstone(signer_fp) {
+                if tombstone.revoked_at <= signed_at {
+                    return Err(rejected(format!(
+                        "Signer {} was tombstoned at {}, before the manifest was signed",
+                        signer_fp, tombstone.revoked_at
+                    )));
+                }
+                warnings.push(format!(
+                    "Signer {} was tombstoned at {}, after this manifest was signed",
+                    signer_fp, tombstone.revoked_at
+                ));
+            } else if !signer.is_active() {
+                match Self::rotated_at(chain, signer) {
+                    Some(rotated_at) if rotated_at <= signed_at => {
+                        return Err(rejected(format!(
+                            "Signer {} was rotated out at {} and held no authority when the manifest was signed",
+                            signer_fp, rotated_at
+                        )));
+                    }
+                    Some(rotated_at) => warnings.push(format!(
+                        "Signer {} was rotated out at {}, after this manifest was signed",
+                        signer_fp, rotated_at
+                    )),
+                    None => warnings.push(format!(
+                        "Signer {} is now {}; the vault does not record when that changed",
+                        signer_fp,
+                        signer.status()
+                    )),
+                }
+            }
+        }
+
+        if let Some(child) = self
+            .children
+            .iter()
+            .find(|child| !chain.has_transitive_authority(signer_fp, &child.fingerprint))
+        {
+            return Err(rejected(format!(
+                "Signer {} has no authority over affected key {}",
+                signer_fp, child.fingerprint
+            )));
+        }
+        Ok(warnings)
+    }
+
+    /// When `key` was replaced: the creation time of its earliest successor in the same lineage
+    fn rotated_at(chain: &AuthorityChain, key: &AuthorityKey) -> Option<DateTime<Utc>> {
+        let lineage = key.lineage_id();
+        chain
+            .keys()
+            .filter(|other| other.fingerprint() != key.fingerprint())
+            .filter(|other| other.lineage_id() == lineage)
+            .map(|other| other.metadata().creation_time)
+            .filter(|created| *created > key.metadata().creation_time)
+            .min()
+    }
+
     /// Generate filename for this manifest (parent_fp/timestamp_event.json)
     pub fn filename(&self) -> String {
         let timestamp = self
@@ -335,6 +489,7 @@ impl AffectedKeyManifest {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ignite::authority::chain::KeyMaterial;
     use hub::time_ext::chrono::TimeZone;
 
     fn create_test_fingerprint(suffix: &str) -> KeyFingerprint {
@@ -475,6 +630,40 @@ mod tests {
         assert!(event_pos < schema_pos);
     }
 
+    #[test]
+    fn test_manifest_canonical_json_round_trip() {
+        let parent_fp = create_test_fingerprint("parent");
+        let event = ManifestEvent::new(
+            ManifestEventType::Revocation,
+            parent_fp,
+            "operator said \"rotate\"",
+        );
+        let mut manifest = AffectedKeyManifest::new(event);
+        manifest.add_child(
+            ManifestChild::new(
+                create_test_fingerprint("child"),
+                KeyType::Ignition,
+                "revoked",
+                Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+            )
+            .with_scope(ManifestScope::new(
+                vec![r"C:\locker\docs".to_string()],
+                "production",
+            )),
+        );
+        manifest.compute_digest().unwrap();
+
+        let json = manifest.to_json_with_digest().unwrap();
+        assert!(json.contains(r#""issued_at":"2024-01-01T12:00:00Z""#));
+        assert!(!json.contains("revoked_at"));
+
+        // The stored form parses back and still verifies
+        let loaded: AffectedKeyManifest = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.event.reason, manifest.event.reason);
+        assert!(loaded.verify_digest().is_ok());
+        assert_eq!(loaded.to_json_with_digest().unwrap(), json);
+    }
+
     #[test]
     fn test_manifest_digest_computation() {
         let event = create_test_event();
@@ -578,4 +767,184 @@ mod tests {
         assert_eq!(scope.paths[0], "src/main.rs");
         assert_eq!(scope.env, "development");
     }
+
+    fn signed_manifest(
+        signer: &AuthorityKey,
+        event_type: ManifestEventType,
+        children: &[(&AuthorityKey, &str)],
+    ) -> AffectedKeyManifest {
+        let mut manifest = AffectedKeyManifest::new(ManifestEvent::new(
+            event_type,
+            signer.fingerprint().clone(),
+            "Test event",
+        ));
+        for (child, status) in children {
+            manifest.add_child(ManifestChild::new(
+                child.fingerprint().clone(),
+                child.key_type(),
+                *status,
+                Utc::now(),
+            ));
+        }
+        manifest
+            .sign(&signer.key_material().signing_key().unwrap())
+            .unwrap();
+        manifest
+    }
+
+    /// Skull → master → repo, all active
+    fn signing_chain() -> (AuthorityChain, AuthorityKey, AuthorityKey, AuthorityKey) {
+        let key = |key_type| {
+            AuthorityKey::new(KeyMaterial::generate_ed25519(), key_type, None, None).unwrap()
+        };
+        let (skull, master, repo) = (
+            key(KeyType::Skull),
+            key(KeyType::Master),
+            key(KeyType::Repo),
+        );
+        let mut chain = AuthorityChain::new();
+        for key in [&skull, &master, &repo] {
+            chain.add_key(key.clone()).unwrap();
+        }
+        chain
+            .add_authority_relationship(skull.fingerprint(), master.fingerprint())
+            .unwrap();
+        chain
+            .add_authority_relationship(master.fingerprint(), repo.fingerprint())
+            .unwrap();
+        (chain, skull, master, repo)
+    }
+
+    #[test]
+    fn test_signed_manifest_detects_forgery() {
+        let (chain, _, master, repo) = signing_chain();
+        let impostor =
+            AuthorityKey::new(KeyMaterial::generate_ed25519(), KeyType::Master, None, None)
+                .unwrap();
+
+        let manifest = signed_manifest(&master, ManifestEventType::Rotation, &[(&repo, "invalid")]);
+        assert!(manifest.verify_full(&chain).is_ok());
+        let json = manifest.to_json_with_digest().unwrap();
+        let reloaded: AffectedKeyManifest = serde_json::from_str(&json).unwrap();
+        assert!(reloaded.verify_full(&chain).is_ok());
+
+        // Editing the body and recomputing the digest no longer passes
+        let mut edited = manifest.clone();
+        edited.children.clear();
+        edited.compute_digest().unwrap();
+        assert!(edited.verify_digest().is_ok());
+        assert!(edited.verify_signature().is_err());
+
+        // A self-consistent manifest from a key outside the vault is rejected
+        let mut forged = signed_manifest(
+            &impostor,
+            ManifestEventType::Rotation,
+            &[(&repo, "invalid")],
+        );
+        assert!(forged.verify_signature().is_ok());
+        assert!(forged.verify_full(&chain).is_err());
+        forged.event.parent_fingerprint = master.fingerprint().clone();
+        assert!(forged.verify_signature().is_err());
+
+        let mut unsigned = manifest.clone();
+        unsigned.signature = None;
+        assert!(unsigned.verify_full(&chain).is_err());
+        assert!(AffectedKeyManifest::new(create_test_event())
+            .sign(&master.key_material().signing_key().unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_signer_needs_authority_over_every_affected_key() {
+        let (chain, skull, master, repo) = signing_chain();
+
+        // A low-tier key cannot declare its ancestors invalidated
+        let escalation = signed_manifest(
+            &repo,
+            ManifestEventType::Revocation,
+            &[(&repo, "revoked"), (&master, "invalid")],
+        );
+        assert!(escalation.verify_signature().is_ok());
+        assert!(escalation.verify_full(&chain).is_err());
+
+        // Authority reaches every descendant, not just direct children
+        let cascade = signed_manifest(
+            &skull,
+            ManifestEventType::Rotation,
+            &[(&master, "archived"), (&repo, "invalid")],
+        );
+        assert!(cascade.verify_full(&chain).is_ok());
+    }
+
+    #[test]
+    fn test_signer_standing_is_judged_at_signing_time() {
+        use crate::ignite::authority::chain::KeyStatus;
+        use crate::ignite::authority::rotation;
+        use crate::ignite::authority::tombstones::{RevocationReason, Tombstone};
+        use hub::time_ext::chrono::Duration;
+
+        let (mut chain, _, master, repo) = signing_chain();
+        let manifest = signed_manifest(&master, ManifestEventType::Rotation, &[(&repo, "invalid")]);
+        let signed_at = manifest.event.initiated_at;
+
+        // Losing authority after signing is a warning, not a rejection
+        chain
+            .get_key_mut(master.fingerprint())
+            .unwrap()
+            .set_status(KeyStatus::Revoked);
+        assert_eq!(manifest.verify_full(&chain).unwrap().len(), 1);
+
+        let mut tombstone = Tombstone::new(
+            master.fingerprint().clone(),
+            KeyType::Master,
+            RevocationReason::KeyCompromise,
+            "alice",
+        );
+        tombstone.revoked_at = signed_at + Duration::seconds(1);
+        chain.add_tombstone(tombstone.clone());
+        assert_eq!(manifest.verify_full(&chain).unwrap().len(), 1);
+
+        // Signatures made after the tombstone or expiry are rejected
+        tombstone.revoked_at = signed_at - Duration::seconds(1);
+        chain.add_tombstone(tombstone);
+        assert!(manifest.verify_full(&chain).is_err());
+
+        let (mut chain, _, master, repo) = signing_chain();
+        let manifest = signed_manifest(&master, ManifestEventType::Rotation, &[(&repo, "invalid")]);
+        assert!(manifest.verify_full(&chain).unwrap().is_empty());
+        chain
+            .get_key_mut(master.fingerprint())
+            .unwrap()
+            .metadata_mut()
+            .set_expiration(Some(signed_at - Duration::seconds(1)));
+        assert!(manifest.verify_full(&chain).is_err());
+
+        // A rotated-out key keeps its earlier manifests but cannot sign new ones
+        let (mut chain, skull, master, _) = signing_chain();
+        let earlier = signed_manifest(&skull, ManifestEventType::Rotation, &[(&master, "invalid")]);
+        let mut successor = rotation::replacement_for(&skull, None).unwrap();
+        successor.metadata_mut().creation_time = earlier.event.initiated_at + Duration::seconds(1);
+        chain.add_key(successor).unwrap();
+        chain
+            .get_key_mut(skull.fingerprint())
+            .unwrap()
+            .set_status(KeyStatus::Archived);
+        assert_eq!(earlier.verify_full(&chain).unwrap().len(), 1);
+
+        let mut later = earlier.clone();
+        later.event.initiated_at = earlier.event.initiated_at + Duration::seconds(2);
+        later.signature = None;
+        later
+            .sign(&skull.key_material().signing_key().unwrap())
+            .unwrap();
+        assert!(later.verify_full(&chain).is_err());
+
+        // A root key's record of its own retirement stays verifiable
+        let retirement = signed_manifest(
+            &skull,
+            ManifestEventType::Rotation,
+            &[(&skull, "archived"), (&master, "invalid")],
+        );
+        assert!(retirement.verify_full(&chain).is_ok());
+    }
 }
@@ -3,11 +3,27 @@
 //! Implements the X→M→R→I→D authority hierarchy with cryptographic proofs,
 //! manifests, and key lifecycle management.
 
+pub mod approvals;
+pub mod canonical;
 pub mod chain;
+pub mod ignition;
+pub mod lineage;
 pub mod manifests;
+pub mod proof_archive;
 pub mod proofs;
+pub mod renewal;
+pub mod revocation;
+pub mod rotation;
+pub mod sealing;
+pub mod skull_shares;
 pub mod storage;
+pub mod tombstones;
 
-pub use chain::{AuthorityKey, KeyFingerprint, KeyFormat, KeyMaterial, KeyMetadata, KeyType};
+pub use approvals::{Approval, ApprovalAction, OperationRequest};
+pub use chain::{
+    AuthorityChain, AuthorityKey, KeyFingerprint, KeyFormat, KeyMaterial, KeyMetadata, KeyStatus,
+    KeyType,
+};
 pub use manifests::{AffectedKeyManifest, ManifestChild, ManifestEvent};
-pub use proofs::{AuthorityClaim, ProofBundle, SubjectReceipt};
+pub use proofs::{AuthorityClaim, ProofBundle, ProofPair, SubjectReceipt};
+pub use tombstones::{RevocationReason, Tombstone};
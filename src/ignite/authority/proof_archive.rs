@@ -0,0 +1,496 @@
+//! Proof archive and active-proof index.
+//!
+//! Each parent→child edge has at most one active proof pair, recorded in
+//! `proofs/index.json`. Issuing a new pair for an edge replaces its index
+//! entry and moves the previous pair to `proofs/archive/<parent_short>/`
+//! together with a `.retired.json` record naming the event that retired it.
+//! Rotation and revocation retire every edge into the affected keys.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use hub::data_ext::serde::{Deserialize, Serialize};
+use hub::time_ext::chrono::{DateTime, Utc};
+
+use super::canonical;
+use super::chain::KeyFingerprint;
+use super::proofs::ProofPair;
+use super::storage;
+use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::utils;
+
+/// Event that moved a proof pair out of the active set
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum RetirementEvent {
+    /// A newer pair was issued for the same edge outside of renewal
+    Superseded,
+    /// The pair was re-issued before expiry by `ignite proof renew`
+    Renewed,
+    /// The child (or an ancestor of it) was rotated
+    Rotated,
+    /// The child (or an ancestor of it) was revoked
+    Revoked,
+}
+
+impl RetirementEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RetirementEvent::Superseded => "superseded",
+            RetirementEvent::Renewed => "renewed",
+            RetirementEvent::Rotated => "rotated",
+            RetirementEvent::Revoked => "revoked",
+        }
+    }
+}
+
+impl From<RetirementEvent> for String {
+    fn from(event: RetirementEvent) -> String {
+        event.as_str().to_string()
+    }
+}
+
+impl TryFrom<String> for RetirementEvent {
+    type Error = IgniteError;
+
+    fn try_from(s: String) -> Result<Self> {
+        match s.as_str() {
+            "superseded" => Ok(RetirementEvent::Superseded),
+            "renewed" => Ok(RetirementEvent::Renewed),
+            "rotated" => Ok(RetirementEvent::Rotated),
+            "revoked" => Ok(RetirementEvent::Revoked),
+            _ => Err(IgniteError::InvalidOperation {
+                operation: "parse_retirement_event".to_string(),
+                reason: format!("Unknown retirement event: {}", s),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for RetirementEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Active proof pair for one parent→child edge
+///
+/// Paths are relative to the proofs directory so the vault can be relocated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProofIndexEntry {
+    pub parent_fp: KeyFingerprint,
+    pub child_fp: KeyFingerprint,
+    pub claim: String,
+    pub receipt: String,
+    #[serde(with = "canonical::timestamp")]
+    pub expires_at: DateTime<Utc>,
+}
+
+impl ProofIndexEntry {
+    pub fn claim_path(&self) -> PathBuf {
+        utils::proofs_dir().join(&self.claim)
+    }
+
+    pub fn receipt_path(&self) -> PathBuf {
+        utils::proofs_dir().join(&self.receipt)
+    }
+
+    pub fn load_pair(&self) -> Result<ProofPair> {
+        storage::load_proof_pair_from_path(&self.claim_path())
+    }
+
+    fn is_edge(&self, parent: &KeyFingerprint, child: &KeyFingerprint) -> bool {
+        &self.parent_fp == parent && &self.child_fp == child
+    }
+}
+
+/// Index of the active proof for every edge
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProofIndex {
+    pub schema_version: String,
+    pub entries: Vec<ProofIndexEntry>,
+}
+
+impl ProofIndex {
+    pub fn new() -> Self {
+        Self {
+            schema_version: "1.0".to_string(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Load the index, rebuilding it in memory for vaults that predate it
+    pub fn load() -> Result<Self> {
+        match storage::load_proof_index()? {
+            Some(index) => Ok(index),
+            None => Self::rebuild(),
+        }
+    }
+
+    /// Reconstruct the index from the claims on disk
+    ///
+    /// Where an edge has several claims the one expiring last is treated as
+    /// active. Nothing is written; call `save` to persist the result.
+    pub fn rebuild() -> Result<Self> {
+        let mut index = Self::new();
+        for path in storage::list_all_proofs()? {
+            let Ok(bundle) = storage::load_proof_from_path(&path) else {
+                continue;
+            };
+            let Ok(claim) = bundle.claim() else {
+                continue;
+            };
+            let Some(receipt) = storage::counterpart_proof_path(&path) else {
+                continue;
+            };
+
+            let entry = ProofIndexEntry {
+                parent_fp: claim.parent_fp,
+                child_fp: claim.child_fp,
+                claim: relative_to_proofs(&path)?,
+                receipt: relative_to_proofs(&receipt)?,
+                expires_at: bundle.expires_at,
+            };
+            let newer = index
+                .current(&entry.parent_fp, &entry.child_fp)
+                .is_none_or(|existing| existing.expires_at < entry.expires_at);
+            if newer {
+                index.insert(entry);
+            }
+        }
+        Ok(index)
+    }
+
+    pub fn save(&self) -> Result<PathBuf> {
+        storage::save_proof_index(self)
+    }
+
+    /// Active proof for `parent -> child`, if any
+    pub fn current(
+        &self,
+        parent: &KeyFingerprint,
+        child: &KeyFingerprint,
+    ) -> Option<&ProofIndexEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.is_edge(parent, child))
+    }
+
+    /// Make `entry` active for its edge, returning the entry it replaced
+    pub fn insert(&mut self, entry: ProofIndexEntry) -> Option<ProofIndexEntry> {
+        let previous = self.remove(&entry.parent_fp, &entry.child_fp);
+        self.entries.push(entry);
+        self.entries.sort_by(|a, b| {
+            (a.parent_fp.to_string(), a.child_fp.to_string())
+                .cmp(&(b.parent_fp.to_string(), b.child_fp.to_string()))
+        });
+        previous
+    }
+
+    pub fn remove(
+        &mut self,
+        parent: &KeyFingerprint,
+        child: &KeyFingerprint,
+    ) -> Option<ProofIndexEntry> {
+        let position = self
+            .entries
+            .iter()
+            .position(|entry| entry.is_edge(parent, child))?;
+        Some(self.entries.remove(position))
+    }
+}
+
+/// Record kept beside an archived proof pair
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedProof {
+    pub schema_version: String,
+    pub parent_fp: KeyFingerprint,
+    pub child_fp: KeyFingerprint,
+    pub event: RetirementEvent,
+    #[serde(with = "canonical::timestamp")]
+    pub retired_at: DateTime<Utc>,
+    #[serde(with = "canonical::timestamp")]
+    pub expires_at: DateTime<Utc>,
+    /// Archived claim and receipt, relative to the proofs directory
+    pub claim: String,
+    pub receipt: Option<String>,
+    /// Claim that took over the edge, if any
+    pub replaced_by: Option<String>,
+}
+
+/// Paths of a newly activated proof pair
+#[derive(Debug, Clone)]
+pub struct ActivatedProof {
+    pub claim_path: PathBuf,
+    pub receipt_path: PathBuf,
+    /// Previously active pair for the edge, now archived
+    pub retired: Option<ArchivedProof>,
+}
+
+/// Filename stem for a new proof pair, unique per child within a second
+pub fn proof_timestamp(child: &KeyFingerprint) -> String {
+    format!("{}_{}", Utc::now().format("%Y%m%d_%H%M%S"), child.short())
+}
+
+/// Store `pair` as the active proof for its edge
+///
+/// The previously active pair for the same edge, if any, is archived with
+/// `event` and points at the new claim.
+pub fn activate(
+    pair: &ProofPair,
+    timestamp: &str,
+    event: RetirementEvent,
+) -> Result<ActivatedProof> {
+    let claim = pair.claim.claim()?;
+    let (claim_path, receipt_path) = storage::save_proof_pair(pair, &claim.parent_fp, timestamp)?;
+
+    let entry = ProofIndexEntry {
+        parent_fp: claim.parent_fp,
+        child_fp: claim.child_fp,
+        claim: relative_to_proofs(&claim_path)?,
+        receipt: relative_to_proofs(&receipt_path)?,
+        expires_at: pair.expires_at(),
+    };
+    let replaced_by = entry.claim.clone();
+
+    let mut index = ProofIndex::load()?;
+    let retired = match index.insert(entry) {
+        Some(previous) if previous.claim != replaced_by => {
+            archive_entry(&previous, event, Some(replaced_by))?
+        }
+        _ => None,
+    };
+    index.save()?;
+
+    Ok(ActivatedProof {
+        claim_path,
+        receipt_path,
+        retired,
+    })
+}
+
+/// Archive the active proof of every edge whose child is in `children`
+pub fn retire_edges(
+    children: &[KeyFingerprint],
+    event: RetirementEvent,
+) -> Result<Vec<ArchivedProof>> {
+    let mut index = ProofIndex::load()?;
+    let (retiring, kept): (Vec<_>, Vec<_>) = index
+        .entries
+        .drain(..)
+        .partition(|entry| children.contains(&entry.child_fp));
+    index.entries = kept;
+
+    let mut archived = Vec::new();
+    for entry in &retiring {
+        archived.extend(archive_entry(entry, event, None)?);
+    }
+    index.save()?;
+
+    Ok(archived)
+}
+
+/// Archived pairs retired before `cutoff`, oldest first
+pub fn archived_before(cutoff: DateTime<Utc>) -> Result<Vec<ArchivedProof>> {
+    Ok(storage::list_archived_proofs()?
+        .into_iter()
+        .filter(|record| record.retired_at < cutoff)
+        .collect())
+}
+
+/// Permanently delete archived pairs and their retirement records
+///
+/// Callers gate this behind the danger-mode guard; it cannot be undone.
+pub fn purge(records: &[ArchivedProof]) -> Result<usize> {
+    let mut removed = 0;
+    for record in records {
+        removed += storage::remove_archived_proof(record)?.len();
+    }
+    Ok(removed)
+}
+
+fn archive_entry(
+    entry: &ProofIndexEntry,
+    event: RetirementEvent,
+    replaced_by: Option<String>,
+) -> Result<Option<ArchivedProof>> {
+    let claim_path = entry.claim_path();
+    if !claim_path.exists() {
+        return Ok(None);
+    }
+
+    let moved = storage::archive_proof_pair(&claim_path)?;
+    let record = ArchivedProof {
+        schema_version: "1.0".to_string(),
+        parent_fp: entry.parent_fp.clone(),
+        child_fp: entry.child_fp.clone(),
+        event,
+        retired_at: Utc::now(),
+        expires_at: entry.expires_at,
+        claim: relative_to_proofs(&moved[0])?,
+        receipt: moved.get(1).map(|p| relative_to_proofs(p)).transpose()?,
+        replaced_by,
+    };
+    storage::save_archived_proof(&record)?;
+
+    Ok(Some(record))
+}
+
+fn relative_to_proofs(path: &Path) -> Result<String> {
+    path.strip_prefix(utils::proofs_dir())
+        .map(|relative| relative.to_string_lossy().into_owned())
+        .map_err(|_| IgniteError::InvalidOperation {
+            operation: "index_proof".to_string(),
+            reason: format!("Proof {:?} is outside the proofs directory", path),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ignite::authority::chain::KeyType;
+    use crate::ignite::test_support::{issue_proof, new_key, VaultEnvironment};
+    use hub::time_ext::chrono::Duration;
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn test_activate_archives_previous_proof() {
+        let _env = VaultEnvironment::new();
+        let skull = new_key(KeyType::Skull);
+        let master = new_key(KeyType::Master);
+
+        let first = activate(
+            &issue_proof(&skull, &master, "test archive", Duration::hours(1)),
+            "first",
+            RetirementEvent::Superseded,
+        )
+        .unwrap();
+        assert!(first.retired.is_none());
+
+        let second = activate(
+            &issue_proof(&skull, &master, "test archive", Duration::hours(1)),
+            "second",
+            RetirementEvent::Renewed,
+        )
+        .unwrap();
+        let retired = second.retired.unwrap();
+        assert_eq!(retired.event, RetirementEvent::Renewed);
+        assert_eq!(
+            retired.replaced_by.as_deref(),
+            Some(relative_to_proofs(&second.claim_path).unwrap().as_str())
+        );
+        assert!(!first.claim_path.exists());
+        assert!(!first.receipt_path.exists());
+        assert!(utils::proofs_dir().join(&retired.claim).exists());
+        assert!(utils::proofs_dir()
+            .join(retired.receipt.as_ref().unwrap())
+            .exists());
+
+        // The index answers the lookup and survives a reload
+        let index = ProofIndex::load().unwrap();
+        let current = index
+            .current(skull.fingerprint(), master.fingerprint())
+            .unwrap();
+        assert_eq!(current.claim_path(), second.claim_path);
+        assert!(current.load_pair().unwrap().verify().is_ok());
+        assert_eq!(index.entries.len(), 1);
+        assert_eq!(
+            storage::list_proofs(skull.fingerprint()).unwrap(),
+            vec![second.claim_path]
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_retire_edges_records_event() {
+        let _env = VaultEnvironment::new();
+        let skull = new_key(KeyType::Skull);
+        let master = new_key(KeyType::Master);
+        let repo = new_key(KeyType::Repo);
+
+        activate(
+            &issue_proof(&skull, &master, "test archive", Duration::hours(1)),
+            "a",
+            RetirementEvent::Superseded,
+        )
+        .unwrap();
+        activate(
+            &issue_proof(&master, &repo, "test archive", Duration::hours(1)),
+            "b",
+            RetirementEvent::Superseded,
+        )
+        .unwrap();
+
+        let archived = retire_edges(
+            &[master.fingerprint().clone(), repo.fingerprint().clone()],
+            RetirementEvent::Revoked,
+        )
+        .unwrap();
+        assert_eq!(archived.len(), 2);
+        assert!(
+            archived
+                .iter()
+                .all(|record| record.event == RetirementEvent::Revoked
+                    && record.replaced_by.is_none())
+        );
+
+        let index = ProofIndex::load().unwrap();
+        assert!(index.entries.is_empty());
+        assert!(storage::list_all_proofs().unwrap().is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_purge_removes_archived_pairs() {
+        let _env = VaultEnvironment::new();
+        let skull = new_key(KeyType::Skull);
+        let master = new_key(KeyType::Master);
+
+        activate(
+            &issue_proof(&skull, &master, "test archive", Duration::hours(1)),
+            "a",
+            RetirementEvent::Superseded,
+        )
+        .unwrap();
+        let active = activate(
+            &issue_proof(&skull, &master, "test archive", Duration::hours(1)),
+            "b",
+            RetirementEvent::Renewed,
+        )
+        .unwrap();
+        let retired = active.retired.unwrap();
+
+        assert!(archived_before(retired.retired_at).unwrap().is_empty());
+        let expired = archived_before(Utc::now() + Duration::seconds(1)).unwrap();
+        assert_eq!(expired.len(), 1);
+
+        // Claim, receipt and retirement record go; the active pair stays
+        assert_eq!(purge(&expired).unwrap(), 3);
+        assert!(!utils::proofs_dir().join(&retired.claim).exists());
+        assert!(storage::list_archived_proofs().unwrap().is_empty());
+        assert!(active.claim_path.exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_index_rebuilds_for_legacy_vaults() {
+        let _env = VaultEnvironment::new();
+        let skull = new_key(KeyType::Skull);
+        let master = new_key(KeyType::Master);
+
+        let (claim_path, _) = storage::save_proof_pair(
+            &issue_proof(&skull, &master, "test archive", Duration::hours(1)),
+            skull.fingerprint(),
+            "legacy",
+        )
+        .unwrap();
+
+        let index = ProofIndex::load().unwrap();
+        let current = index
+            .current(skull.fingerprint(), master.fingerprint())
+            .unwrap();
+        assert_eq!(current.claim_path(), claim_path);
+        assert!(storage::load_proof_index().unwrap().is_none());
+    }
+}
@@ -3,24 +3,34 @@
 //! Implements Ed25519 signature-based proofs for authority claims and subject receipts
 //! per IGNITE_PROOFS.md specification.
 
-use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
 use hub::data_ext::serde::{Deserialize, Serialize};
+use hub::data_ext::serde_json;
 use hub::random_ext::rand::{rng, Rng};
 use hub::time_ext::chrono::{DateTime, Utc};
 use sha2::{Digest, Sha256};
 
+use super::canonical;
 use super::chain::KeyFingerprint;
+use crate::ignite::correlation;
 use crate::ignite::error::{IgniteError, Result};
 
+/// Lifetime of a freshly issued claim/receipt pair
+pub const PROOF_TTL_HOURS: i64 = 24;
+
 /// Authority claim payload (parent asserting control over child)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthorityClaim {
     pub schema_version: String,
     pub parent_fp: KeyFingerprint,
     pub child_fp: KeyFingerprint,
+    #[serde(with = "canonical::timestamp")]
     pub issued_at: DateTime<Utc>,
     pub purpose: String,
     pub nonce: String,
+    /// Correlation ID of the operation that issued the claim
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
 }
 
 impl AuthorityClaim {
@@ -36,6 +46,7 @@ impl AuthorityClaim {
             issued_at: Utc::now(),
             purpose: purpose.into(),
             nonce: Self::generate_nonce(),
+            operation_id: correlation::current(),
         }
     }
 
@@ -45,19 +56,9 @@ impl AuthorityClaim {
         random_bytes.iter().map(|b| format!("{:02x}", b)).collect()
     }
 
-    /// Serialize to canonical JSON for signing
+    /// Serialize to canonical JSON (RFC 8785) for signing
     pub fn to_canonical_json(&self) -> Result<String> {
-        // TODO: Implement proper canonical JSON with sorted keys
-        // For now, manually construct in sorted order per spec
-        Ok(format!(
-            r#"{{"child_fp":"{}","issued_at":"{}","nonce":"{}","parent_fp":"{}","purpose":"{}","schema_version":"{}"}}"#,
-            self.child_fp,
-            self.issued_at.to_rfc3339(),
-            self.nonce,
-            self.parent_fp,
-            self.purpose,
-            self.schema_version
-        ))
+        canonical::to_canonical_json(self)
     }
 
     /// Compute SHA256 digest of canonical payload
@@ -75,8 +76,12 @@ pub struct SubjectReceipt {
     pub schema_version: String,
     pub child_fp: KeyFingerprint,
     pub parent_fp: KeyFingerprint,
+    #[serde(with = "canonical::timestamp")]
     pub acknowledged_at: DateTime<Utc>,
     pub nonce: String,
+    /// Correlation ID of the operation that issued the receipt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
 }
 
 impl SubjectReceipt {
@@ -87,6 +92,7 @@ impl SubjectReceipt {
             parent_fp,
             acknowledged_at: Utc::now(),
             nonce: Self::generate_nonce(),
+            operation_id: correlation::current(),
         }
     }
 
@@ -96,16 +102,9 @@ impl SubjectReceipt {
         random_bytes.iter().map(|b| format!("{:02x}", b)).collect()
     }
 
-    /// Serialize to canonical JSON for signing
+    /// Serialize to canonical JSON (RFC 8785) for signing
     pub fn to_canonical_json(&self) -> Result<String> {
-        Ok(format!(
-            r#"{{"acknowledged_at":"{}","child_fp":"{}","nonce":"{}","parent_fp":"{}","schema_version":"{}"}}"#,
-            self.acknowledged_at.to_rfc3339(),
-            self.child_fp,
-            self.nonce,
-            self.parent_fp,
-            self.schema_version
-        ))
+        canonical::to_canonical_json(self)
     }
 
     /// Compute SHA256 digest of canonical payload
@@ -117,9 +116,74 @@ impl SubjectReceipt {
     }
 }
 
+/// Which direction of the authority relationship a bundle attests to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum ProofKind {
+    /// Parent asserting control over child (signed by parent)
+    #[default]
+    Claim,
+    /// Child acknowledging parent's authority (signed by child)
+    Receipt,
+}
+
+impl ProofKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProofKind::Claim => "claim",
+            ProofKind::Receipt => "receipt",
+        }
+    }
+
+    /// Domain-separation tag prefixed to the digest before signing
+    pub fn domain(&self) -> &'static str {
+        match self {
+            ProofKind::Claim => "ignite-claim-v2",
+            ProofKind::Receipt => "ignite-receipt-v2",
+        }
+    }
+
+    /// Bytes actually signed: `<domain>:<hex digest>:<expires_at>`
+    ///
+    /// The expiry is signed too, so editing it invalidates the bundle.
+    pub fn signing_message(&self, digest: &str, expires_at: &DateTime<Utc>) -> Vec<u8> {
+        format!(
+            "{}:{}:{}",
+            self.domain(),
+            digest,
+            canonical::timestamp::format(expires_at)
+        )
+        .into_bytes()
+    }
+}
+
+impl From<ProofKind> for String {
+    fn from(kind: ProofKind) -> String {
+        kind.as_str().to_string()
+    }
+}
+
+impl TryFrom<String> for ProofKind {
+    type Error = IgniteError;
+
+    fn try_from(s: String) -> Result<Self> {
+        match s.as_str() {
+            "claim" => Ok(ProofKind::Claim),
+            "receipt" => Ok(ProofKind::Receipt),
+            _ => Err(IgniteError::InvalidOperation {
+                operation: "parse_proof_kind".to_string(),
+                reason: format!("Unknown proof kind: {}", s),
+            }),
+        }
+    }
+}
+
 /// Complete proof bundle with signature
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProofBundle {
+    /// Bundles written before kinds existed are claims
+    #[serde(default)]
+    pub kind: ProofKind,
     pub payload_json: String,
     pub digest: String,
     pub signature: Vec<u8>,
@@ -134,19 +198,13 @@ impl ProofBundle {
         signing_key: &SigningKey,
         expires_at: DateTime<Utc>,
     ) -> Result<Self> {
-        let payload_json = claim.to_canonical_json()?;
-        let digest = claim.compute_digest()?;
-
-        let signature = signing_key.sign(digest.as_bytes());
-        let public_key = signing_key.verifying_key().to_bytes().to_vec();
-
-        Ok(Self {
-            payload_json,
-            digest,
-            signature: signature.to_bytes().to_vec(),
-            public_key,
+        Self::sign(
+            ProofKind::Claim,
+            claim.to_canonical_json()?,
+            claim.compute_digest()?,
+            signing_key,
             expires_at,
-        })
+        )
     }
 
     /// Sign a subject receipt with Ed25519 private key
@@ -155,13 +213,27 @@ impl ProofBundle {
         signing_key: &SigningKey,
         expires_at: DateTime<Utc>,
     ) -> Result<Self> {
-        let payload_json = receipt.to_canonical_json()?;
-        let digest = receipt.compute_digest()?;
+        Self::sign(
+            ProofKind::Receipt,
+            receipt.to_canonical_json()?,
+            receipt.compute_digest()?,
+            signing_key,
+            expires_at,
+        )
+    }
 
-        let signature = signing_key.sign(digest.as_bytes());
+    fn sign(
+        kind: ProofKind,
+        payload_json: String,
+        digest: String,
+        signing_key: &SigningKey,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Self> {
+        let signature = signing_key.sign(&kind.signing_message(&digest, &expires_at));
         let public_key = signing_key.verifying_key().to_bytes().to_vec();
 
         Ok(Self {
+            kind,
             payload_json,
             digest,
             signature: signature.to_bytes().to_vec(),
@@ -172,26 +244,47 @@ impl ProofBundle {
 
     /// Verify signature and expiration
     pub fn verify(&self) -> Result<()> {
-        if Utc::now() > self.expires_at {
+        if self.is_expired() {
             return Err(IgniteError::CryptoError {
                 operation: "verify_proof".to_string(),
                 reason: "Proof has expired".to_string(),
             });
         }
 
+        self.verify_signature()
+    }
+
+    pub fn is_expired(&self) -> bool {
+        Utc::now() > self.expires_at
+    }
+
+    /// Verify the Ed25519 signature over the domain-separated digest, ignoring expiration
+    ///
+    /// Uses strict verification and rejects weak (small-order) public keys.
+    pub fn verify_signature(&self) -> Result<()> {
         let public_key =
             VerifyingKey::from_bytes(self.public_key.as_slice().try_into().map_err(|_| {
                 IgniteError::crypto_error("parse_public_key", "Invalid key length")
             })?)
             .map_err(|e| IgniteError::crypto_error("parse_public_key", e.to_string()))?;
 
+        if public_key.is_weak() {
+            return Err(IgniteError::crypto_error(
+                "parse_public_key",
+                "Weak (small-order) public key rejected",
+            ));
+        }
+
         let signature =
             Signature::from_bytes(self.signature.as_slice().try_into().map_err(|_| {
                 IgniteError::crypto_error("parse_signature", "Invalid signature length")
             })?);
 
         public_key
-            .verify(self.digest.as_bytes(), &signature)
+            .verify_strict(
+                &self.kind.signing_message(&self.digest, &self.expires_at),
+                &signature,
+            )
             .map_err(|e| IgniteError::crypto_error("verify_signature", e.to_string()))?;
 
         Ok(())
@@ -213,18 +306,137 @@ impl ProofBundle {
         Ok(())
     }
 
-    /// Full verification: digest + signature + expiration
+    /// Fingerprint of the key that produced the signature
+    pub fn signer_fingerprint(&self) -> Result<KeyFingerprint> {
+        KeyFingerprint::from_key_material(&self.public_key)
+    }
+
+    /// Fingerprint the signer must have: `parent_fp` for claims, `child_fp` for receipts
+    pub fn expected_signer(&self) -> Result<KeyFingerprint> {
+        match self.kind {
+            ProofKind::Claim => Ok(self.claim()?.parent_fp),
+            ProofKind::Receipt => Ok(self.receipt()?.child_fp),
+        }
+    }
+
+    /// Verify the embedded public key belongs to the expected party
+    pub fn verify_signer(&self) -> Result<()> {
+        let signer = self.signer_fingerprint()?;
+        let expected = self.expected_signer()?;
+
+        if signer != expected {
+            return Err(IgniteError::CryptoError {
+                operation: "verify_signer".to_string(),
+                reason: format!(
+                    "{} signed by {} but payload names {}",
+                    self.kind.as_str(),
+                    signer,
+                    expected
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Full verification: digest + signer binding + signature + expiration
     pub fn verify_full(&self) -> Result<()> {
         self.verify_digest()?;
+        self.verify_signer()?;
         self.verify()?;
         Ok(())
     }
+
+    /// Parse the signed payload as an authority claim
+    pub fn claim(&self) -> Result<AuthorityClaim> {
+        self.expect_kind(ProofKind::Claim)?;
+        serde_json::from_str(&self.payload_json)
+            .map_err(|e| IgniteError::crypto_error("parse_claim", e.to_string()))
+    }
+
+    /// Parse the signed payload as a subject receipt
+    pub fn receipt(&self) -> Result<SubjectReceipt> {
+        self.expect_kind(ProofKind::Receipt)?;
+        serde_json::from_str(&self.payload_json)
+            .map_err(|e| IgniteError::crypto_error("parse_receipt", e.to_string()))
+    }
+
+    fn expect_kind(&self, kind: ProofKind) -> Result<()> {
+        if self.kind != kind {
+            return Err(IgniteError::crypto_error(
+                "parse_payload",
+                format!(
+                    "expected {} bundle, found {}",
+                    kind.as_str(),
+                    self.kind.as_str()
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Bidirectional proof: parent's authority claim plus child's subject receipt
+///
+/// Neither direction is sufficient on its own; verification requires both
+/// bundles to be valid, unexpired, and to name the same parent and child.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProofPair {
+    pub claim: ProofBundle,
+    pub receipt: ProofBundle,
+}
+
+impl ProofPair {
+    pub fn new(claim: ProofBundle, receipt: ProofBundle) -> Self {
+        Self { claim, receipt }
+    }
+
+    /// Sign a claim with the parent key and countersign a receipt with the child
+    ///
+    /// Both bundles share `expires_at` so the directions lapse together.
+    pub fn issue(
+        claim: &AuthorityClaim,
+        parent_signing_key: &SigningKey,
+        child_signing_key: &SigningKey,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Self> {
+        let mut receipt = SubjectReceipt::new(claim.child_fp.clone(), claim.parent_fp.clone());
+        receipt.operation_id = claim.operation_id.clone();
+
+        Ok(Self {
+            claim: ProofBundle::sign_claim(claim, parent_signing_key, expires_at)?,
+            receipt: ProofBundle::sign_receipt(&receipt, child_signing_key, expires_at)?,
+        })
+    }
+
+    /// Verify both directions and that they agree on the fingerprints
+    pub fn verify(&self) -> Result<(AuthorityClaim, SubjectReceipt)> {
+        self.claim.verify_full()?;
+        self.receipt.verify_full()?;
+
+        let claim = self.claim.claim()?;
+        let receipt = self.receipt.receipt()?;
+
+        if claim.parent_fp != receipt.parent_fp || claim.child_fp != receipt.child_fp {
+            return Err(IgniteError::CryptoError {
+                operation: "verify_proof_pair".to_string(),
+                reason: format!(
+                    "Claim {} -> {} does not match receipt {} -> {}",
+                    claim.parent_fp, claim.child_fp, receipt.parent_fp, receipt.child_fp
+                ),
+            });
+        }
+
+        Ok((claim, receipt))
+    }
+
+    /// Earliest expiry across both directions
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.claim.expires_at.min(self.receipt.expires_at)
+    }
 }
 
-// TODO: Implement proof storage/persistence to vault
-// TODO: Implement proof rotation scheduler (renew 12h before expires_at)
 // TODO: Integrate with authority chain for automatic proof generation during operations
-// TODO: Add archival logic for old proofs during rotation
 // TODO: Implement CLI command `ignite proof --verify <file>` handler
 
 #[cfg(test)]
@@ -244,6 +456,10 @@ mod tests {
         KeyFingerprint::from_string(&format!("SHA256:test{}", suffix)).unwrap()
     }
 
+    fn fingerprint_of(signing_key: &SigningKey) -> KeyFingerprint {
+        KeyFingerprint::from_key_material(&signing_key.verifying_key().to_bytes()).unwrap()
+    }
+
     #[test]
     fn test_authority_claim_creation() {
         let parent_fp = create_test_fingerprint("parent");
@@ -287,6 +503,24 @@ mod tests {
         assert!(json.contains("\"nonce\""));
     }
 
+    #[test]
+    fn test_authority_claim_canonical_json_escapes_fields() {
+        let claim = AuthorityClaim::new(
+            create_test_fingerprint("parent"),
+            create_test_fingerprint("child"),
+            r#"deploy "prod" \ staging"#,
+        );
+        let json = claim.to_canonical_json().unwrap();
+
+        assert!(json.contains(r#""purpose":"deploy \"prod\" \\ staging""#));
+        let parsed: AuthorityClaim = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.purpose, claim.purpose);
+        assert_eq!(
+            parsed.compute_digest().unwrap(),
+            claim.compute_digest().unwrap()
+        );
+    }
+
     #[test]
     fn test_subject_receipt_creation() {
         let parent_fp = create_test_fingerprint("parent");
@@ -304,7 +538,7 @@ mod tests {
     #[test]
     fn test_proof_bundle_sign_and_verify_claim() {
         let signing_key = create_test_signing_key();
-        let parent_fp = create_test_fingerprint("parent");
+        let parent_fp = fingerprint_of(&signing_key);
         let child_fp = create_test_fingerprint("child");
         let expires_at = Utc::now() + hub::time_ext::chrono::Duration::hours(1);
 
@@ -323,7 +557,7 @@ mod tests {
     fn test_proof_bundle_sign_and_verify_receipt() {
         let signing_key = create_test_signing_key();
         let parent_fp = create_test_fingerprint("parent");
-        let child_fp = create_test_fingerprint("child");
+        let child_fp = fingerprint_of(&signing_key);
         let expires_at = Utc::now() + hub::time_ext::chrono::Duration::hours(1);
 
         let receipt = SubjectReceipt::new(child_fp, parent_fp);
@@ -340,7 +574,7 @@ mod tests {
     #[test]
     fn test_proof_bundle_expiration() {
         let signing_key = create_test_signing_key();
-        let parent_fp = create_test_fingerprint("parent");
+        let parent_fp = fingerprint_of(&signing_key);
         let child_fp = create_test_fingerprint("child");
         let expires_at = Utc::now() - hub::time_ext::chrono::Duration::seconds(1); // Already expired
 
@@ -358,7 +592,7 @@ mod tests {
     #[test]
     fn test_proof_bundle_tampered_digest() {
         let signing_key = create_test_signing_key();
-        let parent_fp = create_test_fingerprint("parent");
+        let parent_fp = fingerprint_of(&signing_key);
         let child_fp = create_test_fingerprint("child");
         let expires_at = Utc::now() + hub::time_ext::chrono::Duration::hours(1);
 
@@ -386,6 +620,7 @@ mod tests {
             issued_at: claim1.issued_at,
             purpose: claim1.purpose.clone(),
             nonce: claim1.nonce.clone(),
+            operation_id: claim1.operation_id.clone(),
         };
 
         let digest1 = claim1.compute_digest().unwrap();
@@ -394,4 +629,195 @@ mod tests {
         // Same claim data should produce same digest
         assert_eq!(digest1, digest2);
     }
+
+    #[test]
+    fn test_proof_pair_issue_and_verify() {
+        let parent_key = create_test_signing_key();
+        let child_key = create_test_signing_key();
+        let parent_fp = fingerprint_of(&parent_key);
+        let child_fp = fingerprint_of(&child_key);
+        let expires_at = Utc::now() + hub::time_ext::chrono::Duration::hours(1);
+
+        let claim = AuthorityClaim::new(parent_fp.clone(), child_fp.clone(), "pair");
+        let pair = ProofPair::issue(&claim, &parent_key, &child_key, expires_at).unwrap();
+
+        let (verified_claim, verified_receipt) = pair.verify().unwrap();
+        assert_eq!(verified_claim.parent_fp, parent_fp);
+        assert_eq!(verified_receipt.child_fp, child_fp);
+        assert_eq!(pair.receipt.expires_at, pair.claim.expires_at);
+        assert_eq!(
+            pair.receipt.public_key,
+            child_key.verifying_key().to_bytes().to_vec()
+        );
+    }
+
+    #[test]
+    fn test_proof_pair_rejects_mismatched_fingerprints() {
+        let parent_key = create_test_signing_key();
+        let child_key = create_test_signing_key();
+        let expires_at = Utc::now() + hub::time_ext::chrono::Duration::hours(1);
+
+        let claim = AuthorityClaim::new(
+            fingerprint_of(&parent_key),
+            fingerprint_of(&child_key),
+            "pair",
+        );
+        // Receipt is validly signed by the child but acknowledges another parent
+        let receipt =
+            SubjectReceipt::new(fingerprint_of(&child_key), create_test_fingerprint("other"));
+
+        let pair = ProofPair::new(
+            ProofBundle::sign_claim(&claim, &parent_key, expires_at).unwrap(),
+            ProofBundle::sign_receipt(&receipt, &child_key, expires_at).unwrap(),
+        );
+        assert!(pair.verify().is_err());
+
+        // Two claims are not a pair either
+        let pair = ProofPair::new(pair.claim.clone(), pair.claim);
+        assert!(pair.verify().is_err());
+    }
+
+    #[test]
+    fn test_proof_pair_receipt_expiry() {
+        let parent_key = create_test_signing_key();
+        let child_key = create_test_signing_key();
+        let expires_at = Utc::now() + hub::time_ext::chrono::Duration::hours(1);
+
+        let claim = AuthorityClaim::new(
+            fingerprint_of(&parent_key),
+            fingerprint_of(&child_key),
+            "pair",
+        );
+        let mut pair = ProofPair::issue(&claim, &parent_key, &child_key, expires_at).unwrap();
+
+        let receipt = pair.receipt.receipt().unwrap();
+        pair.receipt = ProofBundle::sign_receipt(
+            &receipt,
+            &child_key,
+            Utc::now() - hub::time_ext::chrono::Duration::seconds(1),
+        )
+        .unwrap();
+
+        assert!(pair.verify().is_err());
+        assert!(pair.expires_at() < Utc::now());
+    }
+
+    #[test]
+    fn test_proof_bundle_serializes_kind() {
+        let signing_key = create_test_signing_key();
+        let claim = AuthorityClaim::new(
+            fingerprint_of(&signing_key),
+            create_test_fingerprint("child"),
+            "kind",
+        );
+        let expires_at = Utc::now() + hub::time_ext::chrono::Duration::hours(1);
+        let proof = ProofBundle::sign_claim(&claim, &signing_key, expires_at).unwrap();
+
+        let json = serde_json::to_string(&proof).unwrap();
+        assert!(json.contains(r#""kind":"claim""#));
+
+        let loaded: ProofBundle = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.kind, ProofKind::Claim);
+        assert!(loaded.verify_full().is_ok());
+    }
+
+    #[test]
+    fn test_proof_bundle_rejects_relabelled_kind() {
+        let child_key = create_test_signing_key();
+        let receipt = SubjectReceipt::new(fingerprint_of(&child_key), create_test_fingerprint("p"));
+        let expires_at = Utc::now() + hub::time_ext::chrono::Duration::hours(1);
+        let mut proof = ProofBundle::sign_receipt(&receipt, &child_key, expires_at).unwrap();
+
+        // A receipt signature must not verify once relabelled as a claim
+        proof.kind = ProofKind::Claim;
+        assert!(proof.verify_signature().is_err());
+        assert!(proof.claim().is_err());
+        assert!(proof.verify_full().is_err());
+    }
+
+    #[test]
+    fn test_proof_bundle_rejects_foreign_signer() {
+        let parent_key = create_test_signing_key();
+        let attacker_key = create_test_signing_key();
+        let claim = AuthorityClaim::new(
+            fingerprint_of(&parent_key),
+            create_test_fingerprint("child"),
+            "forged",
+        );
+        let expires_at = Utc::now() + hub::time_ext::chrono::Duration::hours(1);
+
+        // Signature is internally valid, but the signer is not the named parent
+        let proof = ProofBundle::sign_claim(&claim, &attacker_key, expires_at).unwrap();
+        assert!(proof.verify().is_ok());
+        assert!(proof.verify_signer().is_err());
+        assert!(proof.verify_full().is_err());
+        assert_eq!(
+            proof.signer_fingerprint().unwrap(),
+            fingerprint_of(&attacker_key)
+        );
+    }
+
+    #[test]
+    fn test_proof_bundle_rejects_weak_public_key() {
+        let signing_key = create_test_signing_key();
+        let claim = AuthorityClaim::new(
+            fingerprint_of(&signing_key),
+            create_test_fingerprint("child"),
+            "weak",
+        );
+        let expires_at = Utc::now() + hub::time_ext::chrono::Duration::hours(1);
+        let mut proof = ProofBundle::sign_claim(&claim, &signing_key, expires_at).unwrap();
+
+        // Compressed encoding of the identity point, which has small order
+        let mut identity = vec![0u8; 32];
+        identity[0] = 1;
+        proof.public_key = identity;
+        assert!(proof.verify_signature().is_err());
+    }
+
+    #[test]
+    fn test_proof_bundle_rejects_extended_expiry() {
+        let signing_key = create_test_signing_key();
+        let claim = AuthorityClaim::new(
+            fingerprint_of(&signing_key),
+            create_test_fingerprint("child"),
+            "expiry",
+        );
+        let expires_at = Utc::now() - hub::time_ext::chrono::Duration::hours(1);
+        let mut proof = ProofBundle::sign_claim(&claim, &signing_key, expires_at).unwrap();
+        assert!(proof.verify_signature().is_ok());
+
+        // Reviving an expired proof by editing its expiry breaks the signature
+        proof.expires_at = Utc::now() + hub::time_ext::chrono::Duration::hours(24);
+        assert!(!proof.is_expired());
+        assert!(proof.verify_signature().is_err());
+        assert!(proof.verify_full().is_err());
+    }
+
+    #[test]
+    fn test_legacy_proof_bundle_loads_as_claim() {
+        let signing_key = create_test_signing_key();
+        let claim = AuthorityClaim::new(
+            fingerprint_of(&signing_key),
+            create_test_fingerprint("child"),
+            "legacy",
+        );
+        let digest = claim.compute_digest().unwrap();
+
+        // Shape written before bundles carried a kind: raw digest signed, expiry unsigned
+        let legacy = serde_json::json!({
+            "payload_json": claim.to_canonical_json().unwrap(),
+            "digest": digest,
+            "signature": signing_key.sign(digest.as_bytes()).to_bytes().to_vec(),
+            "public_key": signing_key.verifying_key().to_bytes().to_vec(),
+            "expires_at": "2025-09-29T12:30:00Z",
+        });
+        let proof: ProofBundle = serde_json::from_value(legacy).unwrap();
+
+        assert_eq!(proof.kind, ProofKind::Claim);
+        assert_eq!(proof.claim().unwrap().nonce, claim.nonce);
+        assert!(proof.verify_digest().is_ok());
+        // Unbound expiry is not trusted; the edge must be renewed
+        assert!(proof.verify_signature().is_err());
+    }
 }
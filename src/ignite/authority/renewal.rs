@@ -0,0 +1,273 @@
+//! Proof renewal for live authority edges.
+//!
+//! Claims and receipts expire `PolicyEngine::proof_ttl` after issue (by default
+//! `PROOF_TTL_HOURS`). Renewal walks the proof index for edges whose active
+//! pair falls inside the renewal window, has the parent re-sign a claim with a
+//! fresh nonce, has the child countersign a new receipt, and archives the
+//! renewed pair per IGNITE_PROOFS.md §4.
+
+use std::path::PathBuf;
+
+use hub::time_ext::chrono::{DateTime, Duration, Utc};
+
+use super::chain::{AuthorityChain, AuthorityKey, KeyFingerprint};
+use super::ignition;
+use super::proof_archive::{self, ArchivedProof, ProofIndex, RetirementEvent};
+use super::proofs::{AuthorityClaim, ProofPair};
+use super::storage;
+use crate::ignite::correlation;
+use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::security::passphrase::PassphraseProvider;
+use crate::ignite::security::policy::PolicyEngine;
+
+/// Default for how close to `expires_at` a proof is renewed
+pub const RENEWAL_WINDOW_HOURS: i64 = 12;
+
+/// A live parent/child edge whose proofs are due for renewal
+#[derive(Debug, Clone)]
+pub struct RenewalCandidate {
+    pub parent: KeyFingerprint,
+    pub child: KeyFingerprint,
+    pub purpose: String,
+    /// Expiry of the active pair; `None` if it is missing, incomplete or invalid
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Active claim for this edge, archived on renewal
+    pub claim_path: PathBuf,
+}
+
+/// Artifacts produced by renewing one edge
+#[derive(Debug, Clone)]
+pub struct RenewedProof {
+    pub parent: KeyFingerprint,
+    pub child: KeyFingerprint,
+    pub proof: ProofPair,
+    pub proof_path: PathBuf,
+    pub archived: Option<ArchivedProof>,
+}
+
+/// Find live edges whose proofs expire within `window` of `now`
+///
+/// Only edges between two active keys where the child's recorded parent is
+/// the claim's parent are considered; proofs for retired keys are left alone.
+/// With `filter`, only edges where that key is parent or child are returned.
+pub fn due_for_renewal(
+    chain: &AuthorityChain,
+    filter: Option<&KeyFingerprint>,
+    now: DateTime<Utc>,
+    window: Duration,
+) -> Result<Vec<RenewalCandidate>> {
+    let window = now + window;
+    let mut due = Vec::new();
+
+    for entry in ProofIndex::load()?.entries {
+        if filter.is_some_and(|fp| fp != &entry.parent_fp && fp != &entry.child_fp) {
+            continue;
+        }
+        if live_edge(chain, &entry.parent_fp, &entry.child_fp).is_none() {
+            continue;
+        }
+
+        // A missing, incomplete or invalid pair does not keep the edge fresh
+        let expires_at = entry
+            .load_pair()
+            .ok()
+            .filter(|pair| pair.verify().is_ok())
+            .map(|pair| pair.expires_at());
+        if expires_at.is_some_and(|expiry| expiry > window) {
+            continue;
+        }
+
+        let purpose = storage::load_proof_from_path(&entry.claim_path())
+            .and_then(|bundle| bundle.claim())
+            .map(|claim| claim.purpose)
+            .unwrap_or_else(|_| "renew".to_string());
+        due.push(RenewalCandidate {
+            parent: entry.parent_fp.clone(),
+            child: entry.child_fp.clone(),
+            purpose,
+            expires_at,
+            claim_path: entry.claim_path(),
+        });
+    }
+
+    Ok(due)
+}
+
+/// Re-issue the proof pair for `candidate` and archive the superseded bundles
+///
+/// Passphrase-wrapped keys on either side of the edge are unlocked through
+/// `policy_engine`, so an expired key cannot renew its proofs.
+pub fn renew_proof(
+    chain: &AuthorityChain,
+    candidate: &RenewalCandidate,
+    policy_engine: &PolicyEngine,
+    passphrases: &PassphraseProvider,
+) -> Result<RenewedProof> {
+    let _operation = correlation::begin();
+    let claim = AuthorityClaim::new(
+        candidate.parent.clone(),
+        candidate.child.clone(),
+        candidate.purpose.clone(),
+    );
+    let (parent, child) =
+        live_edge(chain, &candidate.parent, &candidate.child).ok_or_else(|| {
+            IgniteError::InvalidOperation {
+                operation: "renew_proof".to_string(),
+                reason: format!(
+                    "{} -> {} is no longer a live authority edge",
+                    candidate.parent.short(),
+                    candidate.child.short()
+                ),
+            }
+        })?;
+
+    let pair = ProofPair::issue(
+        &claim,
+        &ignition::signing_key(parent, policy_engine, passphrases)?,
+        &ignition::signing_key(child, policy_engine, passphrases)?,
+        Utc::now() + policy_engine.proof_ttl(),
+    )?;
+    pair.verify()?;
+
+    let activated = proof_archive::activate(
+        &pair,
+        &proof_archive::proof_timestamp(&candidate.child),
+        RetirementEvent::Renewed,
+    )?;
+
+    Ok(RenewedProof {
+        parent: candidate.parent.clone(),
+        child: candidate.child.clone(),
+        proof: pair,
+        proof_path: activated.claim_path,
+        archived: activated.retired,
+    })
+}
+
+fn live_edge<'a>(
+    chain: &'a AuthorityChain,
+    parent: &KeyFingerprint,
+    child: &KeyFingerprint,
+) -> Option<(&'a AuthorityKey, &'a AuthorityKey)> {
+    let parent = chain.get_key(parent)?;
+    let child = chain.get_key(child)?;
+
+    let linked = child.parent() == Some(parent.fingerprint());
+    (linked && parent.is_active() && child.is_active()).then_some((parent, child))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ignite::authority::chain::{KeyStatus, KeyType};
+    use crate::ignite::authority::lineage;
+    use crate::ignite::test_support::{issue_proof, save_chain, VaultEnvironment};
+    use serial_test::serial;
+
+    fn window() -> Duration {
+        Duration::hours(RENEWAL_WINDOW_HOURS)
+    }
+
+    /// Persist skull → master → repo; master's proof is due, repo's is fresh
+    fn seed_renewals() -> Vec<AuthorityKey> {
+        let keys = save_chain(&[KeyType::Skull, KeyType::Master, KeyType::Repo]);
+        for (edge, ttl) in keys
+            .windows(2)
+            .zip([Duration::hours(2), Duration::hours(20)])
+        {
+            proof_archive::activate(
+                &issue_proof(&edge[0], &edge[1], "test renewal", ttl),
+                &edge[1].fingerprint().short(),
+                RetirementEvent::Superseded,
+            )
+            .unwrap();
+        }
+        keys
+    }
+
+    #[test]
+    #[serial]
+    fn test_due_for_renewal_respects_window() {
+        let _env = VaultEnvironment::new();
+        let keys = seed_renewals();
+        let chain = AuthorityChain::load_from_vault().unwrap();
+
+        let due = due_for_renewal(&chain, None, Utc::now(), window()).unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(&due[0].parent, keys[0].fingerprint());
+        assert_eq!(&due[0].child, keys[1].fingerprint());
+        assert_eq!(due[0].purpose, "test renewal");
+
+        // Both edges are due once the repo proof is inside the window too
+        let later = Utc::now() + Duration::hours(9);
+        assert_eq!(
+            due_for_renewal(&chain, None, later, window())
+                .unwrap()
+                .len(),
+            2
+        );
+
+        // Filtering by the repo key only sees the master -> repo edge
+        let filtered =
+            due_for_renewal(&chain, Some(keys[2].fingerprint()), later, window()).unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(&filtered[0].child, keys[2].fingerprint());
+    }
+
+    #[test]
+    #[serial]
+    fn test_renew_proof_reissues_and_archives() {
+        let _env = VaultEnvironment::new();
+        let keys = seed_renewals();
+        let chain = AuthorityChain::load_from_vault().unwrap();
+
+        let due = due_for_renewal(&chain, None, Utc::now(), window()).unwrap();
+        let renewed = renew_proof(
+            &chain,
+            &due[0],
+            &PolicyEngine::with_defaults(),
+            &PassphraseProvider::new(),
+        )
+        .unwrap();
+
+        let (claim, _) = renewed.proof.verify().unwrap();
+        assert_eq!(claim.purpose, "test renewal");
+        assert!(renewed.proof.expires_at() > Utc::now() + Duration::hours(23));
+        assert!(renewed.proof_path.exists());
+
+        // Renewed claim and receipt moved to the archive
+        let archived = renewed.archived.as_ref().unwrap();
+        assert_eq!(archived.event, RetirementEvent::Renewed);
+        assert!(crate::ignite::utils::proofs_dir()
+            .join(&archived.claim)
+            .exists());
+        assert!(!due[0].claim_path.exists());
+        assert_eq!(
+            storage::list_proofs(keys[0].fingerprint()).unwrap(),
+            vec![renewed.proof_path.clone()]
+        );
+
+        // Nothing left to renew, and the lineage still verifies
+        assert!(due_for_renewal(&chain, None, Utc::now(), window())
+            .unwrap()
+            .is_empty());
+        let report = lineage::verify_lineage(&chain, keys[2].fingerprint()).unwrap();
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    #[serial]
+    fn test_due_for_renewal_skips_retired_edges() {
+        let _env = VaultEnvironment::new();
+        let mut keys = seed_renewals();
+
+        keys[1].set_status(KeyStatus::Archived);
+        storage::save_key(&keys[1]).unwrap();
+
+        let chain = AuthorityChain::load_from_vault().unwrap();
+        let later = Utc::now() + Duration::hours(23);
+        assert!(due_for_renewal(&chain, None, later, window())
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,286 @@
+//! Key revocation backed by the tombstone registry.
+//!
+//! Revocation retires a key without minting a replacement: the key is marked
+//! revoked, every descendant is invalidated, proofs into the affected keys move
+//! to the proof archive, a tombstone is written so the fingerprint can never be
+//! reinstated, and a revocation manifest signed by the revoked key's parent
+//! (the key itself for a Skull) lists the affected keys per IGNITE_MANIFEST.md.
+
+use std::path::PathBuf;
+
+use hub::time_ext::chrono::Utc;
+
+use super::chain::{AuthorityChain, KeyFingerprint, KeyStatus};
+use super::ignition;
+use super::manifests::{AffectedKeyManifest, ManifestChild, ManifestEvent, ManifestEventType};
+use super::proof_archive::{self, ArchivedProof, RetirementEvent};
+use super::storage;
+use super::tombstones::{RevocationReason, Tombstone};
+use crate::ignite::correlation;
+use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::security::passphrase::PassphraseProvider;
+use crate::ignite::security::policy::PolicyEngine;
+
+/// Artifacts produced by a completed revocation
+#[derive(Debug, Clone)]
+pub struct RevocationOutcome {
+    pub tombstone: Tombstone,
+    pub tombstone_path: PathBuf,
+    pub invalidated: Vec<KeyFingerprint>,
+    /// Active proofs of the revoked key and its descendants, now archived
+    pub retired_proofs: Vec<ArchivedProof>,
+    pub manifest: AffectedKeyManifest,
+    pub manifest_path: PathBuf,
+}
+
+/// Revoke the key identified by `fingerprint` in the on-disk vault
+///
+/// The manifest signer is unlocked before anything is written. Key status
+/// updates are persisted before the tombstone is written, since storage
+/// refuses to save a tombstoned fingerprint.
+pub fn revoke_key(
+    fingerprint: &KeyFingerprint,
+    reason: RevocationReason,
+    revoked_by: &str,
+    policy_engine: &PolicyEngine,
+    passphrases: &PassphraseProvider,
+) -> Result<RevocationOutcome> {
+    let _operation = correlation::begin();
+    let chain = AuthorityChain::load_from_vault()?;
+
+    if let Some(tombstone) = chain.tombstone(fingerprint) {
+        return Err(tombstone.to_error());
+    }
+
+    let mut revoked =
+        chain
+            .get_key(fingerprint)
+            .cloned()
+            .ok_or_else(|| IgniteError::InvalidKey {
+                reason: format!("Key not found with fingerprint: {}", fingerprint),
+            })?;
+
+    let signer = match revoked.parent() {
+        Some(parent_fp) => {
+            let parent = chain
+                .get_key(parent_fp)
+                .ok_or_else(|| IgniteError::OrphanKey {
+                    fingerprint: fingerprint.to_string(),
+                    reason: format!("parent {} not found in vault", parent_fp.short()),
+                })?;
+
+            if !parent.is_active() {
+                return Err(IgniteError::InvalidOperation {
+                    operation: "revoke".to_string(),
+                    reason: format!(
+                        "Parent {} is {} and cannot sign a revocation",
+                        parent_fp.short(),
+                        parent.status()
+                    ),
+                });
+            }
+            policy_engine.validate_key(parent)?;
+            parent
+        }
+        None => &revoked,
+    };
+    let signer_fingerprint = signer.fingerprint().clone();
+    let signing_key = ignition::signing_key(signer, policy_engine, passphrases)?;
+
+    let revoked_at = Utc::now();
+    let mut manifest = AffectedKeyManifest::new(ManifestEvent::new(
+        ManifestEventType::Revocation,
+        signer_fingerprint,
+        format!("{}: {} revoked", reason, fingerprint.short()),
+    ));
+
+    revoked.set_status(KeyStatus::Revoked);
+    revoked.save()?;
+    manifest.add_child(
+        ManifestChild::new(
+            fingerprint.clone(),
+            revoked.key_type(),
+            KeyStatus::Revoked.as_str(),
+            revoked.metadata().creation_time,
+        )
+        .with_revocation(revoked_at),
+    );
+
+    let mut invalidated = Vec::new();
+    for mut dependent in chain.find_dependent_keys(fingerprint)? {
+        if dependent.is_active() {
+            dependent.set_status(KeyStatus::Invalid);
+            dependent.save()?;
+        }
+
+        manifest.add_child(
+            ManifestChild::new(
+                dependent.fingerprint().clone(),
+                dependent.key_type(),
+                dependent.status().as_str(),
+                dependent.metadata().creation_time,
+            )
+            .with_revocation(revoked_at),
+        );
+        invalidated.push(dependent.fingerprint().clone());
+    }
+
+    let mut retired_children = vec![fingerprint.clone()];
+    retired_children.extend(invalidated.iter().cloned());
+    let retired_proofs = proof_archive::retire_edges(&retired_children, RetirementEvent::Revoked)?;
+
+    let tombstone = Tombstone::new(fingerprint.clone(), revoked.key_type(), reason, revoked_by);
+    let tombstone_path = storage::save_tombstone(&tombstone)?;
+
+    manifest.sign(&signing_key)?;
+    let manifest_path = storage::save_manifest(&manifest)?;
+
+    Ok(RevocationOutcome {
+        tombstone,
+        tombstone_path,
+        invalidated,
+        retired_proofs,
+        manifest,
+        manifest_path,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ignite::test_support::{seed_vault, VaultEnvironment};
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn test_revoke_key_writes_tombstone_and_manifest() {
+        let _env = VaultEnvironment::new();
+        let (engine, passphrases) = (PolicyEngine::with_defaults(), PassphraseProvider::new());
+        let keys = seed_vault();
+        let (master, repo, ignition) = (&keys[1], &keys[2], &keys[3]);
+
+        let outcome = revoke_key(
+            repo.fingerprint(),
+            RevocationReason::KeyCompromise,
+            "alice",
+            &engine,
+            &passphrases,
+        )
+        .unwrap();
+
+        assert!(outcome.tombstone_path.exists());
+        assert_eq!(outcome.tombstone.revoked_by, "alice");
+        assert_eq!(outcome.tombstone.reason, RevocationReason::KeyCompromise);
+        assert_eq!(outcome.invalidated, vec![ignition.fingerprint().clone()]);
+
+        assert_eq!(
+            outcome.manifest.event.event_type,
+            ManifestEventType::Revocation
+        );
+        assert_eq!(
+            &outcome.manifest.event.parent_fingerprint,
+            master.fingerprint()
+        );
+        assert_eq!(outcome.manifest.children.len(), 2);
+        assert!(outcome.manifest_path.exists());
+        assert!(outcome.manifest.verify_digest().is_ok());
+
+        let chain = AuthorityChain::load_from_vault().unwrap();
+        assert!(outcome.manifest.verify_full(&chain).is_ok());
+        assert!(chain.is_tombstoned(repo.fingerprint()));
+        assert_eq!(
+            chain.get_key(repo.fingerprint()).unwrap().status(),
+            KeyStatus::Revoked
+        );
+        assert_eq!(
+            chain.get_key(ignition.fingerprint()).unwrap().status(),
+            KeyStatus::Invalid
+        );
+        assert_eq!(
+            chain.get_key(master.fingerprint()).unwrap().status(),
+            KeyStatus::Active
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_revoked_key_cannot_be_reinstated() {
+        let _env = VaultEnvironment::new();
+        let (engine, passphrases) = (PolicyEngine::with_defaults(), PassphraseProvider::new());
+        let keys = seed_vault();
+        let repo = &keys[2];
+
+        revoke_key(
+            repo.fingerprint(),
+            RevocationReason::Superseded,
+            "alice",
+            &engine,
+            &passphrases,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            storage::save_key(repo),
+            Err(IgniteError::Tombstoned { .. })
+        ));
+        assert!(matches!(
+            revoke_key(
+                repo.fingerprint(),
+                RevocationReason::Superseded,
+                "alice",
+                &engine,
+                &passphrases
+            ),
+            Err(IgniteError::Tombstoned { .. })
+        ));
+
+        let mut chain = AuthorityChain::new();
+        for tombstone in storage::load_tombstones().unwrap() {
+            chain.add_tombstone(tombstone);
+        }
+        assert!(matches!(
+            chain.add_key(repo.clone()),
+            Err(IgniteError::Tombstoned { .. })
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn test_inactive_or_expired_parent_cannot_sign_revocation() {
+        let _env = VaultEnvironment::new();
+        let (engine, passphrases) = (PolicyEngine::with_defaults(), PassphraseProvider::new());
+        let keys = seed_vault();
+        let (master, repo) = (&keys[1], &keys[2]);
+
+        let mut archived = master.clone();
+        archived.set_status(KeyStatus::Archived);
+        storage::save_key(&archived).unwrap();
+        let result = revoke_key(
+            repo.fingerprint(),
+            RevocationReason::KeyCompromise,
+            "alice",
+            &engine,
+            &passphrases,
+        );
+        assert!(matches!(result, Err(IgniteError::InvalidOperation { .. })));
+
+        let mut expired = master.clone();
+        expired
+            .metadata_mut()
+            .set_expiration(Some(Utc::now() - hub::time_ext::chrono::Duration::hours(1)));
+        storage::save_key(&expired).unwrap();
+        let result = revoke_key(
+            repo.fingerprint(),
+            RevocationReason::KeyCompromise,
+            "alice",
+            &engine,
+            &passphrases,
+        );
+        assert!(matches!(result, Err(IgniteError::PolicyViolation { .. })));
+
+        // Nothing was written by either refusal
+        let chain = AuthorityChain::load_from_vault().unwrap();
+        assert!(!chain.is_tombstoned(repo.fingerprint()));
+        assert!(chain.get_key(repo.fingerprint()).unwrap().is_active());
+    }
+}
@@ -0,0 +1,403 @@
+//! Key rotation with cascading invalidation.
+//!
+//! Rotation mints replacement key material for the same tier, has the parent
+//! sign a fresh `rotate-<role>` authority claim, archives the outgoing key and
+//! invalidates every descendant. Proofs into the affected keys move to the
+//! proof archive, and the keys are recorded in a rotation manifest per
+//! IGNITE_MANIFEST.md.
+
+use std::path::PathBuf;
+
+use hub::time_ext::chrono::Utc;
+
+use super::chain::{
+    AuthorityChain, AuthorityKey, KeyFingerprint, KeyMaterial, KeyMetadata, KeyStatus,
+};
+use super::ignition;
+use super::manifests::{AffectedKeyManifest, ManifestChild, ManifestEvent, ManifestEventType};
+use super::proof_archive::{self, ArchivedProof, RetirementEvent};
+use super::proofs::{AuthorityClaim, ProofPair};
+use super::storage;
+use crate::ignite::correlation;
+use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::security::passphrase::{PassphraseProvider, PassphraseRequest};
+use crate::ignite::security::policy::PolicyEngine;
+
+/// Artifacts produced by a completed rotation
+#[derive(Debug, Clone)]
+pub struct RotationOutcome {
+    pub retired: KeyFingerprint,
+    pub replacement: AuthorityKey,
+    pub invalidated: Vec<KeyFingerprint>,
+    pub proof: ProofPair,
+    pub proof_path: PathBuf,
+    /// Active proofs of the retired key and its descendants, now archived
+    pub retired_proofs: Vec<ArchivedProof>,
+    pub manifest: AffectedKeyManifest,
+    pub manifest_path: PathBuf,
+}
+
+/// Purpose string recorded on rotation claims (e.g. `rotate-repo`)
+pub fn rotation_purpose(key: &AuthorityKey) -> String {
+    format!("rotate-{}", key.key_type())
+}
+
+/// Unsaved successor to `retired` in the same tier, lineage and parent slot
+///
+/// Policy defaults are not applied yet; `rotate_key` applies them, and
+/// `PolicyEngine::explain_key` can preview them.
+pub fn replacement_for(
+    retired: &AuthorityKey,
+    parent: Option<&AuthorityKey>,
+) -> Result<AuthorityKey> {
+    let metadata = KeyMetadata {
+        creation_time: Utc::now(),
+        creator: whoami::username(),
+        description: retired.metadata().description.clone(),
+        lineage_id: Some(retired.lineage_id()),
+        ..Default::default()
+    };
+    let mut replacement = AuthorityKey::new(
+        KeyMaterial::generate_ed25519(),
+        retired.key_type(),
+        None,
+        Some(metadata),
+    )?;
+    if let Some(parent) = parent {
+        replacement.set_parent(parent.fingerprint().clone())?;
+    }
+    Ok(replacement)
+}
+
+/// Rotate the key identified by `fingerprint` in the on-disk vault
+///
+/// The parent signs the claim over the replacement; a Skull key has no parent
+/// and signs its own successor. The replacement is persisted before the old key
+/// is archived so an interrupted rotation never leaves the tier without an
+/// active key.
+pub fn rotate_key(
+    fingerprint: &KeyFingerprint,
+    policy_engine: &PolicyEngine,
+    passphrases: &PassphraseProvider,
+) -> Result<RotationOutcome> {
+    let _operation = correlation::begin();
+    let chain = AuthorityChain::load_from_vault()?;
+
+    let mut retired =
+        chain
+            .get_key(fingerprint)
+            .cloned()
+            .ok_or_else(|| IgniteError::InvalidKey {
+                reason: format!("Key not found with fingerprint: {}", fingerprint),
+            })?;
+
+    if !retired.is_active() {
+        return Err(IgniteError::InvalidOperation {
+            operation: "rotate".to_string(),
+            reason: format!(
+                "Key {} is {} and cannot be rotated",
+                fingerprint.short(),
+                retired.status()
+            ),
+        });
+    }
+
+    let mut parent = match retired.parent() {
+        Some(parent_fp) => {
+            let parent =
+                chain
+                    .get_key(parent_fp)
+                    .cloned()
+                    .ok_or_else(|| IgniteError::OrphanKey {
+                        fingerprint: fingerprint.to_string(),
+                        reason: format!("parent {} not found in vault", parent_fp.short()),
+                    })?;
+
+            if !parent.is_active() {
+                return Err(IgniteError::InvalidOperation {
+                    operation: "rotate".to_string(),
+                    reason: format!(
+                        "Parent {} is {} and cannot sign a rotation",
+                        parent_fp.short(),
+                        parent.status()
+                    ),
+                });
+            }
+            policy_engine.validate_key(&parent)?;
+            Some(parent)
+        }
+        None => None,
+    };
+
+    let signer = parent.as_ref().unwrap_or(&retired);
+    let signer_fingerprint = signer.fingerprint().clone();
+    let signing_key = ignition::signing_key(signer, policy_engine, passphrases)?;
+
+    // Wrapped keys are replaced by wrapped keys; fetch the passphrase before any write
+    let passphrase = if retired.is_passphrase_wrapped() {
+        Some(passphrases.obtain(&PassphraseRequest::new_key(retired.key_type(), None))?)
+    } else {
+        None
+    };
+
+    let mut replacement = replacement_for(&retired, parent.as_ref())?;
+    policy_engine.apply_key_defaults(&mut replacement)?;
+    policy_engine.validate_key(&replacement)?;
+    let replacement_signing_key = replacement.key_material().signing_key()?;
+    if let Some(passphrase) = &passphrase {
+        ignition::wrap(&mut replacement, passphrase.expose(), policy_engine)?;
+    }
+    replacement.save()?;
+
+    let claim = AuthorityClaim::new(
+        signer_fingerprint.clone(),
+        replacement.fingerprint().clone(),
+        rotation_purpose(&retired),
+    );
+    let pair = ProofPair::issue(
+        &claim,
+        &signing_key,
+        &replacement_signing_key,
+        Utc::now() + policy_engine.proof_ttl(),
+    )?;
+    let proof_path = proof_archive::activate(
+        &pair,
+        &proof_archive::proof_timestamp(replacement.fingerprint()),
+        RetirementEvent::Superseded,
+    )?
+    .claim_path;
+
+    if let Some(parent) = parent.as_mut() {
+        parent.add_child(replacement.fingerprint().clone())?;
+        parent.save()?;
+    }
+
+    // Archive the outgoing key, then cascade invalidation
+    let rotated_at = Utc::now();
+    let mut manifest = AffectedKeyManifest::new(ManifestEvent::new(
+        ManifestEventType::Rotation,
+        signer_fingerprint,
+        format!(
+            "{}: {} replaced by {}",
+            rotation_purpose(&retired),
+            fingerprint.short(),
+            replacement.fingerprint().short()
+        ),
+    ));
+
+    retired.set_status(KeyStatus::Archived);
+    retired.save()?;
+    manifest.add_child(
+        ManifestChild::new(
+            fingerprint.clone(),
+            retired.key_type(),
+            KeyStatus::Archived.as_str(),
+            retired.metadata().creation_time,
+        )
+        .with_revocation(rotated_at),
+    );
+
+    let mut invalidated = Vec::new();
+    for mut dependent in chain.find_dependent_keys(fingerprint)? {
+        if dependent.is_active() {
+            dependent.set_status(KeyStatus::Invalid);
+            dependent.save()?;
+        }
+
+        manifest.add_child(
+            ManifestChild::new(
+                dependent.fingerprint().clone(),
+                dependent.key_type(),
+                dependent.status().as_str(),
+                dependent.metadata().creation_time,
+            )
+            .with_revocation(rotated_at),
+        );
+        invalidated.push(dependent.fingerprint().clone());
+    }
+
+    let mut retired_children = vec![fingerprint.clone()];
+    retired_children.extend(invalidated.iter().cloned());
+    let retired_proofs = proof_archive::retire_edges(&retired_children, RetirementEvent::Rotated)?;
+
+    manifest.sign(&signing_key)?;
+    let manifest_path = storage::save_manifest(&manifest)?;
+
+    Ok(RotationOutcome {
+        retired: fingerprint.clone(),
+        replacement,
+        invalidated,
+        proof: pair,
+        proof_path,
+        retired_proofs,
+        manifest,
+        manifest_path,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ignite::authority::chain::KeyType;
+    use crate::ignite::security::passphrase;
+    use crate::ignite::test_support::{activate_proofs, new_key, seed_vault, VaultEnvironment};
+    use hub::time_ext::chrono::Duration;
+    use serial_test::serial;
+    use std::env;
+
+    #[test]
+    #[serial]
+    fn test_rotate_key_archives_and_invalidates_descendants() {
+        let _env = VaultEnvironment::new();
+        let keys = seed_vault();
+        let (master, repo, ignition) = (&keys[1], &keys[2], &keys[3]);
+        activate_proofs(&keys, "test rotation", Duration::hours(1));
+
+        let outcome = rotate_key(
+            master.fingerprint(),
+            &PolicyEngine::with_defaults(),
+            &PassphraseProvider::new(),
+        )
+        .unwrap();
+
+        assert_eq!(outcome.replacement.key_type(), KeyType::Master);
+        assert_eq!(outcome.replacement.parent(), Some(keys[0].fingerprint()));
+        assert_eq!(outcome.invalidated.len(), 2);
+
+        let chain = AuthorityChain::load_from_vault().unwrap();
+        let status = |fp: &KeyFingerprint| chain.get_key(fp).unwrap().status();
+        assert_eq!(status(master.fingerprint()), KeyStatus::Archived);
+        assert_eq!(status(repo.fingerprint()), KeyStatus::Invalid);
+        assert_eq!(status(ignition.fingerprint()), KeyStatus::Invalid);
+        assert_eq!(status(outcome.replacement.fingerprint()), KeyStatus::Active);
+        assert!(chain.has_authority(keys[0].fingerprint(), outcome.replacement.fingerprint()));
+        assert!(chain.validate_integrity().is_ok());
+
+        // Parent signs the rotation claim and the replacement countersigns
+        let (claim, _) = outcome.proof.verify().unwrap();
+        assert_eq!(claim.purpose, "rotate-master");
+        assert_eq!(&claim.child_fp, outcome.replacement.fingerprint());
+        assert_eq!(
+            outcome.proof.claim.public_key,
+            keys[0].key_material().public_key()
+        );
+        assert_eq!(
+            outcome.proof.receipt.public_key,
+            outcome.replacement.key_material().public_key()
+        );
+        assert!(outcome.proof_path.exists());
+        assert!(storage::counterpart_proof_path(&outcome.proof_path)
+            .unwrap()
+            .exists());
+
+        // Proofs into the master and its descendants are archived; the
+        // replacement's proof is the active one for its edge
+        assert_eq!(outcome.retired_proofs.len(), 3);
+        assert!(outcome
+            .retired_proofs
+            .iter()
+            .all(|record| record.event == RetirementEvent::Rotated));
+        let index = proof_archive::ProofIndex::load().unwrap();
+        assert_eq!(index.entries.len(), 1);
+        assert_eq!(
+            index
+                .current(keys[0].fingerprint(), outcome.replacement.fingerprint())
+                .unwrap()
+                .claim_path(),
+            outcome.proof_path
+        );
+
+        // Manifest lists the archived key and every invalidated descendant
+        assert!(outcome.manifest_path.exists());
+        assert_eq!(
+            outcome.manifest.event.event_type,
+            ManifestEventType::Rotation
+        );
+        assert_eq!(
+            &outcome.manifest.event.parent_fingerprint,
+            keys[0].fingerprint()
+        );
+        assert_eq!(outcome.manifest.children.len(), 3);
+        assert!(outcome.manifest.verify_digest().is_ok());
+    }
+
+    #[test]
+    #[serial]
+    fn test_rotate_skull_is_self_signed() {
+        let _env = VaultEnvironment::new();
+        let keys = seed_vault();
+        let skull = &keys[0];
+
+        let outcome = rotate_key(
+            skull.fingerprint(),
+            &PolicyEngine::with_defaults(),
+            &PassphraseProvider::new(),
+        )
+        .unwrap();
+
+        assert!(outcome.replacement.parent().is_none());
+        assert_eq!(outcome.invalidated.len(), 3);
+        assert_eq!(
+            &outcome.manifest.event.parent_fingerprint,
+            skull.fingerprint()
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_rotate_wrapped_skull_stays_wrapped() {
+        let _env = VaultEnvironment::new();
+        let engine = PolicyEngine::with_defaults();
+        let passphrase = "Correct-Horse-42-Battery";
+
+        let mut skull = new_key(KeyType::Skull);
+        ignition::wrap(&mut skull, passphrase, &engine).unwrap();
+        storage::save_key(&skull).unwrap();
+
+        // Without a passphrase the wrapped signer cannot be unlocked
+        assert!(rotate_key(skull.fingerprint(), &engine, &PassphraseProvider::new()).is_err());
+        assert_eq!(storage::list_keys(KeyType::Skull).unwrap().len(), 1);
+
+        // The replacement may not reuse the retired key's passphrase
+        env::set_var(passphrase::PASSPHRASE_ENV, passphrase);
+        let reused = rotate_key(skull.fingerprint(), &engine, &PassphraseProvider::new());
+        assert!(reused.is_err());
+        assert_eq!(storage::list_keys(KeyType::Skull).unwrap().len(), 1);
+
+        let next = "Staple-Battery-17-Horse";
+        let unlock_var = passphrase::key_env_var(skull.fingerprint());
+        env::set_var(&unlock_var, passphrase);
+        env::set_var(passphrase::PASSPHRASE_ENV, next);
+        let outcome = rotate_key(skull.fingerprint(), &engine, &PassphraseProvider::new());
+        env::remove_var(&unlock_var);
+        env::remove_var(passphrase::PASSPHRASE_ENV);
+        let outcome = outcome.unwrap();
+
+        assert!(outcome.proof.verify().is_ok());
+        let mut replacement =
+            storage::load_key(KeyType::Skull, outcome.replacement.fingerprint()).unwrap();
+        assert!(replacement.is_passphrase_wrapped());
+        assert!(replacement.unlock(next, &engine).is_ok());
+
+        // History follows the lineage across rotation
+        assert_eq!(replacement.lineage_id(), skull.fingerprint().hex());
+        let history = storage::load_passphrase_history(&replacement.lineage_id()).unwrap();
+        assert_eq!(history.entries.len(), 2);
+        assert!(ignition::change_passphrase(&mut replacement, next, passphrase, &engine).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_rotate_rejects_inactive_keys() {
+        let _env = VaultEnvironment::new();
+        let keys = seed_vault();
+        let engine = PolicyEngine::with_defaults();
+        let passphrases = PassphraseProvider::new();
+
+        rotate_key(keys[1].fingerprint(), &engine, &passphrases).unwrap();
+
+        // Archived master and invalidated repo can no longer be rotated
+        assert!(rotate_key(keys[1].fingerprint(), &engine, &passphrases).is_err());
+        assert!(rotate_key(keys[2].fingerprint(), &engine, &passphrases).is_err());
+    }
+}
@@ -0,0 +1,217 @@
+//! Passphrase sealing for private key material at rest.
+//!
+//! Argon2id stretches the vault passphrase with a per-record salt into a
+//! 256-bit key, and XChaCha20-Poly1305 encrypts the private key under it. The
+//! key's public half is bound in as associated data, so a sealed record cannot
+//! be transplanted onto another key. Records carry an explicit `version`;
+//! key files without one hold legacy plaintext material.
+//!
+//! The vault passphrase comes from a `PassphraseProvider` (`seal`/`unseal`
+//! requests). Each thread uses the one installed with `use_vault_passphrases`,
+//! or `PassphraseProvider::from_env` until one is.
+
+use std::cell::RefCell;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use hub::data_ext::serde::{Deserialize, Serialize};
+use hub::random_ext::rand::{rng, Rng};
+use zeroize::Zeroizing;
+
+use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::security::passphrase::{Passphrase, PassphraseProvider, PassphraseRequest};
+
+pub use crate::ignite::security::passphrase::VAULT_PASSPHRASE_ENV;
+
+/// Current sealed record version
+pub const SEALED_KEY_VERSION: u32 = 1;
+
+const KDF_ALGORITHM: &str = "argon2id";
+const CIPHER: &str = "xchacha20poly1305";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+thread_local! {
+    static VAULT_PASSPHRASES: RefCell<Option<PassphraseProvider>> = const { RefCell::new(None) };
+}
+
+/// Argon2id parameters recorded alongside each sealed record
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub algorithm: String,
+    /// Memory cost in KiB
+    pub m_cost: u32,
+    pub t_cost: u32,
+    pub p_cost: u32,
+    pub salt: String,
+}
+
+/// Passphrase-wrapped secret as persisted in the vault
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SealedSecret {
+    pub version: u32,
+    pub kdf: KdfParams,
+    pub cipher: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+impl SealedSecret {
+    /// Encrypt `secret` under `passphrase`, authenticating `associated_data`
+    pub fn seal(secret: &[u8], associated_data: &[u8], passphrase: &str) -> Result<Self> {
+        let params = kdf_params();
+        let salt: [u8; SALT_LEN] = rng().random();
+        let nonce: [u8; NONCE_LEN] = rng().random();
+        let kdf = KdfParams {
+            algorithm: KDF_ALGORITHM.to_string(),
+            m_cost: params.m_cost(),
+            t_cost: params.t_cost(),
+            p_cost: params.p_cost(),
+            salt: encode_hex(&salt),
+        };
+
+        let cipher = XChaCha20Poly1305::new(derive_key(&kdf, passphrase)?.as_ref().into());
+        let ciphertext = cipher
+            .encrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: secret,
+                    aad: associated_data,
+                },
+            )
+            .map_err(|_| IgniteError::crypto_error("seal_secret", "encryption failed"))?;
+
+        Ok(Self {
+            version: SEALED_KEY_VERSION,
+            kdf,
+            cipher: CIPHER.to_string(),
+            nonce: encode_hex(&nonce),
+            ciphertext: encode_hex(&ciphertext),
+        })
+    }
+
+    /// Decrypt the secret; fails on a wrong passphrase or tampered record
+    pub fn open(&self, associated_data: &[u8], passphrase: &str) -> Result<Zeroizing<Vec<u8>>> {
+        if self.version != SEALED_KEY_VERSION {
+            return Err(IgniteError::InvalidKey {
+                reason: format!("unsupported sealed key version {}", self.version),
+            });
+        }
+        if self.cipher != CIPHER {
+            return Err(IgniteError::InvalidKey {
+                reason: format!("unsupported sealed key cipher '{}'", self.cipher),
+            });
+        }
+
+        let nonce = decode_hex(&self.nonce)?;
+        if nonce.len() != NONCE_LEN {
+            return Err(IgniteError::InvalidKey {
+                reason: "sealed key nonce has wrong length".to_string(),
+            });
+        }
+        let ciphertext = decode_hex(&self.ciphertext)?;
+
+        let cipher = XChaCha20Poly1305::new(derive_key(&self.kdf, passphrase)?.as_ref().into());
+        cipher
+            .decrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: &ciphertext,
+                    aad: associated_data,
+                },
+            )
+            .map(Zeroizing::new)
+            .map_err(|_| IgniteError::InvalidKey {
+                reason:
+                    "sealed private key could not be opened (wrong passphrase or tampered record)"
+                        .to_string(),
+            })
+    }
+}
+
+/// Source this thread's vault passphrases from `provider`
+pub fn use_vault_passphrases(provider: PassphraseProvider) {
+    VAULT_PASSPHRASES.with(|current| *current.borrow_mut() = Some(provider));
+}
+
+/// Vault passphrase for a `seal` or `unseal` request
+pub fn vault_passphrase(request: &PassphraseRequest<'_>) -> Result<Passphrase> {
+    VAULT_PASSPHRASES
+        .with(|current| current.borrow().clone())
+        .unwrap_or_else(PassphraseProvider::from_env)
+        .obtain(request)
+}
+
+/// Argon2id cost for new records; tests use the minimum so they stay fast
+fn kdf_params() -> Params {
+    if cfg!(test) {
+        Params::new(Params::MIN_M_COST, 1, 1, None).unwrap_or_default()
+    } else {
+        Params::default()
+    }
+}
+
+fn derive_key(kdf: &KdfParams, passphrase: &str) -> Result<Zeroizing<[u8; 32]>> {
+    if kdf.algorithm != KDF_ALGORITHM {
+        return Err(IgniteError::InvalidKey {
+            reason: format!("unsupported sealed key KDF '{}'", kdf.algorithm),
+        });
+    }
+
+    let params = Params::new(kdf.m_cost, kdf.t_cost, kdf.p_cost, Some(32))
+        .map_err(|e| IgniteError::crypto_error("derive_key", e.to_string()))?;
+    let salt = decode_hex(&kdf.salt)?;
+
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), &salt, key.as_mut())
+        .map_err(|e| IgniteError::crypto_error("derive_key", e.to_string()))?;
+    Ok(key)
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+    let invalid = || IgniteError::InvalidKey {
+        reason: "sealed key record contains invalid hex".to_string(),
+    };
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return Err(invalid());
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_open_round_trip() {
+        let sealed = SealedSecret::seal(b"secret", b"public", "hunter2").unwrap();
+        assert_eq!(sealed.version, SEALED_KEY_VERSION);
+        assert_eq!(sealed.kdf.algorithm, "argon2id");
+        assert!(!sealed.ciphertext.contains(&encode_hex(b"secret")));
+
+        let opened = sealed.open(b"public", "hunter2").unwrap();
+        assert_eq!(opened.as_slice(), b"secret");
+    }
+
+    #[test]
+    fn test_open_rejects_wrong_passphrase_and_context() {
+        let sealed = SealedSecret::seal(b"secret", b"public", "hunter2").unwrap();
+
+        assert!(sealed.open(b"public", "hunter3").is_err());
+        assert!(sealed.open(b"other", "hunter2").is_err());
+
+        let mut future = sealed.clone();
+        future.version = SEALED_KEY_VERSION + 1;
+        assert!(future.open(b"public", "hunter2").is_err());
+    }
+}
@@ -0,0 +1,403 @@
+//! Skull recovery shares.
+//!
+//! Implements the "offline passphrase fragments reconstituted" recovery
+//! pattern: the Skull private key is split with Shamir secret sharing
+//! (`security::shamir`) into shares that are each printed or stored on their
+//! own. A share is a self-describing text block:
+//!
+//! ```text
+//! -----BEGIN IGNITE SKULL SHARE-----
+//! Version: 1
+//! Fingerprint: SHA256:9ff0bae5...
+//! Set: 3f9a0c1d2e4b5a69
+//! Share: 2 of 5
+//! Threshold: 3
+//! Created: 2026-10-17T02:21:08Z
+//!
+//! 5be1 07c3 ...
+//! Checksum: 1a2b3c4d5e6f7081
+//! -----END IGNITE SKULL SHARE-----
+//! ```
+//!
+//! The checksum (truncated SHA-256 over the headers and data) catches
+//! transcription errors per share. `combine` rebuilds the key in memory only
+//! and refuses any result whose public half does not hash to the recorded
+//! Skull fingerprint.
+
+use ed25519_dalek::SigningKey;
+use hub::random_ext::rand::{rng, Rng};
+use hub::time_ext::chrono::{DateTime, SubsecRound, Utc};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use super::canonical;
+use super::chain::{AuthorityKey, KeyFingerprint, KeyFormat, KeyMaterial, KeyType};
+use super::ignition::IgnitionKey;
+use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::security::policy::PolicyEngine;
+use crate::ignite::security::shamir::{self, Share};
+
+/// Current share block format
+pub const SHARE_FORMAT_VERSION: u32 = 1;
+
+const BEGIN_MARKER: &str = "-----BEGIN IGNITE SKULL SHARE-----";
+const END_MARKER: &str = "-----END IGNITE SKULL SHARE-----";
+const CHECKSUM_LEN: usize = 16;
+
+/// One share of a split Skull key
+#[derive(Debug, Clone)]
+pub struct SkullShare {
+    pub fingerprint: KeyFingerprint,
+    /// Random id shared by every share of one split, so splits cannot be mixed
+    pub set_id: String,
+    pub index: u8,
+    pub shares: u8,
+    pub threshold: u8,
+    pub created_at: DateTime<Utc>,
+    data: Zeroizing<Vec<u8>>,
+}
+
+impl SkullShare {
+    fn header_lines(&self) -> Vec<String> {
+        vec![
+            format!("Version: {}", SHARE_FORMAT_VERSION),
+            format!("Fingerprint: {}", self.fingerprint),
+            format!("Set: {}", self.set_id),
+            format!("Share: {} of {}", self.index, self.shares),
+            format!("Threshold: {}", self.threshold),
+            format!(
+                "Created: {}",
+                canonical::timestamp::format(&self.created_at)
+            ),
+        ]
+    }
+
+    fn checksum(&self) -> String {
+        let mut hasher = Sha256::new();
+        for line in self.header_lines() {
+            hasher.update(line.as_bytes());
+            hasher.update(b"\n");
+        }
+        hasher.update(encode_hex(&self.data).as_bytes());
+        format!("{:x}", hasher.finalize())[..CHECKSUM_LEN].to_string()
+    }
+
+    /// Printable block, safe to store or print on its own
+    pub fn to_text(&self) -> Zeroizing<String> {
+        let mut text = Zeroizing::new(String::new());
+        text.push_str(BEGIN_MARKER);
+        text.push('\n');
+        for line in self.header_lines() {
+            text.push_str(&line);
+            text.push('\n');
+        }
+        text.push('\n');
+        let hex = Zeroizing::new(encode_hex(&self.data));
+        let groups: Vec<&str> = (0..hex.len())
+            .step_by(4)
+            .map(|i| &hex[i..(i + 4).min(hex.len())])
+            .collect();
+        for line in groups.chunks(8) {
+            text.push_str(&line.join(" "));
+            text.push('\n');
+        }
+        text.push_str(&format!("Checksum: {}\n", self.checksum()));
+        text.push_str(END_MARKER);
+        text.push('\n');
+        text
+    }
+
+    /// Every share block in `text`, in order of appearance
+    pub fn parse_all(text: &str) -> Result<Vec<Self>> {
+        let mut shares = Vec::new();
+        let mut block: Option<Vec<&str>> = None;
+        for line in text.lines().map(str::trim) {
+            match (line, block.as_mut()) {
+                (BEGIN_MARKER, _) => block = Some(Vec::new()),
+                (END_MARKER, Some(lines)) => {
+                    shares.push(Self::parse_block(lines)?);
+                    block = None;
+                }
+                (_, Some(lines)) => lines.push(line),
+                (_, None) => {}
+            }
+        }
+        if block.is_some() {
+            return Err(share_error("share block is missing its END line"));
+        }
+        Ok(shares)
+    }
+
+    fn parse_block(lines: &[&str]) -> Result<Self> {
+        let mut fields = std::collections::HashMap::new();
+        let mut hex = Zeroizing::new(String::new());
+        for line in lines.iter().filter(|line| !line.is_empty()) {
+            match line.split_once(": ") {
+                Some((name, value)) => {
+                    fields.insert(name, value.trim());
+                }
+                None => hex.extend(line.chars().filter(|c| !c.is_whitespace())),
+            }
+        }
+        let field = |name: &str| {
+            fields
+                .get(name)
+                .copied()
+                .ok_or_else(|| share_error(&format!("share is missing its {} line", name)))
+        };
+        let number = |name: &str, value: &str| {
+            value
+                .parse::<u8>()
+                .map_err(|_| share_error(&format!("invalid {} value: {}", name, value)))
+        };
+
+        let version = field("Version")?;
+        if version != SHARE_FORMAT_VERSION.to_string() {
+            return Err(share_error(&format!(
+                "unsupported share version {}",
+                version
+            )));
+        }
+        let (index, shares) = field("Share")?
+            .split_once(" of ")
+            .ok_or_else(|| share_error("Share line must read '<n> of <total>'"))?;
+        let created_at = DateTime::parse_from_rfc3339(field("Created")?)
+            .map_err(|e| share_error(&format!("invalid Created value: {}", e)))?
+            .with_timezone(&Utc);
+
+        let share = Self {
+            fingerprint: KeyFingerprint::from_string(field("Fingerprint")?)?,
+            set_id: field("Set")?.to_string(),
+            index: number("Share", index)?,
+            shares: number("Share", shares)?,
+            threshold: number("Threshold", field("Threshold")?)?,
+            created_at,
+            data: Zeroizing::new(
+                decode_hex(&hex).ok_or_else(|| share_error("share data is not valid hex"))?,
+            ),
+        };
+        if share.checksum() != field("Checksum")? {
+            return Err(share_error(&format!(
+                "checksum mismatch on share {} of {}; check for transcription errors",
+                share.index, share.shares
+            )));
+        }
+        Ok(share)
+    }
+}
+
+/// Skull signing key rebuilt from shares, held in memory only
+pub struct RecoveredSkull {
+    pub fingerprint: KeyFingerprint,
+    signing_key: SigningKey,
+}
+
+impl RecoveredSkull {
+    pub fn signing_key(&self) -> &SigningKey {
+        &self.signing_key
+    }
+
+    fn key_material(&self) -> KeyMaterial {
+        KeyMaterial::new(
+            self.signing_key.verifying_key().to_bytes().to_vec(),
+            Some(Zeroizing::new(self.signing_key.to_bytes().to_vec())),
+            KeyFormat::Ed25519,
+        )
+    }
+}
+
+/// Split `key`'s private half (`signing_key`) into `shares` shares, `threshold` to recover
+pub fn split(
+    key: &AuthorityKey,
+    signing_key: &SigningKey,
+    shares: u8,
+    threshold: u8,
+) -> Result<Vec<SkullShare>> {
+    if key.key_type() != KeyType::Skull {
+        return Err(IgniteError::InvalidOperation {
+            operation: "split_skull".to_string(),
+            reason: format!(
+                "Only Skull keys are split into shares, not {} keys",
+                key.key_type()
+            ),
+        });
+    }
+    if signing_key.verifying_key().as_bytes() != key.key_material().public_key() {
+        return Err(IgniteError::InvalidKey {
+            reason: "Signing key does not belong to this Skull key".to_string(),
+        });
+    }
+
+    let set_bytes: [u8; 8] = rng().random();
+    let set_id = encode_hex(&set_bytes);
+    let created_at = Utc::now().trunc_subsecs(0);
+    Ok(shamir::split(&signing_key.to_bytes(), shares, threshold)?
+        .into_iter()
+        .map(|share| SkullShare {
+            fingerprint: key.fingerprint().clone(),
+            set_id: set_id.clone(),
+            index: share.index,
+            shares,
+            threshold,
+            created_at,
+            data: share.data,
+        })
+        .collect())
+}
+
+/// Rebuild the Skull key from `shares` and check it against the recorded fingerprint
+pub fn combine(shares: &[SkullShare]) -> Result<RecoveredSkull> {
+    let first = shares
+        .first()
+        .ok_or_else(|| share_error("no shares supplied"))?;
+    if shares
+        .iter()
+        .any(|share| share.fingerprint != first.fingerprint || share.set_id != first.set_id)
+    {
+        return Err(share_error(
+            "shares come from different splits or Skull keys",
+        ));
+    }
+    if shares.len() < first.threshold as usize {
+        return Err(share_error(&format!(
+            "{} share(s) supplied but {} are required",
+            shares.len(),
+            first.threshold
+        )));
+    }
+
+    let parts: Vec<Share> = shares
+        .iter()
+        .map(|share| Share {
+            index: share.index,
+            data: share.data.clone(),
+        })
+        .collect();
+    let secret = shamir::combine(&parts)?;
+    let secret: &[u8; 32] = secret
+        .as_slice()
+        .try_into()
+        .map_err(|_| share_error("shares do not hold an Ed25519 key"))?;
+
+    let signing_key = SigningKey::from_bytes(secret);
+    let fingerprint = KeyFingerprint::from_key_material(signing_key.verifying_key().as_bytes())?;
+    if fingerprint != first.fingerprint {
+        return Err(IgniteError::InvalidKey {
+            reason: format!(
+                "shares rebuild a key with fingerprint {}, not the recorded Skull {}",
+                fingerprint.short(),
+                first.fingerprint.short()
+            ),
+        });
+    }
+
+    Ok(RecoveredSkull {
+        fingerprint,
+        signing_key,
+    })
+}
+
+/// Re-wrap `key` under `new_passphrase` using recovered material (lost passphrase)
+pub fn rewrap(
+    key: &mut AuthorityKey,
+    recovered: &RecoveredSkull,
+    new_passphrase: &str,
+    policy_engine: &PolicyEngine,
+) -> Result<()> {
+    if key.fingerprint() != &recovered.fingerprint {
+        return Err(IgniteError::InvalidKey {
+            reason: format!(
+                "Recovered key {} does not match {}",
+                recovered.fingerprint.short(),
+                key.fingerprint().short()
+            ),
+        });
+    }
+    policy_engine.validate_key(key)?;
+
+    let ignition = IgnitionKey::create(
+        key,
+        &recovered.key_material(),
+        new_passphrase,
+        policy_engine,
+    )?;
+    key.set_ignition(ignition);
+    Ok(())
+}
+
+fn share_error(reason: &str) -> IgniteError {
+    IgniteError::InvalidOperation {
+        operation: "skull_share".to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ignite::authority::ignition;
+
+    const PASSPHRASE: &str = "Correct-Horse-42-Battery";
+
+    #[test]
+    fn test_split_print_parse_combine() {
+        let key =
+            AuthorityKey::new(KeyMaterial::generate_ed25519(), KeyType::Skull, None, None).unwrap();
+        let signing_key = key.key_material().signing_key().unwrap();
+
+        let shares = split(&key, &signing_key, 5, 3).unwrap();
+        let printed: String = shares
+            .iter()
+            .map(|share| share.to_text().to_string())
+            .collect();
+        let parsed = SkullShare::parse_all(&printed).unwrap();
+        assert_eq!(parsed.len(), 5);
+
+        let recovered = combine(&parsed[2..]).unwrap();
+        assert_eq!(&recovered.fingerprint, key.fingerprint());
+        assert_eq!(recovered.signing_key().to_bytes(), signing_key.to_bytes());
+        assert!(combine(&parsed[..2]).is_err());
+
+        // A transcription error is caught by the checksum
+        let corrupted = shares[0].to_text().replacen("Share: 1", "Share: 4", 1);
+        assert!(SkullShare::parse_all(&corrupted).is_err());
+
+        // Shares of two splits never combine
+        let other = split(&key, &signing_key, 5, 3).unwrap();
+        assert!(combine(&[parsed[0].clone(), parsed[1].clone(), other[2].clone()]).is_err());
+    }
+
+    #[test]
+    fn test_rewrap_after_lost_passphrase() {
+        let policy_engine = PolicyEngine::with_defaults();
+        let mut key =
+            AuthorityKey::new(KeyMaterial::generate_ed25519(), KeyType::Skull, None, None).unwrap();
+        let signing_key = key.key_material().signing_key().unwrap();
+        let shares = split(&key, &signing_key, 3, 2).unwrap();
+        ignition::wrap(&mut key, PASSPHRASE, &policy_engine).unwrap();
+
+        let recovered = combine(&shares[1..]).unwrap();
+        let next = "Staple-Battery-17-Horse";
+        rewrap(&mut key, &recovered, next, &policy_engine).unwrap();
+        assert!(ignition::unlock(&key, PASSPHRASE, &policy_engine).is_err());
+        assert!(ignition::unlock(&key, next, &policy_engine).is_ok());
+
+        let other = AuthorityKey::new(KeyMaterial::generate_ed25519(), KeyType::Master, None, None)
+            .unwrap();
+        assert!(split(&other, &signing_key, 3, 2).is_err());
+    }
+}
@@ -7,10 +7,17 @@ use hub::data_ext::serde_json;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use super::approvals::{self, Approval, ApprovalConsumption, ApproverRegistry, OperationRequest};
 use super::chain::{AuthorityKey, KeyFingerprint, KeyType};
+use super::ignition::PassphraseHistory;
 use super::manifests::AffectedKeyManifest;
-use super::proofs::ProofBundle;
+use super::proof_archive::{ArchivedProof, ProofIndex};
+use super::proofs::{ProofBundle, ProofPair};
+use super::sealing;
+use super::tombstones::Tombstone;
 use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::logging;
+use crate::ignite::security::passphrase::PassphraseRequest;
 use crate::ignite::utils;
 
 /// Initialize vault directories
@@ -26,6 +33,11 @@ pub fn key_path(key_type: KeyType, fingerprint: &KeyFingerprint) -> PathBuf {
         .join(format!("{}.json", fingerprint.short()))
 }
 
+const RECEIPT_SUFFIX: &str = ".receipt.json";
+
+/// Suffix of the retirement record kept beside an archived claim
+const RETIRED_SUFFIX: &str = ".retired.json";
+
 /// Generate path for proof storage
 pub fn proof_path(fingerprint: &KeyFingerprint, timestamp: &str) -> PathBuf {
     utils::proofs_dir()
@@ -33,11 +45,35 @@ pub fn proof_path(fingerprint: &KeyFingerprint, timestamp: &str) -> PathBuf {
         .join(format!("{}.json", timestamp))
 }
 
+/// Generate path for the subject receipt stored next to its claim
+pub fn receipt_path(fingerprint: &KeyFingerprint, timestamp: &str) -> PathBuf {
+    utils::proofs_dir()
+        .join(fingerprint.short())
+        .join(format!("{}{}", timestamp, RECEIPT_SUFFIX))
+}
+
+/// Map a claim proof path to its receipt path, or a receipt path to its claim
+pub fn counterpart_proof_path(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?;
+    let counterpart = match name.strip_suffix(RECEIPT_SUFFIX) {
+        Some(timestamp) => format!("{}.json", timestamp),
+        None => format!("{}{}", name.strip_suffix(".json")?, RECEIPT_SUFFIX),
+    };
+    Some(path.with_file_name(counterpart))
+}
+
 /// Generate path for manifest storage (using manifest's own filename logic)
 pub fn manifest_path(manifest: &AffectedKeyManifest) -> PathBuf {
     utils::manifests_dir().join(manifest.filename())
 }
 
+/// Generate path for a tombstone record
+///
+/// Named by the full fingerprint: short prefixes can be ground to collide.
+pub fn tombstone_path(fingerprint: &KeyFingerprint) -> PathBuf {
+    utils::tombstones_dir().join(format!("{}.json", fingerprint.hex()))
+}
+
 /// Atomic write helper - writes to temp file then renames
 fn atomic_write(path: &Path, data: &[u8]) -> Result<()> {
     let parent = path.parent().ok_or_else(|| IgniteError::InvalidOperation {
@@ -61,31 +97,129 @@ fn atomic_write(path: &Path, data: &[u8]) -> Result<()> {
     Ok(())
 }
 
+/// Write-once variant of `atomic_write`: fails if `path` already exists
+fn atomic_write_new(path: &Path, data: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| IgniteError::io_error("create_parent_dir", parent.to_path_buf(), e))?;
+    }
+
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, data)
+        .map_err(|e| IgniteError::io_error("write_temp", temp_path.clone(), e))?;
+
+    // Linking never replaces an existing file, unlike rename
+    let linked = fs::hard_link(&temp_path, path)
+        .map_err(|e| IgniteError::io_error("link_new", path.to_path_buf(), e));
+    let _ = fs::remove_file(&temp_path);
+    linked
+}
+
 /// Persist authority key to vault
+///
+/// Non-Skull keys must carry a parent link to a key of the controlling tier
+/// that is already in the vault; orphans are rejected with `OrphanKey`.
+/// Tombstoned fingerprints are rejected with `Tombstoned`.
+///
+/// Plaintext private material is sealed under the vault passphrase (see
+/// `sealing::vault_passphrase`) before it reaches disk; without one the key is
+/// refused rather than written in the clear. Legacy plaintext records still
+/// load, and are sealed the next time they are saved.
 pub fn save_key(key: &AuthorityKey) -> Result<PathBuf> {
+    let result = write_key(key);
+    let audited = logging::record_result("save_key", &[key.fingerprint()], &result);
+    let path = result?;
+    audited.map(|()| path)
+}
+
+fn write_key(key: &AuthorityKey) -> Result<PathBuf> {
     init_vault()?;
+    ensure_not_tombstoned(key.fingerprint())?;
+    ensure_parent_persisted(key)?;
+
+    let mut record = key.clone();
+    if record.key_material().private_key().is_some() {
+        let passphrase = sealing::vault_passphrase(&PassphraseRequest::seal(key.fingerprint()))
+            .map_err(|e| IgniteError::InvalidOperation {
+                operation: "save_key".to_string(),
+                reason: format!(
+                    "refusing to write unsealed private key {}: {}",
+                    key.fingerprint().short(),
+                    e
+                ),
+            })?;
+        record.seal_private_key(passphrase.expose())?;
+    }
 
     let path = key_path(key.key_type(), key.fingerprint());
-    let json = serde_json::to_string_pretty(key)
+    let json = serde_json::to_string_pretty(&record)
         .map_err(|e| IgniteError::crypto_error("serialize_key", e.to_string()))?;
 
     atomic_write(&path, json.as_bytes())?;
+    if key.is_passphrase_wrapped() {
+        record_passphrase(key)?;
+    }
     Ok(path)
 }
 
+/// Add a wrapped key's passphrase hash to its lineage history
+fn record_passphrase(key: &AuthorityKey) -> Result<()> {
+    let mut history = load_passphrase_history(&key.lineage_id())?;
+    if history.record(key) {
+        save_passphrase_history(&history)?;
+    }
+    Ok(())
+}
+
+fn ensure_parent_persisted(key: &AuthorityKey) -> Result<()> {
+    let Some(parent_type) = key.key_type().parent_type() else {
+        return Ok(());
+    };
+
+    let parent = key.parent().ok_or_else(|| IgniteError::OrphanKey {
+        fingerprint: key.fingerprint().to_string(),
+        reason: format!("{} key has no parent link", key.key_type()),
+    })?;
+
+    if !key_path(parent_type, parent).exists() {
+        return Err(IgniteError::OrphanKey {
+            fingerprint: key.fingerprint().to_string(),
+            reason: format!(
+                "parent {} {} not found in vault",
+                parent_type,
+                parent.short()
+            ),
+        });
+    }
+
+    Ok(())
+}
+
 /// Load authority key from vault
 pub fn load_key(key_type: KeyType, fingerprint: &KeyFingerprint) -> Result<AuthorityKey> {
-    let path = key_path(key_type, fingerprint);
-    let json = fs::read_to_string(&path)
-        .map_err(|e| IgniteError::io_error("read_key", path.clone(), e))?;
+    load_key_from_path(&key_path(key_type, fingerprint))
+}
+
+/// Load authority key from an explicit key file path
+pub fn load_key_from_path(path: &Path) -> Result<AuthorityKey> {
+    let json = fs::read_to_string(path)
+        .map_err(|e| IgniteError::io_error("read_key", path.to_path_buf(), e))?;
 
     let mut key: AuthorityKey = serde_json::from_str(&json)
         .map_err(|e| IgniteError::crypto_error("deserialize_key", e.to_string()))?;
 
-    key.set_key_path(path);
+    key.set_key_path(path.to_path_buf());
     Ok(key)
 }
 
+/// Load every persisted key of a given type
+pub fn load_keys(key_type: KeyType) -> Result<Vec<AuthorityKey>> {
+    list_keys(key_type)?
+        .iter()
+        .map(|path| load_key_from_path(path))
+        .collect()
+}
+
 /// Persist proof bundle to vault
 pub fn save_proof(
     proof: &ProofBundle,
@@ -104,16 +238,206 @@ pub fn save_proof(
 
 /// Load proof bundle from vault
 pub fn load_proof(fingerprint: &KeyFingerprint, timestamp: &str) -> Result<ProofBundle> {
-    let path = proof_path(fingerprint, timestamp);
-    let json = fs::read_to_string(&path)
-        .map_err(|e| IgniteError::io_error("read_proof", path.clone(), e))?;
+    load_proof_from_path(&proof_path(fingerprint, timestamp))
+}
+
+/// Load proof bundle from an explicit proof file path
+pub fn load_proof_from_path(path: &Path) -> Result<ProofBundle> {
+    let json = fs::read_to_string(path)
+        .map_err(|e| IgniteError::io_error("read_proof", path.to_path_buf(), e))?;
 
     serde_json::from_str(&json)
         .map_err(|e| IgniteError::crypto_error("deserialize_proof", e.to_string()))
 }
 
+/// Persist both directions of a proof pair, receipt next to claim
+///
+/// Returns `(claim_path, receipt_path)`.
+pub fn save_proof_pair(
+    pair: &ProofPair,
+    fingerprint: &KeyFingerprint,
+    timestamp: &str,
+) -> Result<(PathBuf, PathBuf)> {
+    let result = write_proof_pair(pair, fingerprint, timestamp);
+    let audited = match pair.claim.claim() {
+        Ok(claim) => {
+            logging::record_result("save_proof", &[&claim.parent_fp, &claim.child_fp], &result)
+        }
+        Err(_) => logging::record_result("save_proof", &[fingerprint], &result),
+    };
+    let paths = result?;
+    audited.map(|()| paths)
+}
+
+fn write_proof_pair(
+    pair: &ProofPair,
+    fingerprint: &KeyFingerprint,
+    timestamp: &str,
+) -> Result<(PathBuf, PathBuf)> {
+    let claim_path = save_proof(&pair.claim, fingerprint, timestamp)?;
+
+    let path = receipt_path(fingerprint, timestamp);
+    let json = serde_json::to_string_pretty(&pair.receipt)
+        .map_err(|e| IgniteError::crypto_error("serialize_receipt", e.to_string()))?;
+    atomic_write(&path, json.as_bytes())?;
+
+    Ok((claim_path, path))
+}
+
+/// Load a proof pair; fails if either direction is missing
+pub fn load_proof_pair(fingerprint: &KeyFingerprint, timestamp: &str) -> Result<ProofPair> {
+    load_proof_pair_from_path(&proof_path(fingerprint, timestamp))
+}
+
+/// Load a proof pair from the claim's path; the receipt is read from beside it
+pub fn load_proof_pair_from_path(claim_path: &Path) -> Result<ProofPair> {
+    let claim = load_proof_from_path(claim_path)?;
+
+    let path = counterpart_proof_path(claim_path).ok_or_else(|| IgniteError::InvalidOperation {
+        operation: "load_proof_pair".to_string(),
+        reason: format!("Not a claim proof path: {:?}", claim_path),
+    })?;
+    let json = fs::read_to_string(&path)
+        .map_err(|e| IgniteError::io_error("read_receipt", path.clone(), e))?;
+    let receipt = serde_json::from_str(&json)
+        .map_err(|e| IgniteError::crypto_error("deserialize_receipt", e.to_string()))?;
+
+    Ok(ProofPair::new(claim, receipt))
+}
+
+/// Persist the active-proof index
+pub fn save_proof_index(index: &ProofIndex) -> Result<PathBuf> {
+    init_vault()?;
+
+    let path = utils::proof_index_path();
+    let json = serde_json::to_string_pretty(index)
+        .map_err(|e| IgniteError::crypto_error("serialize_proof_index", e.to_string()))?;
+
+    atomic_write(&path, json.as_bytes())?;
+    Ok(path)
+}
+
+/// Load the active-proof index; `None` if the vault has never written one
+pub fn load_proof_index() -> Result<Option<ProofIndex>> {
+    let path = utils::proof_index_path();
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let json = fs::read_to_string(&path)
+        .map_err(|e| IgniteError::io_error("read_proof_index", path.clone(), e))?;
+    serde_json::from_str(&json)
+        .map(Some)
+        .map_err(|e| IgniteError::crypto_error("deserialize_proof_index", e.to_string()))
+}
+
+/// Write the retirement record beside an archived claim
+pub fn save_archived_proof(record: &ArchivedProof) -> Result<PathBuf> {
+    let claim_path = utils::proofs_dir().join(&record.claim);
+    let name = claim_path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .and_then(|name| name.strip_suffix(".json"))
+        .ok_or_else(|| IgniteError::InvalidOperation {
+            operation: "save_archived_proof".to_string(),
+            reason: format!("Not an archived claim path: {:?}", claim_path),
+        })?;
+
+    let path = claim_path.with_file_name(format!("{}{}", name, RETIRED_SUFFIX));
+    let json = serde_json::to_string_pretty(record)
+        .map_err(|e| IgniteError::crypto_error("serialize_archived_proof", e.to_string()))?;
+
+    atomic_write(&path, json.as_bytes())?;
+    Ok(path)
+}
+
+/// Retirement records of every archived proof pair, oldest first
+pub fn list_archived_proofs() -> Result<Vec<ArchivedProof>> {
+    let archive = utils::proof_archive_dir();
+    if !archive.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut records = Vec::new();
+    let dirs = fs::read_dir(&archive)
+        .map_err(|e| IgniteError::io_error("list_archived_proofs", archive.clone(), e))?;
+    for dir in dirs {
+        let dir = dir
+            .map_err(|e| IgniteError::io_error("list_archived_proofs", archive.clone(), e))?
+            .path();
+        if !dir.is_dir() {
+            continue;
+        }
+
+        let entries = fs::read_dir(&dir)
+            .map_err(|e| IgniteError::io_error("list_archived_proofs", dir.clone(), e))?;
+        for entry in entries {
+            let path = entry
+                .map_err(|e| IgniteError::io_error("list_archived_proofs", dir.clone(), e))?
+                .path();
+            let is_record = path
+                .file_name()
+                .and_then(|s| s.to_str())
+                .is_some_and(|name| name.ends_with(RETIRED_SUFFIX));
+            if !is_record {
+                continue;
+            }
+
+            let json = fs::read_to_string(&path)
+                .map_err(|e| IgniteError::io_error("load_archived_proof", path.clone(), e))?;
+            records.push(serde_json::from_str::<ArchivedProof>(&json).map_err(|e| {
+                IgniteError::crypto_error("deserialize_archived_proof", e.to_string())
+            })?);
+        }
+    }
+
+    records.sort_by_key(|record| record.retired_at);
+    Ok(records)
+}
+
+/// Delete an archived claim, its receipt and its retirement record
+///
+/// Returns the removed paths.
+pub fn remove_archived_proof(record: &ArchivedProof) -> Result<Vec<PathBuf>> {
+    let proofs = utils::proofs_dir();
+    let claim_path = proofs.join(&record.claim);
+    let record_path = claim_path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .and_then(|name| name.strip_suffix(".json"))
+        .map(|name| claim_path.with_file_name(format!("{}{}", name, RETIRED_SUFFIX)))
+        .ok_or_else(|| IgniteError::InvalidOperation {
+            operation: "remove_archived_proof".to_string(),
+            reason: format!("Not an archived claim path: {:?}", claim_path),
+        })?;
+
+    let mut removed = Vec::new();
+    let receipt = record.receipt.as_ref().map(|receipt| proofs.join(receipt));
+    for path in [Some(claim_path), receipt, Some(record_path)]
+        .into_iter()
+        .flatten()
+    {
+        if path.exists() {
+            fs::remove_file(&path)
+                .map_err(|e| IgniteError::io_error("remove_archived_proof", path.clone(), e))?;
+            removed.push(path);
+        }
+    }
+    Ok(removed)
+}
+
 /// Persist manifest to vault
 pub fn save_manifest(manifest: &AffectedKeyManifest) -> Result<PathBuf> {
+    let result = write_manifest(manifest);
+    let fingerprints: Vec<&KeyFingerprint> = std::iter::once(&manifest.event.parent_fingerprint)
+        .chain(manifest.children.iter().map(|child| &child.fingerprint))
+        .collect();
+    let audited = logging::record_result("save_manifest", &fingerprints, &result);
+    let path = result?;
+    audited.map(|()| path)
+}
+
+fn write_manifest(manifest: &AffectedKeyManifest) -> Result<PathBuf> {
     init_vault()?;
 
     let path = manifest_path(manifest);
@@ -138,6 +462,259 @@ pub fn load_manifest(parent_fp_short: &str, filename: &str) -> Result<AffectedKe
         .map_err(|e| IgniteError::crypto_error("deserialize_manifest", e.to_string()))
 }
 
+/// Generate path for a lineage's passphrase history
+pub fn passphrase_history_path(lineage_id: &str) -> PathBuf {
+    utils::passphrase_history_dir().join(format!("{}.json", lineage_id))
+}
+
+/// Persist a lineage's passphrase history
+pub fn save_passphrase_history(history: &PassphraseHistory) -> Result<PathBuf> {
+    init_vault()?;
+
+    let path = passphrase_history_path(&history.lineage_id);
+    let json = serde_json::to_string_pretty(history)
+        .map_err(|e| IgniteError::crypto_error("serialize_passphrase_history", e.to_string()))?;
+
+    atomic_write(&path, json.as_bytes())?;
+    Ok(path)
+}
+
+/// Load a lineage's passphrase history; empty if none has been recorded
+pub fn load_passphrase_history(lineage_id: &str) -> Result<PassphraseHistory> {
+    let path = passphrase_history_path(lineage_id);
+    if !path.exists() {
+        return Ok(PassphraseHistory::new(lineage_id));
+    }
+
+    let json = fs::read_to_string(&path)
+        .map_err(|e| IgniteError::io_error("read_passphrase_history", path.clone(), e))?;
+    serde_json::from_str(&json)
+        .map_err(|e| IgniteError::crypto_error("deserialize_passphrase_history", e.to_string()))
+}
+
+/// Directory holding one operation request and its approvals
+pub fn approval_request_dir(request_id: &str) -> Result<PathBuf> {
+    if !approvals::is_valid_request_id(request_id) {
+        return Err(IgniteError::InvalidOperation {
+            operation: "approval_request".to_string(),
+            reason: format!("Invalid request id: {}", request_id),
+        });
+    }
+    Ok(utils::approvals_dir().join(request_id))
+}
+
+/// Persist a new operation request
+pub fn save_operation_request(request: &OperationRequest) -> Result<PathBuf> {
+    init_vault()?;
+
+    let path = approval_request_dir(&request.id)?.join("request.json");
+    if path.exists() {
+        return Err(IgniteError::InvalidOperation {
+            operation: "save_operation_request".to_string(),
+            reason: format!("Request {} already exists", request.id),
+        });
+    }
+    let json = serde_json::to_string_pretty(request)
+        .map_err(|e| IgniteError::crypto_error("serialize_operation_request", e.to_string()))?;
+
+    atomic_write(&path, json.as_bytes())?;
+    Ok(path)
+}
+
+pub fn load_operation_request(request_id: &str) -> Result<OperationRequest> {
+    let path = approval_request_dir(request_id)?.join("request.json");
+    let json = fs::read_to_string(&path)
+        .map_err(|e| IgniteError::io_error("read_operation_request", path.clone(), e))?;
+    serde_json::from_str(&json)
+        .map_err(|e| IgniteError::crypto_error("deserialize_operation_request", e.to_string()))
+}
+
+/// Attach an approval to its request, replacing that approver's previous one
+pub fn save_approval(approval: &Approval) -> Result<PathBuf> {
+    let dir = approval_request_dir(&approval.request_id)?;
+    if !approvals::is_valid_approver_name(&approval.approver) {
+        return Err(IgniteError::InvalidOperation {
+            operation: "save_approval".to_string(),
+            reason: format!("Invalid approver name: {}", approval.approver),
+        });
+    }
+    if !dir.join("request.json").exists() {
+        return Err(IgniteError::InvalidOperation {
+            operation: "save_approval".to_string(),
+            reason: format!("No request with id {}", approval.request_id),
+        });
+    }
+
+    let path = dir
+        .join("approvals")
+        .join(format!("{}.json", approval.approver));
+    let json = serde_json::to_string_pretty(approval)
+        .map_err(|e| IgniteError::crypto_error("serialize_approval", e.to_string()))?;
+
+    atomic_write(&path, json.as_bytes())?;
+    Ok(path)
+}
+
+/// Every approval attached to a request
+pub fn load_approvals(request_id: &str) -> Result<Vec<Approval>> {
+    let dir = approval_request_dir(request_id)?.join("approvals");
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries =
+        fs::read_dir(&dir).map_err(|e| IgniteError::io_error("list_approvals", dir.clone(), e))?;
+    let mut approvals = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| IgniteError::io_error("list_approvals", dir.clone(), e))?
+            .path();
+        if path.extension().and_then(|s| s.to_str()) != Some("json") {
+            continue;
+        }
+        let json = fs::read_to_string(&path)
+            .map_err(|e| IgniteError::io_error("read_approval", path.clone(), e))?;
+        approvals.push(
+            serde_json::from_str(&json)
+                .map_err(|e| IgniteError::crypto_error("deserialize_approval", e.to_string()))?,
+        );
+    }
+    Ok(approvals)
+}
+
+/// Mark a request as used; a request can be consumed only once
+pub fn save_approval_consumption(consumption: &ApprovalConsumption) -> Result<PathBuf> {
+    let path = approval_request_dir(&consumption.request_id)?.join("consumed.json");
+    if path.exists() {
+        return Err(IgniteError::InvalidOperation {
+            operation: "consume_request".to_string(),
+            reason: format!("Request {} was already used", consumption.request_id),
+        });
+    }
+    let json = serde_json::to_string_pretty(consumption)
+        .map_err(|e| IgniteError::crypto_error("serialize_consumption", e.to_string()))?;
+
+    atomic_write(&path, json.as_bytes())?;
+    Ok(path)
+}
+
+pub fn load_approval_consumption(request_id: &str) -> Result<Option<ApprovalConsumption>> {
+    let path = approval_request_dir(request_id)?.join("consumed.json");
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let json = fs::read_to_string(&path)
+        .map_err(|e| IgniteError::io_error("read_consumption", path.clone(), e))?;
+    serde_json::from_str(&json)
+        .map(Some)
+        .map_err(|e| IgniteError::crypto_error("deserialize_consumption", e.to_string()))
+}
+
+pub fn save_approver_registry(registry: &ApproverRegistry) -> Result<PathBuf> {
+    init_vault()?;
+
+    let path = utils::approvers_path();
+    let json = serde_json::to_string_pretty(registry)
+        .map_err(|e| IgniteError::crypto_error("serialize_approvers", e.to_string()))?;
+
+    atomic_write(&path, json.as_bytes())?;
+    Ok(path)
+}
+
+/// Registered approvers; empty if none have been registered
+pub fn load_approver_registry() -> Result<ApproverRegistry> {
+    let path = utils::approvers_path();
+    if !path.exists() {
+        return Ok(ApproverRegistry::default());
+    }
+
+    let json = fs::read_to_string(&path)
+        .map_err(|e| IgniteError::io_error("read_approvers", path.clone(), e))?;
+    serde_json::from_str(&json)
+        .map_err(|e| IgniteError::crypto_error("deserialize_approvers", e.to_string()))
+}
+
+/// Record a tombstone for a revoked fingerprint
+///
+/// Tombstones are write-once: recording a second tombstone for the same
+/// fingerprint fails with `Tombstoned`, and an existing record file is never
+/// replaced.
+pub fn save_tombstone(tombstone: &Tombstone) -> Result<PathBuf> {
+    init_vault()?;
+    ensure_not_tombstoned(&tombstone.fingerprint)?;
+
+    let path = tombstone_path(&tombstone.fingerprint);
+    if path.exists() {
+        return Err(IgniteError::InvalidOperation {
+            operation: "save_tombstone".to_string(),
+            reason: format!(
+                "tombstone record {} already exists and does not match {}",
+                path.display(),
+                tombstone.fingerprint
+            ),
+        });
+    }
+
+    let json = serde_json::to_string_pretty(tombstone)
+        .map_err(|e| IgniteError::crypto_error("serialize_tombstone", e.to_string()))?;
+
+    atomic_write_new(&path, json.as_bytes())?;
+    Ok(path)
+}
+
+/// Load the tombstone for a fingerprint, if it has been revoked
+pub fn load_tombstone(fingerprint: &KeyFingerprint) -> Result<Option<Tombstone>> {
+    let path = tombstone_path(fingerprint);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let tombstone = load_tombstone_from_path(&path)?;
+    Ok(Some(tombstone).filter(|t| &t.fingerprint == fingerprint))
+}
+
+/// Load every tombstone in the registry
+pub fn load_tombstones() -> Result<Vec<Tombstone>> {
+    let dir = utils::tombstones_dir();
+
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries =
+        fs::read_dir(&dir).map_err(|e| IgniteError::io_error("list_tombstones", dir, e))?;
+
+    let mut tombstones = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| IgniteError::InvalidOperation {
+            operation: "list_tombstones_entry".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        if entry.path().extension().and_then(|s| s.to_str()) == Some("json") {
+            tombstones.push(load_tombstone_from_path(&entry.path())?);
+        }
+    }
+
+    Ok(tombstones)
+}
+
+fn load_tombstone_from_path(path: &Path) -> Result<Tombstone> {
+    let json = fs::read_to_string(path)
+        .map_err(|e| IgniteError::io_error("read_tombstone", path.to_path_buf(), e))?;
+
+    serde_json::from_str(&json)
+        .map_err(|e| IgniteError::crypto_error("deserialize_tombstone", e.to_string()))
+}
+
+fn ensure_not_tombstoned(fingerprint: &KeyFingerprint) -> Result<()> {
+    match load_tombstone(fingerprint)? {
+        Some(tombstone) => Err(tombstone.to_error()),
+        None => Ok(()),
+    }
+}
+
 /// List all keys of a given type
 pub fn list_keys(key_type: KeyType) -> Result<Vec<PathBuf>> {
     let dir = utils::keys_dir().join(key_type.to_string());
@@ -163,15 +740,47 @@ pub fn list_keys(key_type: KeyType) -> Result<Vec<PathBuf>> {
     Ok(paths)
 }
 
-/// List all proofs for a given fingerprint
+/// List all claim proofs for a given fingerprint (receipts are excluded)
 pub fn list_proofs(fingerprint: &KeyFingerprint) -> Result<Vec<PathBuf>> {
-    let dir = utils::proofs_dir().join(fingerprint.short());
+    list_claims_in(&utils::proofs_dir().join(fingerprint.short()))
+}
+
+/// List every live claim proof in the vault, skipping the archive
+pub fn list_all_proofs() -> Result<Vec<PathBuf>> {
+    let dir = utils::proofs_dir();
 
     if !dir.exists() {
         return Ok(Vec::new());
     }
 
-    let entries = fs::read_dir(&dir).map_err(|e| IgniteError::io_error("list_proofs", dir, e))?;
+    let entries =
+        fs::read_dir(&dir).map_err(|e| IgniteError::io_error("list_all_proofs", dir, e))?;
+
+    let archive = utils::proof_archive_dir();
+    let mut paths = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| IgniteError::InvalidOperation {
+            operation: "list_all_proofs_entry".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        let path = entry.path();
+        if path.is_dir() && path != archive {
+            paths.extend(list_claims_in(&path)?);
+        }
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+fn list_claims_in(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries = fs::read_dir(dir)
+        .map_err(|e| IgniteError::io_error("list_proofs", dir.to_path_buf(), e))?;
 
     let mut paths = Vec::new();
     for entry in entries {
@@ -180,14 +789,50 @@ pub fn list_proofs(fingerprint: &KeyFingerprint) -> Result<Vec<PathBuf>> {
             reason: e.to_string(),
         })?;
 
-        if entry.path().extension().and_then(|s| s.to_str()) == Some("json") {
-            paths.push(entry.path());
+        let path = entry.path();
+        let is_receipt = path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .is_some_and(|name| name.ends_with(RECEIPT_SUFFIX));
+
+        if path.extension().and_then(|s| s.to_str()) == Some("json") && !is_receipt {
+            paths.push(path);
         }
     }
 
     Ok(paths)
 }
 
+/// Move a claim and its receipt (if present) into `proofs/archive/<parent_short>/`
+///
+/// Returns the archived paths.
+pub fn archive_proof_pair(claim_path: &Path) -> Result<Vec<PathBuf>> {
+    let parent_dir = claim_path
+        .parent()
+        .and_then(|dir| dir.file_name())
+        .ok_or_else(|| IgniteError::InvalidOperation {
+            operation: "archive_proof".to_string(),
+            reason: format!("Not a vault proof path: {:?}", claim_path),
+        })?;
+    let archive_dir = utils::proof_archive_dir().join(parent_dir);
+    fs::create_dir_all(&archive_dir)
+        .map_err(|e| IgniteError::io_error("create_proof_archive", archive_dir.clone(), e))?;
+
+    let mut archived = Vec::new();
+    let receipt = counterpart_proof_path(claim_path).filter(|path| path.exists());
+    for path in std::iter::once(claim_path.to_path_buf()).chain(receipt) {
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+        let target = archive_dir.join(name);
+        fs::rename(&path, &target)
+            .map_err(|e| IgniteError::io_error("archive_proof", path.clone(), e))?;
+        archived.push(target);
+    }
+
+    Ok(archived)
+}
+
 /// List all manifests for a given parent fingerprint
 pub fn list_manifests(parent_fp_short: &str) -> Result<Vec<PathBuf>> {
     let dir = utils::manifests_dir().join(parent_fp_short);
@@ -217,7 +862,6 @@ pub fn list_manifests(parent_fp_short: &str) -> Result<Vec<PathBuf>> {
 // TODO: Implement key deletion with archival
 // TODO: Implement proof archival during rotation
 // TODO: Add integrity verification on load (hash checking)
-// TODO: Add encryption at rest for private key material (via Cage)
 // TODO: Implement backup/restore functionality
 
 #[cfg(test)]
@@ -228,12 +872,14 @@ mod tests {
         AffectedKeyManifest, ManifestChild, ManifestEvent, ManifestEventType,
     };
     use crate::ignite::authority::proofs::{AuthorityClaim, ProofBundle};
+    use crate::ignite::authority::tombstones::RevocationReason;
     use ed25519_dalek::{SecretKey, SigningKey};
     use hub::random_ext::rand::{rng, Rng};
     use hub::time_ext::chrono::{Duration, Utc};
     use serial_test::serial;
     use std::env;
     use tempfile::TempDir;
+    use zeroize::Zeroizing;
 
     struct TestEnvironment {
         _temp_dir: TempDir,
@@ -243,6 +889,7 @@ mod tests {
         fn new() -> Self {
             let temp_dir = TempDir::new().unwrap();
             env::set_var("IGNITE_DATA_ROOT", temp_dir.path());
+            env::set_var(sealing::VAULT_PASSPHRASE_ENV, "test vault passphrase");
             Self {
                 _temp_dir: temp_dir,
             }
@@ -252,6 +899,7 @@ mod tests {
     impl Drop for TestEnvironment {
         fn drop(&mut self) {
             env::remove_var("IGNITE_DATA_ROOT");
+            env::remove_var(sealing::VAULT_PASSPHRASE_ENV);
         }
     }
 
@@ -261,26 +909,29 @@ mod tests {
         let secret_key = SecretKey::from(secret_bytes);
         let signing_key = SigningKey::from(&secret_key);
         let public_key = signing_key.verifying_key().to_bytes().to_vec();
-        let private_key = Some(signing_key.to_bytes().to_vec());
+        let private_key = Some(Zeroizing::new(signing_key.to_bytes().to_vec()));
 
         KeyMaterial::new(public_key, private_key, KeyFormat::Ed25519)
     }
 
-    fn create_test_authority_key() -> AuthorityKey {
-        let key_material = create_test_key_material();
-        AuthorityKey::new(key_material, KeyType::Master, None, None).unwrap()
-    }
-
     fn create_test_authority_key_with_type(key_type: KeyType) -> AuthorityKey {
         let key_material = create_test_key_material();
         AuthorityKey::new(key_material, key_type, None, None).unwrap()
     }
 
+    fn create_test_child_key(key_type: KeyType, parent: &AuthorityKey) -> AuthorityKey {
+        let mut key = create_test_authority_key_with_type(key_type);
+        key.set_parent(parent.fingerprint().clone()).unwrap();
+        key
+    }
+
     #[test]
     #[serial]
     fn test_key_storage_round_trip() {
         let _test_env = TestEnvironment::new();
-        let original_key = create_test_authority_key();
+        let skull_key = create_test_authority_key_with_type(KeyType::Skull);
+        save_key(&skull_key).unwrap();
+        let original_key = create_test_child_key(KeyType::Master, &skull_key);
 
         // Save the key
         let saved_path = save_key(&original_key).unwrap();
@@ -297,9 +948,168 @@ mod tests {
             original_key.key_material().public_key()
         );
         assert_eq!(
-            loaded_key.key_material().private_key(),
-            original_key.key_material().private_key()
+            loaded_key.key_material().signing_key().unwrap().to_bytes(),
+            original_key
+                .key_material()
+                .signing_key()
+                .unwrap()
+                .to_bytes()
         );
+        assert_eq!(loaded_key.parent(), Some(skull_key.fingerprint()));
+    }
+
+    #[test]
+    #[serial]
+    fn test_save_key_seals_private_material() {
+        let _test_env = TestEnvironment::new();
+        let skull_key = create_test_authority_key_with_type(KeyType::Skull);
+        let expected = skull_key.key_material().signing_key().unwrap();
+
+        env::set_var(
+            sealing::VAULT_PASSPHRASE_ENV,
+            "correct horse battery staple",
+        );
+        let path = save_key(&skull_key).unwrap();
+
+        // No plaintext on disk, only a versioned sealed record
+        let record: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert!(record["key_material"].get("private_key").is_none());
+        assert_eq!(
+            record["key_material"]["sealed_private_key"]["version"],
+            sealing::SEALED_KEY_VERSION
+        );
+
+        // Loading leaves the key sealed; signing opens it on demand
+        let loaded = load_key(KeyType::Skull, skull_key.fingerprint()).unwrap();
+        assert!(loaded.key_material().is_sealed());
+        assert!(loaded.key_material().private_key().is_none());
+        assert_eq!(
+            loaded.key_material().signing_key().unwrap().to_bytes(),
+            expected.to_bytes()
+        );
+
+        env::set_var(sealing::VAULT_PASSPHRASE_ENV, "wrong passphrase");
+        assert!(loaded.key_material().signing_key().is_err());
+        env::remove_var(sealing::VAULT_PASSPHRASE_ENV);
+        assert!(loaded.key_material().signing_key().is_err());
+
+        // Without a vault passphrase plaintext material is refused, not written
+        let master_key = create_test_child_key(KeyType::Master, &skull_key);
+        assert!(save_key(&master_key).is_err());
+        assert!(!key_path(KeyType::Master, master_key.fingerprint()).exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_load_key_accepts_legacy_plaintext() {
+        let _test_env = TestEnvironment::new();
+        let skull_key = create_test_authority_key_with_type(KeyType::Skull);
+
+        // Legacy records carry `private_key` as a byte array and no sealed record
+        let path = key_path(KeyType::Skull, skull_key.fingerprint());
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, serde_json::to_string_pretty(&skull_key).unwrap()).unwrap();
+        let record: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert!(record["key_material"]["private_key"].is_array());
+
+        let loaded = load_key_from_path(&path).unwrap();
+        assert!(!loaded.key_material().is_sealed());
+        assert_eq!(
+            loaded.key_material().private_key(),
+            skull_key.key_material().private_key()
+        );
+        assert!(loaded.key_material().signing_key().is_ok());
+
+        // Saving it again migrates the record to a sealed one
+        save_key(&loaded).unwrap();
+        assert!(load_key_from_path(&path)
+            .unwrap()
+            .key_material()
+            .is_sealed());
+    }
+
+    #[test]
+    #[serial]
+    fn test_save_key_rejects_orphans() {
+        let _test_env = TestEnvironment::new();
+
+        let unlinked = create_test_authority_key_with_type(KeyType::Master);
+        assert!(matches!(
+            save_key(&unlinked),
+            Err(IgniteError::OrphanKey { .. })
+        ));
+
+        // Parent link must point at a persisted key of the controlling tier
+        let skull_key = create_test_authority_key_with_type(KeyType::Skull);
+        let linked = create_test_child_key(KeyType::Master, &skull_key);
+        assert!(matches!(
+            save_key(&linked),
+            Err(IgniteError::OrphanKey { .. })
+        ));
+
+        save_key(&skull_key).unwrap();
+        assert!(save_key(&linked).is_ok());
+    }
+
+    #[test]
+    #[serial]
+    fn test_save_key_rejects_tombstoned() {
+        let _test_env = TestEnvironment::new();
+        let skull_key = create_test_authority_key_with_type(KeyType::Skull);
+        save_key(&skull_key).unwrap();
+
+        let tombstone = Tombstone::new(
+            skull_key.fingerprint().clone(),
+            KeyType::Skull,
+            RevocationReason::KeyCompromise,
+            "tester",
+        );
+        let path = save_tombstone(&tombstone).unwrap();
+        assert!(path.starts_with(utils::tombstones_dir()));
+
+        assert!(matches!(
+            save_key(&skull_key),
+            Err(IgniteError::Tombstoned { .. })
+        ));
+        assert!(matches!(
+            save_tombstone(&tombstone),
+            Err(IgniteError::Tombstoned { .. })
+        ));
+
+        let loaded = load_tombstone(skull_key.fingerprint()).unwrap().unwrap();
+        assert_eq!(loaded.reason, RevocationReason::KeyCompromise);
+        assert_eq!(load_tombstones().unwrap().len(), 1);
+    }
+
+    #[test]
+    #[serial]
+    fn test_tombstones_survive_short_prefix_collisions() {
+        let _test_env = TestEnvironment::new();
+        let tombstone = |hex: &str| {
+            Tombstone::new(
+                KeyFingerprint::from_string(&format!("SHA256:{}", hex)).unwrap(),
+                KeyType::Master,
+                RevocationReason::KeyCompromise,
+                "tester",
+            )
+        };
+        let first = tombstone(&format!("deadbeef{}", "11".repeat(28)));
+        let second = tombstone(&format!("deadbeef{}", "22".repeat(28)));
+        assert_eq!(first.fingerprint.short(), second.fingerprint.short());
+
+        let first_path = save_tombstone(&first).unwrap();
+        let second_path = save_tombstone(&second).unwrap();
+        assert_ne!(first_path, second_path);
+        assert!(load_tombstone(&first.fingerprint).unwrap().is_some());
+        assert!(load_tombstone(&second.fingerprint).unwrap().is_some());
+
+        // A record under the path that does not match is never overwritten
+        let third = tombstone(&format!("deadbeef{}", "33".repeat(28)));
+        fs::copy(&first_path, tombstone_path(&third.fingerprint)).unwrap();
+        assert!(save_tombstone(&third).is_err());
+        assert!(load_tombstone(&first.fingerprint).unwrap().is_some());
     }
 
     #[test]
@@ -313,7 +1123,8 @@ mod tests {
             SigningKey::from(&secret_key)
         };
 
-        let parent_fp = KeyFingerprint::from_string("SHA256:parent123").unwrap();
+        let parent_fp =
+            KeyFingerprint::from_key_material(&signing_key.verifying_key().to_bytes()).unwrap();
         let child_fp = KeyFingerprint::from_string("SHA256:child456").unwrap();
         let expires_at = Utc::now() + Duration::hours(1);
 
@@ -339,6 +1150,37 @@ mod tests {
         assert!(loaded_proof.verify_full().is_ok());
     }
 
+    #[test]
+    #[serial]
+    fn test_proof_pair_storage_round_trip() {
+        let _test_env = TestEnvironment::new();
+        let parent_material = KeyMaterial::generate_ed25519();
+        let child_material = KeyMaterial::generate_ed25519();
+        let parent_key = parent_material.signing_key().unwrap();
+        let child_key = child_material.signing_key().unwrap();
+
+        let parent_fp = KeyFingerprint::from_key_material(parent_material.public_key()).unwrap();
+        let child_fp = KeyFingerprint::from_key_material(child_material.public_key()).unwrap();
+        let expires_at = Utc::now() + Duration::hours(1);
+
+        let claim = AuthorityClaim::new(parent_fp.clone(), child_fp, "test storage");
+        let pair = ProofPair::issue(&claim, &parent_key, &child_key, expires_at).unwrap();
+
+        let timestamp = "2024-01-01T12-00-00Z";
+        let (claim_path, receipt_path) = save_proof_pair(&pair, &parent_fp, timestamp).unwrap();
+        assert_eq!(claim_path.parent(), receipt_path.parent());
+        assert_eq!(counterpart_proof_path(&claim_path).unwrap(), receipt_path);
+        assert_eq!(counterpart_proof_path(&receipt_path).unwrap(), claim_path);
+
+        let loaded = load_proof_pair(&parent_fp, timestamp).unwrap();
+        assert!(loaded.verify().is_ok());
+        assert_eq!(list_proofs(&parent_fp).unwrap(), vec![claim_path]);
+
+        // A claim without its receipt is not a complete proof
+        fs::remove_file(&receipt_path).unwrap();
+        assert!(load_proof_pair(&parent_fp, timestamp).is_err());
+    }
+
     #[test]
     #[serial]
     fn test_manifest_storage_round_trip() {
@@ -398,9 +1240,11 @@ mod tests {
         let _test_env = TestEnvironment::new();
 
         // Save multiple keys of different types
-        let master_key = create_test_authority_key_with_type(KeyType::Master);
-        let repo_key = create_test_authority_key_with_type(KeyType::Repo);
+        let skull_key = create_test_authority_key_with_type(KeyType::Skull);
+        let master_key = create_test_child_key(KeyType::Master, &skull_key);
+        let repo_key = create_test_child_key(KeyType::Repo, &master_key);
 
+        save_key(&skull_key).unwrap();
         save_key(&master_key).unwrap();
         save_key(&repo_key).unwrap();
 
@@ -412,9 +1256,9 @@ mod tests {
         let repo_keys = list_keys(KeyType::Repo).unwrap();
         assert_eq!(repo_keys.len(), 1);
 
-        // List skull keys (should be empty)
-        let skull_keys = list_keys(KeyType::Skull).unwrap();
-        assert_eq!(skull_keys.len(), 0);
+        // List ignition keys (should be empty)
+        let ignition_keys = list_keys(KeyType::Ignition).unwrap();
+        assert_eq!(ignition_keys.len(), 0);
     }
 
     #[test]
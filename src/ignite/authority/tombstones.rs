@@ -0,0 +1,165 @@
+//! Tombstone registry for revoked keys.
+//!
+//! A tombstone permanently records that a fingerprint was revoked, why, and by
+//! whom. Storage and the authority chain consult the registry so a revoked key
+//! can never be silently reinstated.
+
+use std::fmt;
+
+use hub::data_ext::serde::{Deserialize, Serialize};
+use hub::time_ext::chrono::{DateTime, Utc};
+
+use super::chain::{KeyFingerprint, KeyType};
+use crate::ignite::error::{IgniteError, Result};
+
+/// Reason code recorded on a tombstone (modelled on RFC 5280 CRL reasons)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum RevocationReason {
+    Unspecified,
+    KeyCompromise,
+    AuthorityCompromise,
+    AffiliationChanged,
+    Superseded,
+    CessationOfOperation,
+    PrivilegeWithdrawn,
+}
+
+impl RevocationReason {
+    pub fn all() -> [RevocationReason; 7] {
+        [
+            RevocationReason::Unspecified,
+            RevocationReason::KeyCompromise,
+            RevocationReason::AuthorityCompromise,
+            RevocationReason::AffiliationChanged,
+            RevocationReason::Superseded,
+            RevocationReason::CessationOfOperation,
+            RevocationReason::PrivilegeWithdrawn,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RevocationReason::Unspecified => "unspecified",
+            RevocationReason::KeyCompromise => "key-compromise",
+            RevocationReason::AuthorityCompromise => "authority-compromise",
+            RevocationReason::AffiliationChanged => "affiliation-changed",
+            RevocationReason::Superseded => "superseded",
+            RevocationReason::CessationOfOperation => "cessation-of-operation",
+            RevocationReason::PrivilegeWithdrawn => "privilege-withdrawn",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Result<Self> {
+        RevocationReason::all()
+            .into_iter()
+            .find(|reason| reason.as_str() == s.to_lowercase())
+            .ok_or_else(|| IgniteError::InvalidOperation {
+                operation: "parse_revocation_reason".to_string(),
+                reason: format!(
+                    "Unknown revocation reason '{}'. Expected one of: {}",
+                    s,
+                    RevocationReason::all()
+                        .iter()
+                        .map(|reason| reason.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            })
+    }
+}
+
+impl From<RevocationReason> for String {
+    fn from(reason: RevocationReason) -> String {
+        reason.as_str().to_string()
+    }
+}
+
+impl TryFrom<String> for RevocationReason {
+    type Error = IgniteError;
+
+    fn try_from(s: String) -> Result<Self> {
+        RevocationReason::from_str(&s)
+    }
+}
+
+impl fmt::Display for RevocationReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Permanent record of a revoked fingerprint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tombstone {
+    pub schema_version: String,
+    pub fingerprint: KeyFingerprint,
+    pub role: KeyType,
+    pub reason: RevocationReason,
+    pub revoked_by: String,
+    pub revoked_at: DateTime<Utc>,
+}
+
+impl Tombstone {
+    pub fn new(
+        fingerprint: KeyFingerprint,
+        role: KeyType,
+        reason: RevocationReason,
+        revoked_by: impl Into<String>,
+    ) -> Self {
+        Self {
+            schema_version: "1.0".to_string(),
+            fingerprint,
+            role,
+            reason,
+            revoked_by: revoked_by.into(),
+            revoked_at: Utc::now(),
+        }
+    }
+
+    /// Error returned when a tombstoned fingerprint is used again
+    pub fn to_error(&self) -> IgniteError {
+        IgniteError::Tombstoned {
+            fingerprint: self.fingerprint.to_string(),
+            reason: self.reason.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hub::data_ext::serde_json;
+
+    #[test]
+    fn test_revocation_reason_round_trip() {
+        for reason in RevocationReason::all() {
+            assert_eq!(RevocationReason::from_str(reason.as_str()).unwrap(), reason);
+        }
+        assert_eq!(
+            RevocationReason::from_str("Key-Compromise").unwrap(),
+            RevocationReason::KeyCompromise
+        );
+        assert!(RevocationReason::from_str("lost-it").is_err());
+    }
+
+    #[test]
+    fn test_tombstone_serialization() {
+        let fingerprint = KeyFingerprint::from_string("SHA256:deadbeef").unwrap();
+        let tombstone = Tombstone::new(
+            fingerprint.clone(),
+            KeyType::Ignition,
+            RevocationReason::CessationOfOperation,
+            "alice",
+        );
+
+        let json = serde_json::to_string(&tombstone).unwrap();
+        assert!(json.contains(r#""reason":"cessation-of-operation""#));
+
+        let loaded: Tombstone = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.fingerprint, fingerprint);
+        assert_eq!(loaded.role, KeyType::Ignition);
+        assert_eq!(loaded.revoked_by, "alice");
+        assert!(matches!(loaded.to_error(), IgniteError::Tombstoned { .. }));
+    }
+}
@@ -0,0 +1,108 @@
+//! Operation correlation IDs.
+//!
+//! Each CLI invocation and library operation runs under one UUID, written
+//! into the claims, receipts, manifests and audit entries it produces so a
+//! single `create --parent` or rotation can be traced across every file.
+//! `begin` opens a scope on the current thread; a nested scope joins the
+//! operation already running instead of starting a new one, so a rotation
+//! started from the CLI carries the invocation's ID.
+//!
+//! Child processes (passphrase helpers, Cage) receive the ID as
+//! `IGNITE_OPERATION_ID`, and an invocation started with a valid
+//! `IGNITE_OPERATION_ID` adopts it, so a calling tool can hand its own ID down.
+
+use std::cell::RefCell;
+use std::env;
+use std::process::Command;
+
+use hub::data_ext::uuid::Uuid;
+
+/// Environment variable carrying the operation ID across processes
+pub const OPERATION_ID_ENV: &str = "IGNITE_OPERATION_ID";
+
+thread_local! {
+    static CURRENT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Running operation; the outermost scope clears the ID when dropped
+#[must_use = "the operation ends when the scope is dropped"]
+#[derive(Debug)]
+pub struct OperationScope {
+    owner: bool,
+}
+
+impl OperationScope {
+    fn start(id: String) -> Self {
+        CURRENT.with(|current| {
+            let mut current = current.borrow_mut();
+            if current.is_some() {
+                return Self { owner: false };
+            }
+            *current = Some(id);
+            Self { owner: true }
+        })
+    }
+
+    /// ID of the operation this scope belongs to
+    pub fn id(&self) -> String {
+        current().unwrap_or_default()
+    }
+}
+
+impl Drop for OperationScope {
+    fn drop(&mut self) {
+        if self.owner {
+            CURRENT.with(|current| *current.borrow_mut() = None);
+        }
+    }
+}
+
+/// Join the running operation, or start a new one
+pub fn begin() -> OperationScope {
+    OperationScope::start(Uuid::new_v4().to_string())
+}
+
+/// Start the invocation's operation, adopting a valid inherited `IGNITE_OPERATION_ID`
+pub fn begin_from_env() -> OperationScope {
+    let inherited = env::var(OPERATION_ID_ENV)
+        .ok()
+        .filter(|id| Uuid::parse_str(id).is_ok());
+    OperationScope::start(inherited.unwrap_or_else(|| Uuid::new_v4().to_string()))
+}
+
+/// ID of the running operation, if any
+pub fn current() -> Option<String> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Pass the running operation's ID to a child process
+pub fn export(command: &mut Command) -> &mut Command {
+    match current() {
+        Some(id) => command.env(OPERATION_ID_ENV, id),
+        None => command,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_scopes_share_the_outer_id() {
+        assert_eq!(current(), None);
+
+        let outer = begin();
+        let id = outer.id();
+        assert!(Uuid::parse_str(&id).is_ok());
+        {
+            let inner = begin();
+            assert_eq!(inner.id(), id);
+        }
+        // Dropping the inner scope leaves the operation running
+        assert_eq!(current(), Some(id.clone()));
+
+        drop(outer);
+        assert_eq!(current(), None);
+        assert_ne!(begin().id(), id);
+    }
+}
@@ -25,6 +25,25 @@ pub enum IgniteError {
     CryptoError { operation: String, reason: String },
     /// Key validation failed
     InvalidKey { reason: String },
+    /// A recorded authority edge points at a key that is not in the vault
+    DanglingRelationship { parent: String, child: String },
+    /// A recorded authority edge contradicts the hierarchy or another edge
+    InconsistentRelationship {
+        parent: String,
+        child: String,
+        reason: String,
+    },
+    /// A non-Skull key has no valid parent link (security invariant #1)
+    OrphanKey { fingerprint: String, reason: String },
+    /// The fingerprint has been revoked and may never be reinstated
+    Tombstoned { fingerprint: String, reason: String },
+    /// One or more policies denied the operation
+    PolicyViolation {
+        operation: String,
+        violations: Vec<String>,
+    },
+    /// A danger-mode guard refused a destructive operation
+    DangerRefused { operation: String, reason: String },
 }
 
 impl Display for IgniteError {
@@ -54,6 +73,54 @@ impl Display for IgniteError {
             IgniteError::InvalidKey { reason } => {
                 write!(f, "invalid key: {}", reason)
             }
+            IgniteError::DanglingRelationship { parent, child } => {
+                write!(
+                    f,
+                    "dangling authority edge {} -> {}: key not found in vault",
+                    parent, child
+                )
+            }
+            IgniteError::InconsistentRelationship {
+                parent,
+                child,
+                reason,
+            } => {
+                write!(
+                    f,
+                    "inconsistent authority edge {} -> {}: {}",
+                    parent, child, reason
+                )
+            }
+            IgniteError::OrphanKey {
+                fingerprint,
+                reason,
+            } => {
+                write!(f, "orphan key {}: {}", fingerprint, reason)
+            }
+            IgniteError::Tombstoned {
+                fingerprint,
+                reason,
+            } => {
+                write!(
+                    f,
+                    "key {} is tombstoned ({}) and cannot be reinstated",
+                    fingerprint, reason
+                )
+            }
+            IgniteError::PolicyViolation {
+                operation,
+                violations,
+            } => {
+                write!(
+                    f,
+                    "policy denied '{}': {}",
+                    operation,
+                    violations.join("; ")
+                )
+            }
+            IgniteError::DangerRefused { operation, reason } => {
+                write!(f, "dangerous operation '{}' refused: {}", operation, reason)
+            }
         }
     }
 }
@@ -1,5 +1,647 @@
-//! Logging helpers placeholder.
+//! Audit logging.
+//!
+//! Events are appended as JSON lines to `audit.jsonl` under the data root.
+//! Each entry records who did what to which keys and how it ended, plus a
+//! sequence number and the SHA-256 of the previous line, so the log forms a
+//! hash chain. `audit.head` anchors the newest entry, which makes a truncated
+//! tail detectable as well; `verify` walks the chain and reports the first
+//! break. The chain starts at entry 0 on the log's first line.
+//!
+//! Writers hold an exclusive lock on the log while they read its tail, append
+//! and update the head. A failed write is returned to the caller, so
+//! operations that change the vault fail rather than go unrecorded.
 
-pub fn log_event(_message: &str) {
-    // TODO: wire into audit logging once storage + proofs land.
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Read, Write};
+
+use hub::data_ext::serde::{Deserialize, Serialize};
+use hub::data_ext::serde_json;
+use hub::time_ext::chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use sha2::{Digest, Sha256};
+
+use crate::ignite::authority::canonical;
+use crate::ignite::authority::{AuthorityChain, KeyFingerprint};
+use crate::ignite::correlation;
+use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::utils;
+
+/// `prev_hash` of the first chained entry
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// How an audited operation ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum AuditOutcome {
+    Success,
+    Failure,
+    /// A guard or policy declined to run the operation
+    Refused,
+    /// Informational event that is not an operation
+    Info,
+}
+
+impl AuditOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditOutcome::Success => "success",
+            AuditOutcome::Failure => "failure",
+            AuditOutcome::Refused => "refused",
+            AuditOutcome::Info => "info",
+        }
+    }
+}
+
+impl From<AuditOutcome> for String {
+    fn from(outcome: AuditOutcome) -> String {
+        outcome.as_str().to_string()
+    }
+}
+
+impl TryFrom<String> for AuditOutcome {
+    type Error = IgniteError;
+
+    fn try_from(s: String) -> Result<Self> {
+        match s.as_str() {
+            "success" => Ok(AuditOutcome::Success),
+            "failure" => Ok(AuditOutcome::Failure),
+            "refused" => Ok(AuditOutcome::Refused),
+            "info" => Ok(AuditOutcome::Info),
+            _ => Err(IgniteError::InvalidOperation {
+                operation: "parse_audit_outcome".to_string(),
+                reason: format!("Unknown audit outcome: {}", s),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for AuditOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One line of the audit log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuditEntry {
+    /// Position in the chain, from 0
+    pub seq: u64,
+    #[serde(with = "canonical::timestamp")]
+    pub timestamp: DateTime<Utc>,
+    pub actor: String,
+    pub operation: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fingerprints: Vec<String>,
+    pub outcome: AuditOutcome,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub message: String,
+    /// Correlation ID of the operation the entry belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
+    /// SHA-256 of the previous line, `GENESIS_HASH` for the first entry
+    pub prev_hash: String,
+}
+
+/// Newest chained entry, kept beside the log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct AuditHead {
+    seq: u64,
+    hash: String,
+}
+
+/// Record an informational event
+///
+/// Nothing depends on the event, so a failed write is reported on stderr
+/// instead of returned.
+pub fn log_event(message: &str) {
+    if let Err(e) = record("event", &[], AuditOutcome::Info, message) {
+        eprintln!("Warning: {}", e);
+    }
+}
+
+/// Record `operation` on `fingerprints` and how it ended
+pub fn record(
+    operation: &str,
+    fingerprints: &[&KeyFingerprint],
+    outcome: AuditOutcome,
+    message: &str,
+) -> Result<()> {
+    append_entry(operation, fingerprints, outcome, message)
+}
+
+/// Record `operation` as a success or, with the error text, a failure
+pub fn record_result<T>(
+    operation: &str,
+    fingerprints: &[&KeyFingerprint],
+    result: &Result<T>,
+) -> Result<()> {
+    match result {
+        Ok(_) => record(operation, fingerprints, AuditOutcome::Success, ""),
+        Err(e) => record(
+            operation,
+            fingerprints,
+            AuditOutcome::Failure,
+            &e.to_string(),
+        ),
+    }
+}
+
+fn append_entry(
+    operation: &str,
+    fingerprints: &[&KeyFingerprint],
+    outcome: AuditOutcome,
+    message: &str,
+) -> Result<()> {
+    let path = utils::audit_log_path();
+    let io_error = |e| IgniteError::io_error("append_audit_log", path.clone(), e);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+
+    // Held until `file` drops, across the tail read, the append and the head write
+    let mut file = OpenOptions::new()
+        .create(true)
+        .read(true)
+        .append(true)
+        .open(&path)
+        .map_err(io_error)?;
+    file.lock().map_err(io_error)?;
+
+    let mut text = String::new();
+    file.read_to_string(&mut text).map_err(io_error)?;
+    let (seq, prev_hash) = next_link(&text)?;
+    let entry = AuditEntry {
+        seq,
+        timestamp: Utc::now(),
+        actor: whoami::username(),
+        operation: operation.to_string(),
+        fingerprints: fingerprints.iter().map(|fp| fp.to_string()).collect(),
+        outcome,
+        message: message.to_string(),
+        operation_id: correlation::current(),
+        prev_hash,
+    };
+    let line = serde_json::to_string(&entry)
+        .map_err(|e| IgniteError::crypto_error("serialize_audit_entry", e.to_string()))?;
+    writeln!(file, "{}", line).map_err(io_error)?;
+
+    let head = serde_json::to_string(&AuditHead {
+        seq,
+        hash: hash_line(&line),
+    })
+    .map_err(|e| IgniteError::crypto_error("serialize_audit_head", e.to_string()))?;
+    let head_path = utils::audit_head_path();
+    let temp_path = head_path.with_extension("tmp");
+    fs::write(&temp_path, head)
+        .and_then(|()| fs::rename(&temp_path, &head_path))
+        .map_err(|e| IgniteError::io_error("write_audit_head", head_path, e))
+}
+
+/// Sequence number and `prev_hash` for the entry after the log's last line
+///
+/// A last line that is not an audit entry is an error: appending after it
+/// would restart or fork the chain.
+fn next_link(text: &str) -> Result<(u64, String)> {
+    match text.lines().rev().find(|line| !line.is_empty()) {
+        None => Ok((0, GENESIS_HASH.to_string())),
+        Some(last) => match serde_json::from_str::<AuditEntry>(last) {
+            Ok(entry) => Ok((entry.seq + 1, hash_line(last))),
+            Err(e) => Err(IgniteError::InvalidOperation {
+                operation: "append_audit_log".to_string(),
+                reason: format!(
+                    "Last line of the audit log is not a well-formed entry ({}); run `ignite audit verify`",
+                    e
+                ),
+            }),
+        },
+    }
+}
+
+fn hash_line(line: &str) -> String {
+    format!("{:x}", Sha256::digest(line.as_bytes()))
+}
+
+/// Where and why the chain first fails to verify
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainBreak {
+    /// 1-based line number in the log
+    pub line: usize,
+    pub reason: String,
+}
+
+/// Result of walking the audit chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditVerification {
+    /// Chained entries verified before any break
+    pub entries: usize,
+    pub first_break: Option<ChainBreak>,
+}
+
+impl AuditVerification {
+    pub fn is_intact(&self) -> bool {
+        self.first_break.is_none()
+    }
+}
+
+/// Verify the vault's audit log against its head record
+pub fn verify() -> Result<AuditVerification> {
+    let path = utils::audit_log_path();
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(IgniteError::io_error("read_audit_log", path, e)),
+    };
+
+    let head_path = utils::audit_head_path();
+    let head = match fs::read_to_string(&head_path) {
+        Ok(json) => Some(
+            serde_json::from_str::<AuditHead>(&json)
+                .map_err(|e| IgniteError::crypto_error("deserialize_audit_head", e.to_string()))?,
+        ),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(IgniteError::io_error("read_audit_head", head_path, e)),
+    };
+
+    Ok(verify_chain(&text, head.as_ref()))
+}
+
+fn verify_chain(text: &str, head: Option<&AuditHead>) -> AuditVerification {
+    let mut verification = AuditVerification {
+        entries: 0,
+        first_break: None,
+    };
+    let mut prev_hash = GENESIS_HASH.to_string();
+    let mut line_count = 0;
+
+    for (index, line) in text.lines().enumerate() {
+        line_count = index + 1;
+        let broken = |reason: String| ChainBreak {
+            line: index + 1,
+            reason,
+        };
+
+        match serde_json::from_str::<AuditEntry>(line) {
+            Ok(entry) if entry.seq != verification.entries as u64 => {
+                verification.first_break = Some(broken(format!(
+                    "expected entry {} but found entry {}; entries were removed or reordered",
+                    verification.entries, entry.seq
+                )));
+                return verification;
+            }
+            Ok(entry) if entry.prev_hash != prev_hash => {
+                verification.first_break = Some(broken(
+                    "previous-entry hash does not match; the line before was edited or removed"
+                        .to_string(),
+                ));
+                return verification;
+            }
+            Ok(_) => verification.entries += 1,
+            Err(_) => {
+                verification.first_break =
+                    Some(broken("line is not a well-formed audit entry".to_string()));
+                return verification;
+            }
+        }
+        prev_hash = hash_line(line);
+    }
+
+    let end = ChainBreak {
+        line: line_count,
+        reason: String::new(),
+    };
+    verification.first_break = match head {
+        None if verification.entries > 0 => Some(ChainBreak {
+            reason: "audit head record is missing".to_string(),
+            ..end
+        }),
+        None => None,
+        Some(head) if head.seq + 1 > verification.entries as u64 => Some(ChainBreak {
+            reason: format!(
+                "log holds {} entries but the head records {}; the log was truncated",
+                verification.entries,
+                head.seq + 1
+            ),
+            ..end
+        }),
+        Some(head) if head.seq + 1 != verification.entries as u64 || head.hash != prev_hash => {
+            Some(ChainBreak {
+                reason: "last entry does not match the head record; it was edited".to_string(),
+                ..end
+            })
+        }
+        Some(_) => None,
+    };
+    verification
+}
+
+/// Filters for `query`; unset fields match every entry
+#[derive(Debug, Clone, Default)]
+pub struct AuditQuery {
+    /// Lowercase hex prefixes; an entry matches if any of its keys starts with one
+    pub fingerprints: Vec<String>,
+    pub operation: Option<String>,
+    pub actor: Option<String>,
+    /// Inclusive lower bound
+    pub since: Option<DateTime<Utc>>,
+    /// Exclusive upper bound
+    pub until: Option<DateTime<Utc>>,
+    pub outcome: Option<AuditOutcome>,
+    pub operation_id: Option<String>,
+}
+
+impl AuditQuery {
+    /// Match keys whose fingerprint starts with `prefix`, and every descendant of them in `chain`
+    pub fn with_key_tree(mut self, prefix: &str, chain: &AuthorityChain) -> Result<Self> {
+        let prefix = prefix
+            .strip_prefix("SHA256:")
+            .unwrap_or(prefix)
+            .to_lowercase();
+        if prefix.is_empty() || !prefix.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(IgniteError::InvalidOperation {
+                operation: "audit_query".to_string(),
+                reason: format!("Fingerprint prefix must be hex, got '{}'", prefix),
+            });
+        }
+
+        for key in chain.keys() {
+            if key.fingerprint().hex().starts_with(&prefix) {
+                for dependent in chain.find_dependent_keys(key.fingerprint())? {
+                    self.fingerprints
+                        .push(dependent.fingerprint().hex().to_string());
+                }
+            }
+        }
+        self.fingerprints.push(prefix);
+        self.fingerprints.sort();
+        self.fingerprints.dedup();
+        Ok(self)
+    }
+
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        let touches_key = self.fingerprints.is_empty()
+            || entry.fingerprints.iter().any(|fingerprint| {
+                let hex = fingerprint
+                    .split_once(':')
+                    .map_or(fingerprint.as_str(), |(_, hex)| hex);
+                self.fingerprints
+                    .iter()
+                    .any(|prefix| hex.starts_with(prefix.as_str()))
+            });
+
+        touches_key
+            && self
+                .operation
+                .as_ref()
+                .is_none_or(|operation| &entry.operation == operation)
+            && self
+                .actor
+                .as_ref()
+                .is_none_or(|actor| &entry.actor == actor)
+            && self.since.is_none_or(|since| entry.timestamp >= since)
+            && self.until.is_none_or(|until| entry.timestamp < until)
+            && self.outcome.is_none_or(|outcome| entry.outcome == outcome)
+            && self
+                .operation_id
+                .as_ref()
+                .is_none_or(|id| entry.operation_id.as_ref() == Some(id))
+    }
+}
+
+/// Chained entries of the vault's audit log matching `query`, oldest first
+///
+/// Lines that do not parse are skipped; `verify` reports where they are.
+pub fn query(query: &AuditQuery) -> Result<Vec<AuditEntry>> {
+    let path = utils::audit_log_path();
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(IgniteError::io_error("read_audit_log", path, e)),
+    };
+
+    Ok(text
+        .lines()
+        .filter_map(|line| serde_json::from_str::<AuditEntry>(line).ok())
+        .filter(|entry| query.matches(entry))
+        .collect())
+}
+
+/// Parse a time bound: RFC 3339, `YYYY-MM-DD` (midnight UTC) or `<n>h|d|w` before `now`
+pub fn parse_time(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(time.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_time(NaiveTime::MIN).and_utc());
+    }
+
+    let relative = value.len().checked_sub(1).and_then(|split| {
+        let count = value[..split].parse::<i64>().ok()?;
+        match &value[split..] {
+            "h" => Duration::try_hours(count),
+            "d" => Duration::try_days(count),
+            "w" => Duration::try_weeks(count),
+            _ => None,
+        }
+    });
+    relative
+        .map(|ago| now - ago)
+        .ok_or_else(|| IgniteError::InvalidOperation {
+            operation: "parse_time".to_string(),
+            reason: format!(
+                "Invalid time '{}': use RFC 3339, YYYY-MM-DD or a relative age like 12h, 7d, 2w",
+                value
+            ),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ignite::test_support::VaultEnvironment;
+    use serial_test::serial;
+
+    fn write_log(lines: &[&str]) {
+        let text: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+        fs::write(utils::audit_log_path(), text).unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn test_chain_detects_edits_truncation_and_reordering() {
+        let _env = VaultEnvironment::new();
+        let fingerprint = KeyFingerprint::from_key_material(b"audit test key").unwrap();
+
+        record("save_key", &[&fingerprint], AuditOutcome::Success, "").unwrap();
+        record_result::<()>(
+            "save_manifest",
+            &[],
+            &Err(IgniteError::InvalidKey {
+                reason: "test".to_string(),
+            }),
+        )
+        .unwrap();
+        log_event("note");
+
+        let intact = verify().unwrap();
+        assert!(intact.is_intact(), "{:?}", intact.first_break);
+        assert_eq!(intact.entries, 3);
+
+        let text = fs::read_to_string(utils::audit_log_path()).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        let entry: AuditEntry = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(entry.outcome, AuditOutcome::Failure);
+        assert_eq!(entry.message, "invalid key: test");
+
+        // Editing an entry breaks the link from the entry after it
+        write_log(&[
+            lines[0],
+            lines[1].replace("failure", "success").as_str(),
+            lines[2],
+        ]);
+        assert_eq!(verify().unwrap().first_break.unwrap().line, 3);
+
+        // Editing the newest entry is caught by the head record
+        write_log(&[
+            lines[0],
+            lines[1],
+            lines[2].replace("note", "nope").as_str(),
+        ]);
+        assert_eq!(verify().unwrap().first_break.unwrap().line, 3);
+
+        // Reordering and removal break the sequence
+        write_log(&[lines[1], lines[0], lines[2]]);
+        assert_eq!(verify().unwrap().first_break.unwrap().line, 1);
+        write_log(&[lines[0], lines[2]]);
+        assert_eq!(verify().unwrap().first_break.unwrap().line, 2);
+
+        // Dropping the tail leaves a valid chain that no longer reaches the head
+        write_log(&[lines[0], lines[1]]);
+        let truncated = verify().unwrap().first_break.unwrap();
+        assert!(
+            truncated.reason.contains("truncated"),
+            "{}",
+            truncated.reason
+        );
+
+        // The chain starts at the first line; nothing before entry 0 is accepted
+        let unchained = r#"{"timestamp":"2025-09-28T12:00:00Z","actor":"ops","message":"old"}"#;
+        let mut prefixed = vec![unchained];
+        prefixed.extend(&lines);
+        write_log(&prefixed);
+        assert_eq!(verify().unwrap().first_break.unwrap().line, 1);
+
+        write_log(&lines);
+        assert!(verify().unwrap().is_intact());
+    }
+
+    #[test]
+    #[serial]
+    fn test_append_refuses_a_malformed_tail() {
+        let _env = VaultEnvironment::new();
+        record("create", &[], AuditOutcome::Success, "").unwrap();
+
+        // A torn or foreign last line must not restart the chain at entry 0
+        let text = fs::read_to_string(utils::audit_log_path()).unwrap();
+        fs::write(utils::audit_log_path(), format!("{}{{\"seq\":1,", text)).unwrap();
+        assert!(record("create", &[], AuditOutcome::Success, "").is_err());
+        assert!(record_result("save_key", &[], &Ok(())).is_err());
+        assert_eq!(
+            fs::read_to_string(utils::audit_log_path())
+                .unwrap()
+                .lines()
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_query_filters_by_key_tree_and_fields() {
+        use crate::ignite::authority::{AuthorityKey, KeyMaterial, KeyType};
+
+        let _env = VaultEnvironment::new();
+        let key = |key_type| {
+            AuthorityKey::new(KeyMaterial::generate_ed25519(), key_type, None, None).unwrap()
+        };
+        let (skull, master, repo) = (
+            key(KeyType::Skull),
+            key(KeyType::Master),
+            key(KeyType::Repo),
+        );
+        let mut chain = AuthorityChain::new();
+        for key in [&skull, &master, &repo] {
+            chain.add_key(key.clone()).unwrap();
+        }
+        chain
+            .add_authority_relationship(skull.fingerprint(), master.fingerprint())
+            .unwrap();
+        chain
+            .add_authority_relationship(master.fingerprint(), repo.fingerprint())
+            .unwrap();
+
+        record("create", &[skull.fingerprint()], AuditOutcome::Success, "").unwrap();
+        record("create", &[master.fingerprint()], AuditOutcome::Success, "").unwrap();
+        record(
+            "save_key",
+            &[repo.fingerprint()],
+            AuditOutcome::Failure,
+            "disk full",
+        )
+        .unwrap();
+        log_event("unrelated");
+
+        // A Master prefix reaches its Repo descendant but not the Skull above it
+        let prefix = &master.fingerprint().hex()[..8];
+        let tree = AuditQuery::default().with_key_tree(prefix, &chain).unwrap();
+        let operations: Vec<String> = query(&tree)
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.operation)
+            .collect();
+        assert_eq!(operations, ["create", "save_key"]);
+
+        let failures = AuditQuery {
+            outcome: Some(AuditOutcome::Failure),
+            ..tree
+        };
+        assert_eq!(query(&failures).unwrap()[0].message, "disk full");
+
+        let now = Utc::now();
+        let creates = AuditQuery {
+            operation: Some("create".to_string()),
+            since: Some(parse_time("1h", now).unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(query(&creates).unwrap().len(), 2);
+        let future = AuditQuery {
+            since: Some(parse_time("2999-01-01", now).unwrap()),
+            ..Default::default()
+        };
+        assert!(query(&future).unwrap().is_empty());
+        assert!(parse_time("7x", now).is_err());
+        assert!(AuditQuery::default().with_key_tree("zz", &chain).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_entries_carry_the_running_operation_id() {
+        let _env = VaultEnvironment::new();
+
+        log_event("outside any operation");
+        let operation = correlation::begin();
+        record("create", &[], AuditOutcome::Success, "").unwrap();
+        log_event("same operation");
+
+        let by_id = AuditQuery {
+            operation_id: Some(operation.id()),
+            ..Default::default()
+        };
+        let entries = query(&by_id).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].operation, "create");
+        assert!(query(&AuditQuery::default()).unwrap()[0]
+            .operation_id
+            .is_none());
+    }
 }
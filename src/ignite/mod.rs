@@ -12,7 +12,11 @@ pub mod utils;
 
 pub mod authority;
 pub mod cli;
+pub mod correlation;
 pub mod logging;
 pub mod security;
 
+#[cfg(test)]
+pub(crate) mod test_support;
+
 pub use error::{IgniteError, Result};
@@ -0,0 +1,214 @@
+//! Compact breached-password filter.
+//!
+//! A breach corpus (one password per line, often tens of millions of entries)
+//! is compiled once into a Bloom filter and installed at
+//! `metadata/breach_filter.bin`. Lookups hash the NFKC-normalized passphrase,
+//! so the filter never reveals corpus entries and can report false positives
+//! at the configured rate but never false negatives.
+//!
+//! File layout: `IGBF` magic, format version (u8), hash count (u8), bit count
+//! (u64, little-endian), then the bitmap.
+
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+use super::strength;
+use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::utils;
+
+const MAGIC: &[u8; 4] = b"IGBF";
+const FORMAT_VERSION: u8 = 1;
+const HEADER_LEN: usize = 14;
+
+/// False-positive rate used when building a filter without an explicit one
+pub const DEFAULT_FALSE_POSITIVE_RATE: f64 = 0.001;
+
+/// Bloom filter over normalized breached passwords
+#[derive(Clone, PartialEq, Eq)]
+pub struct BreachFilter {
+    bits: Vec<u8>,
+    bit_count: u64,
+    hashes: u8,
+}
+
+impl fmt::Debug for BreachFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BreachFilter")
+            .field("bit_count", &self.bit_count)
+            .field("hashes", &self.hashes)
+            .finish()
+    }
+}
+
+impl BreachFilter {
+    /// Empty filter sized for `entries` at `false_positive_rate`
+    pub fn with_capacity(entries: usize, false_positive_rate: f64) -> Self {
+        let entries = entries.max(1) as f64;
+        let rate = false_positive_rate.clamp(1e-9, 0.5);
+        let ln2 = std::f64::consts::LN_2;
+
+        let bit_count = (-(entries * rate.ln()) / (ln2 * ln2)).ceil().max(8.0) as u64;
+        let hashes = ((bit_count as f64 / entries) * ln2)
+            .round()
+            .clamp(1.0, 32.0) as u8;
+        Self {
+            bits: vec![0; bit_count.div_ceil(8) as usize],
+            bit_count,
+            hashes,
+        }
+    }
+
+    /// Build a filter from a corpus file with one password per line
+    pub fn from_corpus(path: &Path, false_positive_rate: f64) -> Result<Self> {
+        let entries = corpus_lines(path)?.count();
+        let mut filter = Self::with_capacity(entries, false_positive_rate);
+        for line in corpus_lines(path)? {
+            filter.insert(&line?);
+        }
+        Ok(filter)
+    }
+
+    pub fn insert(&mut self, passphrase: &str) {
+        let positions: Vec<u64> = self.positions(passphrase).collect();
+        for bit in positions {
+            self.bits[(bit / 8) as usize] |= 1 << (bit % 8);
+        }
+    }
+
+    /// True if `passphrase` is (probably) in the corpus
+    pub fn contains(&self, passphrase: &str) -> bool {
+        self.positions(passphrase)
+            .all(|bit| self.bits[(bit / 8) as usize] & (1 << (bit % 8)) != 0)
+    }
+
+    fn positions(&self, passphrase: &str) -> impl Iterator<Item = u64> + '_ {
+        let digest = Sha256::digest(strength::normalize(passphrase).as_bytes());
+        let word = |i: usize| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&digest[i..i + 8]);
+            u64::from_le_bytes(bytes)
+        };
+        let (h1, h2) = (word(0), word(8) | 1);
+        (0..self.hashes as u64).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % self.bit_count)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.bits.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(FORMAT_VERSION);
+        bytes.push(self.hashes);
+        bytes.extend_from_slice(&self.bit_count.to_le_bytes());
+        bytes.extend_from_slice(&self.bits);
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let invalid = |reason: &str| IgniteError::InvalidOperation {
+            operation: "load_breach_filter".to_string(),
+            reason: reason.to_string(),
+        };
+        if bytes.len() < HEADER_LEN || &bytes[0..4] != MAGIC {
+            return Err(invalid("not a breach filter file"));
+        }
+        if bytes[4] != FORMAT_VERSION {
+            return Err(invalid(&format!(
+                "unsupported breach filter version {}",
+                bytes[4]
+            )));
+        }
+
+        let hashes = bytes[5];
+        let mut count = [0u8; 8];
+        count.copy_from_slice(&bytes[6..HEADER_LEN]);
+        let bit_count = u64::from_le_bytes(count);
+        let bits = bytes[HEADER_LEN..].to_vec();
+        if hashes == 0 || bit_count == 0 || bits.len() as u64 != bit_count.div_ceil(8) {
+            return Err(invalid("breach filter header does not match its bitmap"));
+        }
+
+        Ok(Self {
+            bits,
+            bit_count,
+            hashes,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| IgniteError::io_error("create_dir", parent.to_path_buf(), e))?;
+        }
+        fs::write(path, self.to_bytes())
+            .map_err(|e| IgniteError::io_error("write_breach_filter", path.to_path_buf(), e))
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let bytes = fs::read(path)
+            .map_err(|e| IgniteError::io_error("read_breach_filter", path.to_path_buf(), e))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Filter installed in the vault, if any
+    pub fn load_installed() -> Result<Option<Self>> {
+        let path = installed_path();
+        if !path.exists() {
+            return Ok(None);
+        }
+        Self::load(&path).map(Some)
+    }
+}
+
+/// Where `PolicyEngine::with_defaults` looks for a breach filter
+pub fn installed_path() -> PathBuf {
+    utils::breach_filter_path()
+}
+
+fn corpus_lines(path: &Path) -> Result<impl Iterator<Item = Result<String>> + '_> {
+    let file = File::open(path)
+        .map_err(|e| IgniteError::io_error("read_corpus", path.to_path_buf(), e))?;
+    Ok(BufReader::new(file)
+        .lines()
+        .map(move |line| {
+            line.map_err(|e| IgniteError::io_error("read_corpus", path.to_path_buf(), e))
+        })
+        .filter(|line| !matches!(line, Ok(l) if l.is_empty())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_round_trip() {
+        let mut filter = BreachFilter::with_capacity(100, DEFAULT_FALSE_POSITIVE_RATE);
+        for entry in ["Summer2019!", "Tr0ub4dor&3", "ｃｏｍｐａｎｙ123"] {
+            filter.insert(entry);
+        }
+
+        let restored = BreachFilter::from_bytes(&filter.to_bytes()).unwrap();
+        assert_eq!(restored, filter);
+        assert!(restored.contains("Summer2019!"));
+        assert!(restored.contains("company123"));
+        assert!(!restored.contains("Staple-Battery-17-Horse"));
+
+        let mut corrupt = filter.to_bytes();
+        corrupt.truncate(HEADER_LEN + 1);
+        assert!(BreachFilter::from_bytes(&corrupt).is_err());
+    }
+
+    #[test]
+    fn test_filter_from_corpus() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let corpus = dir.path().join("corpus.txt");
+        fs::write(&corpus, "hunter2\n\niloveyou1\nTr0ub4dor&3\n").unwrap();
+
+        let filter = BreachFilter::from_corpus(&corpus, 0.01).unwrap();
+        assert!(filter.contains("iloveyou1"));
+        assert!(filter.contains("Tr0ub4dor&3"));
+        assert!(!filter.contains("Correct-Horse-42-Battery"));
+    }
+}
@@ -0,0 +1,236 @@
+//! Danger-mode guards for destructive operations.
+//!
+//! Rotating or revoking Skull and Master keys, purging proof archives,
+//! overwriting stored keys and changing a seeded approver registry cannot be
+//! undone, so they pass through layered
+//! confirmation before anything is touched:
+//!   1. `--danger-mode` on the command line
+//!   2. `DANGER_MODE=1` in the environment
+//!   3. the target's confirmation phrase (a key's short fingerprint) typed
+//!      at a terminal, or `--i-am-sure` for non-interactive automation
+//!
+//! Every attempt is recorded in the audit log, whether it is granted or not.
+
+use std::env;
+use std::fmt;
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use crate::ignite::authority::KeyType;
+use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::logging::{self, AuditOutcome};
+
+/// Environment variable that must be `1` for any dangerous operation
+pub const DANGER_ENV: &str = "DANGER_MODE";
+
+/// Operations the guard protects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DangerousOperation {
+    RotateKey,
+    RevokeKey,
+    PurgeArchive,
+    OverwriteKey,
+    ModifyApprovers,
+}
+
+impl DangerousOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DangerousOperation::RotateKey => "rotate_key",
+            DangerousOperation::RevokeKey => "revoke_key",
+            DangerousOperation::PurgeArchive => "purge_archive",
+            DangerousOperation::OverwriteKey => "overwrite_key",
+            DangerousOperation::ModifyApprovers => "modify_approvers",
+        }
+    }
+
+    /// Whether rotating, revoking or overwriting a `key_type` key is guarded
+    pub fn guards_key(key_type: KeyType) -> bool {
+        matches!(key_type, KeyType::Skull | KeyType::Master)
+    }
+}
+
+impl fmt::Display for DangerousOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The confirmations supplied for one invocation
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DangerGuard {
+    pub danger_mode: bool,
+    pub env_danger: bool,
+    pub i_am_sure: bool,
+}
+
+impl DangerGuard {
+    /// Guard from command-line flags, reading `DANGER_MODE` from the environment
+    pub fn new(danger_mode: bool, i_am_sure: bool) -> Self {
+        Self {
+            danger_mode,
+            env_danger: env::var(DANGER_ENV).is_ok_and(|value| value == "1"),
+            i_am_sure,
+        }
+    }
+
+    /// Allow `operation` on `target` once every layer is satisfied
+    ///
+    /// `confirm` shows its prompt and returns what the operator typed; it is
+    /// only called when `--i-am-sure` is absent. The attempt is audited
+    /// either way, and an override that cannot be audited is not granted.
+    pub fn authorize<F>(
+        &self,
+        operation: DangerousOperation,
+        target: &str,
+        phrase: &str,
+        confirm: F,
+    ) -> Result<()>
+    where
+        F: FnOnce(&str) -> io::Result<String>,
+    {
+        let result = self.check(operation, target, phrase, confirm);
+        let audited = logging::record(
+            "danger_override",
+            &[],
+            match result {
+                Ok(()) => AuditOutcome::Success,
+                Err(_) => AuditOutcome::Refused,
+            },
+            &format!(
+                "{} on {}{} (danger-mode={}, {}={}, i-am-sure={})",
+                operation,
+                target,
+                result
+                    .as_ref()
+                    .err()
+                    .map(|reason| format!(": {}", reason))
+                    .unwrap_or_default(),
+                self.danger_mode,
+                DANGER_ENV,
+                self.env_danger,
+                self.i_am_sure
+            ),
+        );
+
+        result.map_err(|reason| IgniteError::DangerRefused {
+            operation: operation.to_string(),
+            reason,
+        })?;
+        audited
+    }
+
+    fn check<F>(
+        &self,
+        operation: DangerousOperation,
+        target: &str,
+        phrase: &str,
+        confirm: F,
+    ) -> std::result::Result<(), String>
+    where
+        F: FnOnce(&str) -> io::Result<String>,
+    {
+        if !self.danger_mode {
+            return Err(format!(
+                "{} on {} requires --danger-mode",
+                operation, target
+            ));
+        }
+        if !self.env_danger {
+            return Err(format!("--danger-mode requires {}=1", DANGER_ENV));
+        }
+        if self.i_am_sure {
+            return Ok(());
+        }
+
+        let prompt = format!(
+            "⚠️  DANGER: {} on {} cannot be undone.\nType '{}' to confirm: ",
+            operation, target, phrase
+        );
+        let typed = confirm(&prompt).map_err(|e| format!("confirmation failed: {}", e))?;
+        if typed.trim() != phrase {
+            return Err("confirmation did not match".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Prompt on stderr and read one line from a terminal stdin
+pub fn read_confirmation(prompt: &str) -> io::Result<String> {
+    let stdin = io::stdin();
+    if !stdin.is_terminal() {
+        return Err(io::Error::other(
+            "stdin is not a terminal; pass --i-am-sure for automation",
+        ));
+    }
+
+    eprint!("{}", prompt);
+    io::stderr().flush()?;
+    let mut line = String::new();
+    stdin.lock().read_line(&mut line)?;
+    Ok(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ignite::test_support::VaultEnvironment;
+    use crate::ignite::utils;
+    use serial_test::serial;
+
+    const TARGET: &str = "Skull 9ff0bae5";
+    const PHRASE: &str = "9ff0bae5";
+
+    fn guard(danger_mode: bool, env_danger: bool, i_am_sure: bool) -> DangerGuard {
+        DangerGuard {
+            danger_mode,
+            env_danger,
+            i_am_sure,
+        }
+    }
+
+    fn typed(text: &'static str) -> impl FnOnce(&str) -> io::Result<String> {
+        move |_| Ok(text.to_string())
+    }
+
+    #[test]
+    #[serial]
+    fn test_every_layer_is_required() {
+        let _env = VaultEnvironment::new();
+        let operation = DangerousOperation::RevokeKey;
+
+        // Flag and environment are both required, even with --i-am-sure
+        assert!(guard(false, true, true)
+            .authorize(operation, TARGET, PHRASE, typed(PHRASE))
+            .is_err());
+        assert!(guard(true, false, true)
+            .authorize(operation, TARGET, PHRASE, typed(PHRASE))
+            .is_err());
+
+        // Interactive confirmation must match the phrase
+        assert!(guard(true, true, false)
+            .authorize(operation, TARGET, PHRASE, typed("9ff0bae4\n"))
+            .is_err());
+        assert!(guard(true, true, false)
+            .authorize(operation, TARGET, PHRASE, typed("9ff0bae5\n"))
+            .is_ok());
+
+        // --i-am-sure skips the prompt entirely
+        let result = guard(true, true, true).authorize(operation, TARGET, PHRASE, |_| {
+            panic!("prompted despite --i-am-sure")
+        });
+        assert!(matches!(result, Ok(())));
+
+        // Every attempt is audited, refused or granted
+        let log = std::fs::read_to_string(utils::audit_log_path()).unwrap();
+        assert_eq!(log.matches(r#""operation":"danger_override""#).count(), 5);
+        assert_eq!(log.matches(r#""outcome":"success""#).count(), 2);
+        assert!(DangerousOperation::guards_key(KeyType::Master));
+        assert!(!DangerousOperation::guards_key(KeyType::Repo));
+
+        // An override that cannot be recorded is not granted
+        std::fs::write(utils::audit_log_path(), format!("{}not an entry\n", log)).unwrap();
+        assert!(guard(true, true, true)
+            .authorize(operation, TARGET, PHRASE, typed(PHRASE))
+            .is_err());
+    }
+}
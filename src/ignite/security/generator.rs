@@ -0,0 +1,109 @@
+//! Memorable passphrase generator.
+//!
+//! Diceware-style successor to `_generate_ignition_key` in the legacy
+//! `padlock.sh`: words are drawn uniformly from the 2048-word list shared with
+//! `strength` (11 bits each) and joined with `-`. Every candidate is checked
+//! against the caller's `PolicyEngine`, so a returned passphrase always
+//! satisfies the active policy.
+
+use hub::random_ext::rand::{rng, Rng};
+use zeroize::Zeroizing;
+
+use super::policy::PolicyEngine;
+use super::strength::WORDLIST;
+use crate::ignite::authority::KeyType;
+use crate::ignite::error::{IgniteError, Result};
+
+/// Word count used when the caller does not ask for one
+pub const DEFAULT_WORD_COUNT: usize = 6;
+
+const SEPARATOR: &str = "-";
+const MAX_ATTEMPTS: usize = 16;
+
+/// A generated passphrase and the entropy of the process that produced it
+#[derive(Debug, Clone)]
+pub struct GeneratedPassphrase {
+    value: Zeroizing<String>,
+    pub words: usize,
+    /// `words * log2(wordlist size)`, the true entropy of the draw
+    pub entropy_bits: f64,
+}
+
+impl GeneratedPassphrase {
+    pub fn expose(&self) -> &str {
+        &self.value
+    }
+}
+
+/// `words` random wordlist entries joined with `-`
+pub fn generate(words: usize) -> GeneratedPassphrase {
+    let list: Vec<&str> = WORDLIST.lines().collect();
+    let mut random = rng();
+    let value = (0..words)
+        .map(|_| list[random.random_range(0..list.len())])
+        .collect::<Vec<_>>()
+        .join(SEPARATOR);
+
+    GeneratedPassphrase {
+        value: Zeroizing::new(value),
+        words,
+        entropy_bits: words as f64 * (list.len() as f64).log2(),
+    }
+}
+
+/// Generate a passphrase of `words` words that passes `policy_engine` for `key_type`
+pub fn generate_for_policy(
+    policy_engine: &PolicyEngine,
+    key_type: KeyType,
+    words: usize,
+) -> Result<GeneratedPassphrase> {
+    if words == 0 {
+        return Err(IgniteError::InvalidOperation {
+            operation: "generate_passphrase".to_string(),
+            reason: "Word count must be at least 1".to_string(),
+        });
+    }
+
+    let mut last_error = None;
+    for _ in 0..MAX_ATTEMPTS {
+        let candidate = generate(words);
+        match policy_engine.validate_passphrase(key_type, candidate.expose()) {
+            Ok(_) => return Ok(candidate),
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    Err(IgniteError::InvalidOperation {
+        operation: "generate_passphrase".to_string(),
+        reason: format!(
+            "No {}-word passphrase satisfied the policy after {} attempts ({}); try more words",
+            words,
+            MAX_ATTEMPTS,
+            last_error.map(|e| e.to_string()).unwrap_or_default()
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_passphrases_satisfy_policy() {
+        let engine = PolicyEngine::with_defaults();
+        let generated =
+            generate_for_policy(&engine, KeyType::Ignition, DEFAULT_WORD_COUNT).unwrap();
+
+        assert_eq!(
+            generated.expose().split(SEPARATOR).count(),
+            DEFAULT_WORD_COUNT
+        );
+        assert_eq!(generated.entropy_bits, 66.0);
+        assert!(engine
+            .validate_passphrase(KeyType::Distro, generated.expose())
+            .is_ok());
+
+        // Two words can never reach the default entropy floor
+        assert!(generate_for_policy(&engine, KeyType::Ignition, 2).is_err());
+    }
+}
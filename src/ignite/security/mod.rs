@@ -8,6 +8,22 @@
 //!
 //! See `policy` module for the modular policy engine implementation.
 
+pub mod breach;
+pub mod danger;
+pub mod generator;
+pub mod passphrase;
 pub mod policy;
+pub mod policy_config;
+pub mod shamir;
+pub mod strength;
 
-pub use policy::{ExpirationPolicy, PassphraseStrengthPolicy, Policy, PolicyEngine};
+pub use breach::BreachFilter;
+pub use danger::{DangerGuard, DangerousOperation};
+pub use generator::GeneratedPassphrase;
+pub use passphrase::{PassphraseProvider, PassphraseRequest, PassphraseSource};
+pub use policy::{
+    ExpirationPolicy, ExplainStep, Outcome, PassphraseReusePolicy, PassphraseStrengthPolicy, Phase,
+    Policy, PolicyEngine, PolicyExplanation, PolicyReport, Verdict,
+};
+pub use policy_config::PolicyConfig;
+pub use strength::StrengthEstimate;
@@ -0,0 +1,501 @@
+//! Passphrase sourcing for ignition keys.
+//!
+//! `PassphraseProvider` is the single place create, unlock and rotate obtain
+//! passphrases from, and where the vault passphrase that seals private key
+//! material at rest comes from. Sources are tried in order:
+//!   * an explicitly configured file descriptor, stdin or helper command
+//!   * `PADLOCK_<FP>_PASSPHRASE` for the key's short fingerprint
+//!   * `IGNITE_PASSPHRASE`
+//!   * an interactive no-echo prompt, when enabled and attached to a terminal
+//!
+//! Vault requests (`seal`/`unseal`) read `IGNITE_VAULT_PASSPHRASE` in place of
+//! the two key variables.
+//!
+//! Passphrases are never taken from command-line arguments. Every successful
+//! lookup records its source (never the passphrase) through `logging::record`,
+//! and a lookup that cannot be recorded fails.
+
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, IsTerminal, Read};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+
+use zeroize::Zeroizing;
+
+use crate::ignite::authority::{KeyFingerprint, KeyType};
+use crate::ignite::correlation;
+use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::logging::{self, AuditOutcome};
+
+/// Fallback environment variable when no per-key variable is set
+pub const PASSPHRASE_ENV: &str = "IGNITE_PASSPHRASE";
+
+/// Environment variable holding the vault passphrase
+pub const VAULT_PASSPHRASE_ENV: &str = "IGNITE_VAULT_PASSPHRASE";
+
+/// Environment variable naming a default helper command
+pub const PASSPHRASE_HELPER_ENV: &str = "IGNITE_PASSPHRASE_HELPER";
+
+/// Per-key environment variable, e.g. `PADLOCK_A1B2C3D4_PASSPHRASE`
+pub fn key_env_var(fingerprint: &KeyFingerprint) -> String {
+    format!("PADLOCK_{}_PASSPHRASE", fingerprint.short().to_uppercase())
+}
+
+/// Why a passphrase is needed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PassphrasePurpose {
+    /// Unlock an existing wrapped key
+    Unlock,
+    /// Wrap a newly minted key; prompts ask for confirmation
+    New,
+    /// Seal private key material under the vault passphrase; prompts confirm
+    Seal,
+    /// Open sealed private key material
+    Unseal,
+}
+
+impl PassphrasePurpose {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PassphrasePurpose::Unlock => "unlock",
+            PassphrasePurpose::New => "new",
+            PassphrasePurpose::Seal => "seal",
+            PassphrasePurpose::Unseal => "unseal",
+        }
+    }
+
+    /// Whether the vault passphrase, rather than a key's own, is wanted
+    pub fn is_vault(&self) -> bool {
+        matches!(self, PassphrasePurpose::Seal | PassphrasePurpose::Unseal)
+    }
+}
+
+/// The key a passphrase is requested for
+///
+/// Vault requests carry no key type: one vault passphrase seals every key.
+#[derive(Debug, Clone, Copy)]
+pub struct PassphraseRequest<'a> {
+    pub key_type: Option<KeyType>,
+    pub fingerprint: Option<&'a KeyFingerprint>,
+    pub purpose: PassphrasePurpose,
+}
+
+impl<'a> PassphraseRequest<'a> {
+    pub fn unlock(key_type: KeyType, fingerprint: &'a KeyFingerprint) -> Self {
+        Self {
+            key_type: Some(key_type),
+            fingerprint: Some(fingerprint),
+            purpose: PassphrasePurpose::Unlock,
+        }
+    }
+
+    pub fn new_key(key_type: KeyType, fingerprint: Option<&'a KeyFingerprint>) -> Self {
+        Self {
+            key_type: Some(key_type),
+            fingerprint,
+            purpose: PassphrasePurpose::New,
+        }
+    }
+
+    /// Vault passphrase to seal `fingerprint`'s private key before it is written
+    pub fn seal(fingerprint: &'a KeyFingerprint) -> Self {
+        Self {
+            key_type: None,
+            fingerprint: Some(fingerprint),
+            purpose: PassphrasePurpose::Seal,
+        }
+    }
+
+    /// Vault passphrase to open `fingerprint`'s sealed private key
+    pub fn unseal(fingerprint: &'a KeyFingerprint) -> Self {
+        Self {
+            key_type: None,
+            fingerprint: Some(fingerprint),
+            purpose: PassphrasePurpose::Unseal,
+        }
+    }
+
+    fn describe(&self) -> String {
+        match (self.key_type, self.fingerprint) {
+            (Some(key_type), Some(fp)) => format!("{} key {}", key_type, fp.short()),
+            (Some(key_type), None) => format!("new {} key", key_type),
+            (None, Some(fp)) => format!("vault (key {})", fp.short()),
+            (None, None) => "vault".to_string(),
+        }
+    }
+}
+
+/// Where a passphrase was read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PassphraseSource {
+    Fd(u32),
+    Stdin,
+    Helper(String),
+    Env(String),
+    Prompt,
+}
+
+impl fmt::Display for PassphraseSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PassphraseSource::Fd(fd) => write!(f, "fd:{}", fd),
+            PassphraseSource::Stdin => write!(f, "stdin"),
+            PassphraseSource::Helper(command) => write!(f, "helper:{}", command),
+            PassphraseSource::Env(var) => write!(f, "env:{}", var),
+            PassphraseSource::Prompt => write!(f, "prompt"),
+        }
+    }
+}
+
+/// A passphrase together with the source it came from
+pub struct Passphrase {
+    value: Zeroizing<String>,
+    pub source: PassphraseSource,
+}
+
+impl Passphrase {
+    pub fn expose(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Debug for Passphrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Passphrase")
+            .field("source", &self.source)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Resolves passphrases from the configured sources
+#[derive(Debug, Clone, Default)]
+pub struct PassphraseProvider {
+    fd: Option<u32>,
+    /// Descriptor opened on first use, so later reads continue where it left off
+    fd_stream: Arc<Mutex<Option<File>>>,
+    stdin: bool,
+    helper: Option<String>,
+    interactive: bool,
+}
+
+impl PassphraseProvider {
+    /// Environment-only provider; no prompt, no fd, no stdin
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Provider honouring `IGNITE_PASSPHRASE_HELPER` when it is set
+    pub fn from_env() -> Self {
+        let helper = env::var(PASSPHRASE_HELPER_ENV)
+            .ok()
+            .filter(|command| !command.trim().is_empty());
+        Self {
+            helper,
+            ..Self::default()
+        }
+    }
+
+    /// Read one passphrase per line from file descriptor `fd`
+    pub fn with_fd(mut self, fd: u32) -> Self {
+        self.fd = Some(fd);
+        self
+    }
+
+    /// Read one passphrase per line from stdin
+    pub fn with_stdin(mut self) -> Self {
+        self.stdin = true;
+        self
+    }
+
+    /// Run `command` through `sh -c` and use the first line of its stdout
+    pub fn with_helper(mut self, command: impl Into<String>) -> Self {
+        self.helper = Some(command.into());
+        self
+    }
+
+    /// Allow falling back to a terminal prompt
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// Obtain a passphrase for `request` and audit which source supplied it
+    pub fn obtain(&self, request: &PassphraseRequest<'_>) -> Result<Passphrase> {
+        let passphrase = self.resolve(request)?;
+        logging::record(
+            "passphrase",
+            &request.fingerprint.into_iter().collect::<Vec<_>>(),
+            AuditOutcome::Info,
+            &format!(
+                "passphrase {} for {} sourced from {}",
+                request.purpose.as_str(),
+                request.describe(),
+                passphrase.source
+            ),
+        )?;
+        Ok(passphrase)
+    }
+
+    fn resolve(&self, request: &PassphraseRequest<'_>) -> Result<Passphrase> {
+        if let Some(fd) = self.fd {
+            let mut stream = self
+                .fd_stream
+                .lock()
+                .map_err(|_| source_error("passphrase fd lock poisoned"))?;
+            let file = match stream.as_mut() {
+                Some(file) => file,
+                None => stream.insert(
+                    File::open(format!("/dev/fd/{}", fd))
+                        .map_err(|e| source_error(format!("cannot open fd {}: {}", fd, e)))?,
+                ),
+            };
+            return read_line(file).map(|value| Passphrase {
+                value,
+                source: PassphraseSource::Fd(fd),
+            });
+        }
+        if self.stdin {
+            return read_line(io::stdin().lock()).map(|value| Passphrase {
+                value,
+                source: PassphraseSource::Stdin,
+            });
+        }
+        if let Some(command) = &self.helper {
+            return run_helper(command, request).map(|value| Passphrase {
+                value,
+                source: PassphraseSource::Helper(command.clone()),
+            });
+        }
+
+        let key_var = request.fingerprint.map(key_env_var);
+        let env_vars: Vec<&str> = if request.purpose.is_vault() {
+            vec![VAULT_PASSPHRASE_ENV]
+        } else {
+            key_var
+                .iter()
+                .map(String::as_str)
+                .chain([PASSPHRASE_ENV])
+                .collect()
+        };
+        for var in env_vars {
+            if let Some(value) = env::var(var).ok().filter(|value| !value.is_empty()) {
+                return Ok(Passphrase {
+                    value: Zeroizing::new(value),
+                    source: PassphraseSource::Env(var.to_string()),
+                });
+            }
+        }
+
+        if self.interactive && io::stdin().is_terminal() {
+            return prompt(request).map(|value| Passphrase {
+                value,
+                source: PassphraseSource::Prompt,
+            });
+        }
+
+        if request.purpose.is_vault() {
+            return Err(source_error(format!(
+                "no vault passphrase available for {}; set {} or use --passphrase-helper",
+                request.describe(),
+                VAULT_PASSPHRASE_ENV
+            )));
+        }
+        Err(source_error(format!(
+            "no passphrase available for {}; set {} or {}, or use --passphrase-fd, --passphrase-stdin or --passphrase-helper",
+            request.describe(),
+            key_var.as_deref().unwrap_or("PADLOCK_<FP>_PASSPHRASE"),
+            PASSPHRASE_ENV
+        )))
+    }
+}
+
+/// Read a single line without buffering past it, so later requests on the
+/// same stream get the following line
+fn read_line(mut reader: impl Read) -> Result<Zeroizing<String>> {
+    let mut bytes = Zeroizing::new(Vec::new());
+    let mut byte = [0u8; 1];
+    loop {
+        match reader.read(&mut byte) {
+            Ok(0) => break,
+            Ok(_) if byte[0] == b'\n' => break,
+            Ok(_) => bytes.push(byte[0]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(source_error(format!("read failed: {}", e))),
+        }
+    }
+    if bytes.last() == Some(&b'\r') {
+        bytes.pop();
+    }
+
+    let value = String::from_utf8(bytes.to_vec())
+        .map_err(|_| source_error("passphrase is not valid UTF-8"))?;
+    if value.is_empty() {
+        return Err(source_error("empty passphrase"));
+    }
+    Ok(Zeroizing::new(value))
+}
+
+fn run_helper(command: &str, request: &PassphraseRequest<'_>) -> Result<Zeroizing<String>> {
+    let output = correlation::export(&mut Command::new("sh"))
+        .arg("-c")
+        .arg(command)
+        .env(
+            "IGNITE_PASSPHRASE_KEY_TYPE",
+            request
+                .key_type
+                .map(|key_type| key_type.to_string())
+                .unwrap_or_default(),
+        )
+        .env(
+            "IGNITE_PASSPHRASE_FINGERPRINT",
+            request
+                .fingerprint
+                .map(|fp| fp.to_string())
+                .unwrap_or_default(),
+        )
+        .env("IGNITE_PASSPHRASE_PURPOSE", request.purpose.as_str())
+        .stdin(Stdio::null())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| source_error(format!("failed to run helper: {}", e)))?;
+
+    let stdout = Zeroizing::new(output.stdout);
+    if !output.status.success() {
+        return Err(source_error(format!(
+            "helper exited with {}",
+            output.status
+        )));
+    }
+    read_line(stdout.as_slice())
+}
+
+fn prompt(request: &PassphraseRequest<'_>) -> Result<Zeroizing<String>> {
+    let ask = |message: String| {
+        rpassword::prompt_password(message)
+            .map(Zeroizing::new)
+            .map_err(|e| source_error(format!("prompt failed: {}", e)))
+    };
+
+    let value = ask(format!("Passphrase for {}: ", request.describe()))?;
+    if value.is_empty() {
+        return Err(source_error("empty passphrase"));
+    }
+    if matches!(
+        request.purpose,
+        PassphrasePurpose::New | PassphrasePurpose::Seal
+    ) {
+        let confirmation = ask("Confirm passphrase: ".to_string())?;
+        if *confirmation != *value {
+            return Err(source_error("passphrases do not match"));
+        }
+    }
+    Ok(value)
+}
+
+fn source_error(reason: impl Into<String>) -> IgniteError {
+    IgniteError::InvalidOperation {
+        operation: "obtain_passphrase".to_string(),
+        reason: reason.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ignite::test_support::VaultEnvironment;
+    use serial_test::serial;
+
+    fn fingerprint() -> KeyFingerprint {
+        KeyFingerprint::from_key_material(b"passphrase test key").unwrap()
+    }
+
+    #[test]
+    fn test_read_line_takes_one_line() {
+        let mut input: &[u8] = b"first pass\r\nsecond pass\n";
+        assert_eq!(read_line(&mut input).unwrap().as_str(), "first pass");
+        assert_eq!(read_line(&mut input).unwrap().as_str(), "second pass");
+        assert!(read_line(&mut input).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_env_sources_prefer_key_variable() {
+        let _env = VaultEnvironment::new();
+        let fp = fingerprint();
+        let key_var = key_env_var(&fp);
+        let request = PassphraseRequest::unlock(KeyType::Distro, &fp);
+        let provider = PassphraseProvider::new();
+
+        assert!(provider.obtain(&request).is_err());
+
+        env::set_var(PASSPHRASE_ENV, "generic");
+        let passphrase = provider.obtain(&request).unwrap();
+        assert_eq!(passphrase.expose(), "generic");
+        assert_eq!(
+            passphrase.source,
+            PassphraseSource::Env(PASSPHRASE_ENV.to_string())
+        );
+
+        env::set_var(&key_var, "per-key");
+        let passphrase = provider.obtain(&request).unwrap();
+        env::remove_var(&key_var);
+        assert_eq!(passphrase.expose(), "per-key");
+        assert_eq!(passphrase.source, PassphraseSource::Env(key_var));
+
+        // The audit log names the source but never the passphrase
+        let lookups = logging::query(&logging::AuditQuery {
+            operation: Some("passphrase".to_string()),
+            fingerprints: vec![fp.hex().to_string()],
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(lookups.len(), 2);
+        assert!(lookups
+            .iter()
+            .all(|entry| entry.outcome == AuditOutcome::Info));
+        assert!(lookups[1].message.contains("sourced from env:PADLOCK_"));
+        assert!(lookups
+            .iter()
+            .all(|entry| !entry.message.contains("per-key")));
+    }
+
+    #[test]
+    #[serial]
+    fn test_vault_requests_read_only_the_vault_variable() {
+        let _env = VaultEnvironment::new();
+        let fp = fingerprint();
+        let provider = PassphraseProvider::new();
+        env::set_var(PASSPHRASE_ENV, "key passphrase");
+        env::set_var(key_env_var(&fp), "per-key");
+
+        let passphrase = provider.obtain(&PassphraseRequest::unseal(&fp)).unwrap();
+        assert_eq!(
+            passphrase.source,
+            PassphraseSource::Env(VAULT_PASSPHRASE_ENV.to_string())
+        );
+
+        env::remove_var(VAULT_PASSPHRASE_ENV);
+        assert!(provider.obtain(&PassphraseRequest::seal(&fp)).is_err());
+        env::remove_var(key_env_var(&fp));
+    }
+
+    #[test]
+    #[serial]
+    fn test_helper_source() {
+        let _env = VaultEnvironment::new();
+        let request = PassphraseRequest::new_key(KeyType::Ignition, None);
+
+        let provider =
+            PassphraseProvider::new().with_helper("echo \"from-$IGNITE_PASSPHRASE_KEY_TYPE\"");
+        let passphrase = provider.obtain(&request).unwrap();
+        assert_eq!(passphrase.expose(), "from-ignition");
+        assert!(matches!(passphrase.source, PassphraseSource::Helper(_)));
+
+        assert!(PassphraseProvider::new()
+            .with_helper("exit 3")
+            .obtain(&request)
+            .is_err());
+    }
+}
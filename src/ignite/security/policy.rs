@@ -3,23 +3,245 @@
 //! This module provides a composable policy engine that can host multiple
 //! validation policies. Policies participate in three phases:
 //!   * `apply_key_defaults` – mutate key metadata before persistence
-//!   * `validate_key` – enforce key-level invariants (expiration, hierarchy, etc.)
-//!   * `validate_passphrase` – enforce passphrase rules for ignition-wrapped keys
+//!   * `check_key` – judge key-level invariants (expiration, hierarchy, etc.)
+//!   * `check_passphrase` – judge passphrase rules for ignition-wrapped keys
+//!     (`check_key_passphrase` for rules that need the key, such as reuse)
 //!
-//! The engine ships with two default policies:
+//! Checks return `Verdict`s (allow, warn or deny, each with a policy name,
+//! code and message). The engine runs every policy and collects the verdicts
+//! into a `PolicyReport`; `validate_*` fail with all denials at once, while
+//! warnings such as an approaching expiry ride along in the report.
+//!
+//! The engine ships with three default policies:
 //!   * `ExpirationPolicy` – enforces default expiry windows and rejects expired keys
-//!   * `PassphraseStrengthPolicy` – enforces length/diversity/banned-pattern rules
+//!   * `PassphraseStrengthPolicy` – enforces length, entropy and breach-corpus rules
+//!   * `PassphraseReusePolicy` – rejects passphrases recently used in the key's lineage
 //!
-//! Additional policies can be registered at runtime via `PolicyEngine::register_policy`.
+//! `PolicyEngine::load` configures these (and the proof TTLs) from the policy
+//! files described in `policy_config`. Additional policies can be registered
+//! at runtime via `PolicyEngine::register_policy`.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Arc;
 
+use hub::data_ext::serde_json;
 use hub::time_ext::chrono::{DateTime, Duration, Utc};
 
-use crate::ignite::authority::{AuthorityKey, KeyMetadata, KeyType};
+use super::breach::BreachFilter;
+use super::policy_config::{
+    ApprovalConfig, ExpirationConfig, PassphraseConfig, PolicyConfig, TierDays,
+};
+use super::strength;
+use crate::ignite::authority::proofs::PROOF_TTL_HOURS;
+use crate::ignite::authority::renewal::RENEWAL_WINDOW_HOURS;
+use crate::ignite::authority::{storage, AuthorityKey, KeyMetadata, KeyType};
 use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::logging;
+
+/// Result of one policy check, ordered from most to least permissive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Outcome {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl Outcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::Allow => "allow",
+            Outcome::Warn => "warn",
+            Outcome::Deny => "deny",
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A single policy judgement, e.g. `expiration/expiring_soon`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verdict {
+    pub policy: &'static str,
+    pub outcome: Outcome,
+    /// Stable machine-readable reason within the policy
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl Verdict {
+    pub fn allow(policy: &'static str) -> Self {
+        Self {
+            policy,
+            outcome: Outcome::Allow,
+            code: "ok",
+            message: String::new(),
+        }
+    }
+
+    pub fn warn(policy: &'static str, code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            policy,
+            outcome: Outcome::Warn,
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn deny(policy: &'static str, code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            policy,
+            outcome: Outcome::Deny,
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.policy, self.code)?;
+        if !self.message.is_empty() {
+            write!(f, ": {}", self.message)?;
+        }
+        Ok(())
+    }
+}
+
+/// Every verdict from one evaluation, in policy registration order
+#[derive(Debug, Clone, Default)]
+pub struct PolicyReport {
+    pub verdicts: Vec<Verdict>,
+}
+
+impl PolicyReport {
+    /// Most restrictive outcome; `Allow` when no policy objected
+    pub fn outcome(&self) -> Outcome {
+        self.verdicts
+            .iter()
+            .map(|verdict| verdict.outcome)
+            .max()
+            .unwrap_or(Outcome::Allow)
+    }
+
+    pub fn is_denied(&self) -> bool {
+        self.outcome() == Outcome::Deny
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &Verdict> {
+        self.with_outcome(Outcome::Warn)
+    }
+
+    pub fn denials(&self) -> impl Iterator<Item = &Verdict> {
+        self.with_outcome(Outcome::Deny)
+    }
+
+    fn with_outcome(&self, outcome: Outcome) -> impl Iterator<Item = &Verdict> {
+        self.verdicts
+            .iter()
+            .filter(move |verdict| verdict.outcome == outcome)
+    }
+
+    /// `Err` listing every denial, or the report itself (warnings included)
+    pub fn into_result(self, operation: &str) -> Result<Self> {
+        if !self.is_denied() {
+            return Ok(self);
+        }
+        Err(IgniteError::PolicyViolation {
+            operation: operation.to_string(),
+            violations: self.denials().map(|verdict| verdict.to_string()).collect(),
+        })
+    }
+}
+
+/// Stage of policy evaluation a step belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    ApplyKeyDefaults,
+    ValidateKey,
+    ValidatePassphrase,
+}
+
+impl Phase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Phase::ApplyKeyDefaults => "apply_key_defaults",
+            Phase::ValidateKey => "validate_key",
+            Phase::ValidatePassphrase => "validate_passphrase",
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// One policy's result in one phase of a dry run
+#[derive(Debug, Clone)]
+pub struct ExplainStep {
+    pub policy: &'static str,
+    pub phase: Phase,
+    /// `None` when the phase was skipped
+    pub outcome: Option<Outcome>,
+    pub code: &'static str,
+    pub message: String,
+    /// Metadata fields this policy's defaults would set, as `(field, value)`
+    pub defaults: Vec<(String, String)>,
+}
+
+impl ExplainStep {
+    fn from_verdict(phase: Phase, verdict: Verdict) -> Self {
+        Self {
+            policy: verdict.policy,
+            phase,
+            outcome: Some(verdict.outcome),
+            code: verdict.code,
+            message: verdict.message,
+            defaults: Vec::new(),
+        }
+    }
+
+    fn skipped(policy: &'static str, phase: Phase, reason: &str) -> Self {
+        Self {
+            policy,
+            phase,
+            outcome: None,
+            code: "skipped",
+            message: reason.to_string(),
+            defaults: Vec::new(),
+        }
+    }
+}
+
+/// Dry-run evaluation of every policy against one key
+#[derive(Debug, Clone)]
+pub struct PolicyExplanation {
+    /// The key as it would look after `apply_key_defaults`
+    pub key: AuthorityKey,
+    pub steps: Vec<ExplainStep>,
+}
+
+impl PolicyExplanation {
+    /// Most restrictive outcome over the evaluated steps
+    pub fn outcome(&self) -> Outcome {
+        self.steps
+            .iter()
+            .filter_map(|step| step.outcome)
+            .max()
+            .unwrap_or(Outcome::Allow)
+    }
+}
 
 /// Pluggable policy contract.
+///
+/// The `check_*` hooks return this policy's warnings and denials; an empty
+/// list means the policy allows the subject.
 pub trait Policy: Send + Sync {
     fn name(&self) -> &'static str;
 
@@ -27,36 +249,114 @@ pub trait Policy: Send + Sync {
         Ok(())
     }
 
-    fn validate_key(&self, _key: &AuthorityKey) -> Result<()> {
-        Ok(())
+    fn check_key(&self, _key: &AuthorityKey) -> Vec<Verdict> {
+        Vec::new()
     }
 
-    fn validate_passphrase(&self, _key_type: KeyType, _passphrase: &str) -> Result<()> {
-        Ok(())
+    fn check_passphrase(&self, _key_type: KeyType, _passphrase: &str) -> Vec<Verdict> {
+        Vec::new()
+    }
+
+    /// Passphrase checks that need the key itself (e.g. its lineage)
+    fn check_key_passphrase(&self, _key: &AuthorityKey, _passphrase: &str) -> Vec<Verdict> {
+        Vec::new()
+    }
+
+    /// Problems with the policy's own setup, independent of any key or passphrase
+    fn check_setup(&self) -> Vec<Verdict> {
+        Vec::new()
     }
 }
 
 /// Central policy engine.
-#[derive(Default)]
 pub struct PolicyEngine {
     policies: Vec<Box<dyn Policy>>,
+    proof_ttl: Duration,
+    renewal_window: Duration,
+    approvals: ApprovalConfig,
+}
+
+impl Default for PolicyEngine {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl PolicyEngine {
     pub fn new() -> Self {
         Self {
             policies: Vec::new(),
+            proof_ttl: Duration::hours(PROOF_TTL_HOURS),
+            renewal_window: Duration::hours(RENEWAL_WINDOW_HOURS),
+            approvals: ApprovalConfig::default(),
         }
     }
 
-    /// Install the default policy bundle (expiration + passphrase strength).
+    /// Install the default policy bundle (expiration, passphrase strength and reuse).
     pub fn with_defaults() -> Self {
+        Self::from_config(&PolicyConfig::default())
+    }
+
+    /// Engine configured by the policy files in `PolicyConfig::search_paths`
+    pub fn load() -> Result<Self> {
+        Ok(Self::from_config(&PolicyConfig::load()?))
+    }
+
+    /// Register the built-in policies enabled in `config`, with its settings
+    pub fn from_config(config: &PolicyConfig) -> Self {
         let mut engine = Self::new();
-        engine.register_policy(ExpirationPolicy::default());
-        engine.register_policy(PassphraseStrengthPolicy::default());
+        engine.proof_ttl = Duration::hours(config.proofs.ttl_hours.into());
+        engine.renewal_window = Duration::hours(config.proofs.renewal_window_hours.into());
+        engine.approvals = config.approvals.clone();
+
+        if config.policies.expiration {
+            engine.register_policy(ExpirationPolicy::from_config(&config.expiration));
+        }
+        if config.policies.passphrase_strength {
+            engine.register_policy(PassphraseStrengthPolicy::from_config(&config.passphrase));
+        }
+        if config.policies.passphrase_reuse {
+            engine.register_policy(PassphraseReusePolicy::new(config.passphrase.reuse_depth));
+        }
         engine
     }
 
+    /// Lifetime of newly issued authority proofs
+    pub fn proof_ttl(&self) -> Duration {
+        self.proof_ttl
+    }
+
+    /// How close to expiry a proof must be before renewal picks it up
+    pub fn renewal_window(&self) -> Duration {
+        self.renewal_window
+    }
+
+    /// Approvals required before a create, rotate or revoke of a `key_type` key
+    pub fn approval_threshold(&self, key_type: KeyType) -> usize {
+        self.approvals.threshold(key_type)
+    }
+
+    /// Lifetime of operation requests and approvals
+    pub fn approval_ttl(&self) -> Duration {
+        Duration::hours(self.approvals.ttl_hours.into())
+    }
+
+    /// Names of the registered policies, in evaluation order
+    /// Setup problems of every registered policy (e.g. an unreadable breach filter)
+    pub fn check_setup(&self) -> PolicyReport {
+        PolicyReport {
+            verdicts: self
+                .policies
+                .iter()
+                .flat_map(|policy| policy.check_setup())
+                .collect(),
+        }
+    }
+
+    pub fn policy_names(&self) -> Vec<&'static str> {
+        self.policies.iter().map(|policy| policy.name()).collect()
+    }
+
     pub fn register_policy<P>(&mut self, policy: P)
     where
         P: Policy + 'static,
@@ -71,37 +371,170 @@ impl PolicyEngine {
         Ok(())
     }
 
-    pub fn validate_key(&self, key: &AuthorityKey) -> Result<()> {
+    /// Every policy's verdict on `key`
+    pub fn evaluate_key(&self, key: &AuthorityKey) -> PolicyReport {
+        self.collect(|policy| policy.check_key(key))
+    }
+
+    /// Every policy's verdict on a passphrase for a `key_type` key
+    pub fn evaluate_passphrase(&self, key_type: KeyType, passphrase: &str) -> PolicyReport {
+        self.collect(|policy| policy.check_passphrase(key_type, passphrase))
+    }
+
+    /// Type-level and key-level passphrase verdicts for `key`
+    pub fn evaluate_key_passphrase(&self, key: &AuthorityKey, passphrase: &str) -> PolicyReport {
+        self.collect(|policy| {
+            let mut verdicts = policy.check_passphrase(key.key_type(), passphrase);
+            verdicts.extend(policy.check_key_passphrase(key, passphrase));
+            verdicts
+        })
+    }
+
+    /// `evaluate_key`, failing with every denial if any policy denies
+    pub fn validate_key(&self, key: &AuthorityKey) -> Result<PolicyReport> {
+        self.evaluate_key(key).into_result("validate_key")
+    }
+
+    pub fn validate_passphrase(&self, key_type: KeyType, passphrase: &str) -> Result<PolicyReport> {
+        self.evaluate_passphrase(key_type, passphrase)
+            .into_result("validate_passphrase")
+    }
+
+    pub fn validate_key_passphrase(
+        &self,
+        key: &AuthorityKey,
+        passphrase: &str,
+    ) -> Result<PolicyReport> {
+        self.evaluate_key_passphrase(key, passphrase)
+            .into_result("validate_passphrase")
+    }
+
+    /// Run every phase of every policy against a copy of `key`
+    ///
+    /// Nothing is persisted and `key` is left untouched, so this works for
+    /// stored keys and for keys a create or rotate would mint. The passphrase
+    /// phase is skipped when no `passphrase` is supplied.
+    pub fn explain_key(&self, key: &AuthorityKey, passphrase: Option<&str>) -> PolicyExplanation {
+        let mut subject = key.clone();
+        let mut steps = Vec::new();
+
         for policy in &self.policies {
-            policy.validate_key(key)?;
+            let before = metadata_fields(&subject);
+            let step = match policy.apply_key_defaults(&mut subject) {
+                Ok(()) => {
+                    let after = metadata_fields(&subject);
+                    let defaults: Vec<(String, String)> = after
+                        .into_iter()
+                        .filter(|(field, value)| before.get(field) != Some(value))
+                        .collect();
+                    ExplainStep {
+                        defaults,
+                        ..ExplainStep::from_verdict(
+                            Phase::ApplyKeyDefaults,
+                            Verdict::allow(policy.name()),
+                        )
+                    }
+                }
+                Err(e) => ExplainStep::from_verdict(
+                    Phase::ApplyKeyDefaults,
+                    Verdict::deny(policy.name(), "error", e.to_string()),
+                ),
+            };
+            steps.push(step);
+        }
+
+        steps.extend(
+            self.evaluate_key(&subject)
+                .verdicts
+                .into_iter()
+                .map(|verdict| ExplainStep::from_verdict(Phase::ValidateKey, verdict)),
+        );
+
+        match (subject.key_type().is_ignition_key(), passphrase) {
+            (true, Some(passphrase)) => steps.extend(
+                self.evaluate_key_passphrase(&subject, passphrase)
+                    .verdicts
+                    .into_iter()
+                    .map(|verdict| ExplainStep::from_verdict(Phase::ValidatePassphrase, verdict)),
+            ),
+            (wrapped, _) => {
+                let reason = if wrapped {
+                    "no passphrase supplied".to_string()
+                } else {
+                    format!("{} keys are not passphrase-wrapped", subject.key_type())
+                };
+                steps.extend(self.policies.iter().map(|policy| {
+                    ExplainStep::skipped(policy.name(), Phase::ValidatePassphrase, &reason)
+                }));
+            }
+        }
+
+        PolicyExplanation {
+            key: subject,
+            steps,
         }
-        Ok(())
     }
 
-    pub fn validate_passphrase(&self, key_type: KeyType, passphrase: &str) -> Result<()> {
+    fn collect(&self, check: impl Fn(&dyn Policy) -> Vec<Verdict>) -> PolicyReport {
+        let mut verdicts = Vec::new();
         for policy in &self.policies {
-            policy.validate_passphrase(key_type, passphrase)?;
+            let found = check(policy.as_ref());
+            if found.is_empty() {
+                verdicts.push(Verdict::allow(policy.name()));
+            } else {
+                verdicts.extend(found);
+            }
         }
-        Ok(())
+        PolicyReport { verdicts }
     }
 }
 
+/// Key metadata flattened to `field -> rendered value` for diffing
+fn metadata_fields(key: &AuthorityKey) -> BTreeMap<String, String> {
+    let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(key.metadata()) else {
+        return BTreeMap::new();
+    };
+    fields
+        .into_iter()
+        .map(|(field, value)| {
+            let rendered = match value {
+                serde_json::Value::String(text) => text,
+                other => other.to_string(),
+            };
+            (field, rendered)
+        })
+        .collect()
+}
+
 /// Default expiration policy per key tier.
 #[derive(Debug, Clone)]
 pub struct ExpirationPolicy {
     defaults: HashMap<KeyType, Duration>,
-    warning_fraction: f32,
+    warning_fraction: f64,
+    warning_windows: HashMap<KeyType, Duration>,
 }
 
 impl ExpirationPolicy {
     pub fn new() -> Self {
-        let mut defaults = HashMap::new();
-        defaults.insert(KeyType::Ignition, Duration::days(30));
-        defaults.insert(KeyType::Distro, Duration::days(7));
+        Self::from_config(&ExpirationConfig::default())
+    }
+
+    pub fn from_config(config: &ExpirationConfig) -> Self {
+        let per_tier = |days: &TierDays| -> HashMap<KeyType, Duration> {
+            KeyType::all()
+                .into_iter()
+                .filter_map(|key_type| {
+                    days.get(key_type)
+                        .filter(|days| *days > 0)
+                        .map(|days| (key_type, Duration::days(days.into())))
+                })
+                .collect()
+        };
 
         Self {
-            defaults,
-            warning_fraction: 0.1,
+            defaults: per_tier(&config.days),
+            warning_fraction: config.warning_fraction,
+            warning_windows: per_tier(&config.warning_days),
         }
     }
 
@@ -114,18 +547,26 @@ impl ExpirationPolicy {
             .map(|delta| key.metadata().creation_time + delta)
     }
 
-    fn is_warning(&self, metadata: &KeyMetadata) -> bool {
-        match (metadata.expiration(), self.warning_fraction) {
-            (Some(expiration), fraction) if fraction > 0.0 => {
-                let total = expiration - metadata.creation_time;
-                let total_secs = total.num_seconds();
-                if total_secs <= 0 {
-                    return true;
-                }
-                let warning_secs = ((total_secs as f64) * (fraction.min(1.0) as f64)).max(1.0);
-                let warning_window = Duration::seconds(warning_secs as i64);
-                Utc::now() >= (expiration - warning_window)
-            }
+    fn warning_window(&self, key_type: KeyType, metadata: &KeyMetadata) -> Option<Duration> {
+        if let Some(window) = self.warning_windows.get(&key_type) {
+            return Some(*window);
+        }
+
+        let expiration = metadata.expiration()?;
+        if self.warning_fraction <= 0.0 {
+            return None;
+        }
+        let total_secs = (expiration - metadata.creation_time).num_seconds().max(0);
+        let warning_secs = ((total_secs as f64) * self.warning_fraction.min(1.0)).max(1.0);
+        Some(Duration::seconds(warning_secs as i64))
+    }
+
+    fn is_warning(&self, key_type: KeyType, metadata: &KeyMetadata) -> bool {
+        match (
+            metadata.expiration(),
+            self.warning_window(key_type, metadata),
+        ) {
+            (Some(expiration), Some(window)) => Utc::now() >= expiration - window,
             _ => false,
         }
     }
@@ -151,80 +592,207 @@ impl Policy for ExpirationPolicy {
         Ok(())
     }
 
-    fn validate_key(&self, key: &AuthorityKey) -> Result<()> {
-        if let Some(expiration) = key.metadata().expiration() {
-            if Utc::now() > expiration {
-                return Err(IgniteError::InvalidOperation {
-                    operation: "policy_expiration".to_string(),
-                    reason: format!("Key {} has expired", key.fingerprint()),
-                });
-            }
+    fn check_key(&self, key: &AuthorityKey) -> Vec<Verdict> {
+        let Some(expiration) = key.metadata().expiration() else {
+            return Vec::new();
+        };
 
-            if self.is_warning(key.metadata()) {
-                // TODO: Route warning to logging once audit hooks land.
-            }
+        let now = Utc::now();
+        if now > expiration {
+            return vec![Verdict::deny(
+                self.name(),
+                "expired",
+                format!(
+                    "Key {} expired at {}",
+                    key.fingerprint().short(),
+                    expiration.format("%Y-%m-%d %H:%M:%S UTC")
+                ),
+            )];
+        }
+        if self.is_warning(key.key_type(), key.metadata()) {
+            return vec![Verdict::warn(
+                self.name(),
+                "expiring_soon",
+                format!(
+                    "Key {} expires in {}",
+                    key.fingerprint().short(),
+                    describe_remaining(expiration - now)
+                ),
+            )];
         }
+        Vec::new()
+    }
+}
 
-        Ok(())
+fn describe_remaining(remaining: Duration) -> String {
+    let plural = |n: i64, unit: &str| format!("{} {}{}", n, unit, if n == 1 { "" } else { "s" });
+    if remaining.num_days() >= 1 {
+        plural(remaining.num_days(), "day")
+    } else if remaining.num_hours() >= 1 {
+        plural(remaining.num_hours(), "hour")
+    } else {
+        "less than an hour".to_string()
     }
 }
 
 /// Passphrase strength enforcement policy.
-#[derive(Debug, Clone, Default)]
-pub struct PassphraseStrengthPolicy;
+///
+/// Passphrases are NFKC-normalized and scored by `strength::estimate`; one is
+/// accepted when its length in characters is in range, it contains no control
+/// characters, it is absent from the breach filter (if one is configured) and
+/// its estimated entropy reaches `min_entropy_bits`. An enabled filter that
+/// cannot be read denies every passphrase rather than skipping the check.
+#[derive(Debug, Clone)]
+pub struct PassphraseStrengthPolicy {
+    min_length: usize,
+    max_length: usize,
+    min_entropy_bits: f64,
+    breach_filter: Option<Arc<BreachFilter>>,
+    /// Why the enabled breach filter could not be loaded
+    breach_filter_error: Option<String>,
+}
 
 impl PassphraseStrengthPolicy {
-    fn validate(&self, passphrase: &str) -> Result<()> {
-        if passphrase.len() < 12 {
-            return Err(IgniteError::InvalidOperation {
-                operation: "validate_passphrase".to_string(),
-                reason: "Passphrase must be at least 12 characters long".to_string(),
-            });
+    pub const DEFAULT_MIN_ENTROPY_BITS: f64 = 50.0;
+
+    pub fn new() -> Self {
+        let config = PassphraseConfig::default();
+        Self {
+            min_length: config.min_length,
+            max_length: config.max_length,
+            min_entropy_bits: config.min_entropy_bits,
+            breach_filter: None,
+            breach_filter_error: None,
         }
+    }
 
-        if passphrase.len() > 256 {
-            return Err(IgniteError::InvalidOperation {
-                operation: "validate_passphrase".to_string(),
-                reason: "Passphrase must be less than 256 characters".to_string(),
-            });
+    /// Policy with `config`'s rules, loading the installed breach filter if enabled
+    pub fn from_config(config: &PassphraseConfig) -> Self {
+        let policy = Self {
+            min_length: config.min_length,
+            max_length: config.max_length,
+            min_entropy_bits: config.min_entropy_bits,
+            breach_filter: None,
+            breach_filter_error: None,
+        };
+        if config.breach_filter {
+            policy.with_installed_breach_filter()
+        } else {
+            policy
         }
+    }
 
-        let has_upper = passphrase.chars().any(|c| c.is_uppercase());
-        let has_lower = passphrase.chars().any(|c| c.is_lowercase());
-        let has_digit = passphrase.chars().any(|c| c.is_ascii_digit());
-        let has_special = passphrase.chars().any(|c| !c.is_alphanumeric());
+    pub fn with_min_entropy_bits(mut self, bits: f64) -> Self {
+        self.min_entropy_bits = bits;
+        self
+    }
 
-        let diversity = [has_upper, has_lower, has_digit, has_special]
-            .iter()
-            .filter(|&&b| b)
-            .count();
+    pub fn with_breach_filter(mut self, filter: BreachFilter) -> Self {
+        self.breach_filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// Attach the vault's installed breach filter, if one is installed
+    ///
+    /// An installed filter that cannot be read makes every passphrase fail.
+    pub fn with_installed_breach_filter(self) -> Self {
+        self.with_loaded_breach_filter(BreachFilter::load_installed())
+    }
+
+    fn with_loaded_breach_filter(mut self, loaded: Result<Option<BreachFilter>>) -> Self {
+        match loaded {
+            Ok(Some(filter)) => self.with_breach_filter(filter),
+            Ok(None) => self,
+            Err(e) => {
+                logging::log_event(&format!("breach filter not loaded: {}", e));
+                self.breach_filter_error = Some(e.to_string());
+                self
+            }
+        }
+    }
+
+    fn breach_filter_unavailable(&self) -> Option<Verdict> {
+        self.breach_filter_error.as_ref().map(|error| {
+            Verdict::deny(
+                self.name(),
+                "breach_filter_unavailable",
+                format!(
+                    "Breach filter is enabled but could not be read ({}); reinstall it or set passphrase.breach_filter = false",
+                    error
+                ),
+            )
+        })
+    }
+
+    pub fn min_entropy_bits(&self) -> f64 {
+        self.min_entropy_bits
+    }
+
+    fn check(&self, passphrase: &str) -> Vec<Verdict> {
+        let mut verdicts = Vec::new();
+        let mut deny =
+            |code, message: String| verdicts.push(Verdict::deny(self.name(), code, message));
+        let normalized = strength::normalize(passphrase);
+        let length = normalized.chars().count();
 
-        if diversity < 3 {
-            return Err(IgniteError::InvalidOperation {
-                operation: "validate_passphrase".to_string(),
-                reason: "Passphrase must contain at least three of: uppercase, lowercase, digits, special characters".to_string(),
-            });
+        if length < self.min_length {
+            deny(
+                "too_short",
+                format!(
+                    "Passphrase must be at least {} characters long",
+                    self.min_length
+                ),
+            );
         }
 
-        if is_common_password(passphrase) {
-            return Err(IgniteError::InvalidOperation {
-                operation: "validate_passphrase".to_string(),
-                reason: "Common password detected. Please choose a unique passphrase".to_string(),
-            });
+        if length > self.max_length {
+            deny(
+                "too_long",
+                format!("Passphrase must be at most {} characters", self.max_length),
+            );
         }
 
-        let injection_patterns = ["$(", "`", ";", "&", "|", "\n", "\r", "\0"];
-        if injection_patterns
-            .iter()
-            .any(|pat| passphrase.contains(pat))
+        if normalized.chars().any(char::is_control) {
+            deny(
+                "control_characters",
+                "Passphrase must not contain control characters".to_string(),
+            );
+        }
+
+        if let Some(unavailable) = self.breach_filter_unavailable() {
+            deny(unavailable.code, unavailable.message);
+        }
+        if self
+            .breach_filter
+            .as_ref()
+            .is_some_and(|filter| filter.contains(&normalized))
         {
-            return Err(IgniteError::InvalidOperation {
-                operation: "validate_passphrase".to_string(),
-                reason: "Passphrase contains potentially dangerous shell characters".to_string(),
-            });
+            deny(
+                "breached",
+                "Passphrase appears in a breached-password corpus. Please choose a unique passphrase"
+                    .to_string(),
+            );
         }
 
-        Ok(())
+        let estimate = strength::estimate(&normalized);
+        if estimate.entropy_bits < self.min_entropy_bits {
+            let mut message = format!(
+                "Passphrase is too guessable (~{:.0} bits of entropy, {:.0} required)",
+                estimate.entropy_bits, self.min_entropy_bits
+            );
+            if !estimate.patterns.is_empty() {
+                message.push_str(&format!("; found {}", estimate.describe_patterns()));
+            }
+            deny("low_entropy", message);
+        }
+
+        verdicts
+    }
+}
+
+impl Default for PassphraseStrengthPolicy {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -233,82 +801,128 @@ impl Policy for PassphraseStrengthPolicy {
         "passphrase_strength"
     }
 
-    fn validate_passphrase(&self, key_type: KeyType, passphrase: &str) -> Result<()> {
+    fn check_passphrase(&self, key_type: KeyType, passphrase: &str) -> Vec<Verdict> {
         if key_type.is_ignition_key() {
-            self.validate(passphrase)
+            self.check(passphrase)
         } else {
-            Ok(())
+            Vec::new()
         }
     }
+    fn check_setup(&self) -> Vec<Verdict> {
+        self.breach_filter_unavailable().into_iter().collect()
+    }
 }
 
-fn is_common_password(passphrase: &str) -> bool {
-    let common_passwords = [
-        "password",
-        "123456",
-        "password123",
-        "admin",
-        "qwerty",
-        "letmein",
-        "welcome",
-        "monkey",
-        "1234567890",
-        "abc123",
-    ];
+/// Rejects passphrases used by any of the last `depth` keys of a lineage.
+#[derive(Debug, Clone)]
+pub struct PassphraseReusePolicy {
+    depth: usize,
+}
 
-    let lower = passphrase.to_lowercase();
-    common_passwords
-        .iter()
-        .any(|candidate| lower.contains(candidate))
+impl PassphraseReusePolicy {
+    pub fn new(depth: usize) -> Self {
+        Self { depth }
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::ignite::authority::{AuthorityKey, KeyFormat, KeyMaterial};
-    use ed25519_dalek::{SecretKey, SigningKey};
-    use hub::random_ext::rand::{rng, Rng};
+impl Default for PassphraseReusePolicy {
+    fn default() -> Self {
+        Self::new(5)
+    }
+}
+
+impl Policy for PassphraseReusePolicy {
+    fn name(&self) -> &'static str {
+        "passphrase_reuse"
+    }
 
-    fn sample_key(key_type: KeyType) -> AuthorityKey {
-        let mut random = rng();
-        let secret_bytes: [u8; 32] = random.random();
-        let secret_key = SecretKey::from(secret_bytes);
-        let signing_key = SigningKey::from(&secret_key);
-        let public_key = signing_key.verifying_key().to_bytes().to_vec();
-        let private_key = Some(signing_key.to_bytes().to_vec());
-        let material = KeyMaterial::new(public_key, private_key, KeyFormat::Ed25519);
+    fn check_key_passphrase(&self, key: &AuthorityKey, passphrase: &str) -> Vec<Verdict> {
+        if !key.key_type().is_ignition_key() {
+            return Vec::new();
+        }
 
-        AuthorityKey::new(material, key_type, None, None).unwrap()
+        match storage::load_passphrase_history(&key.lineage_id()) {
+            Ok(history) if history.contains_recent(passphrase, self.depth) => {
+                vec![Verdict::deny(
+                    self.name(),
+                    "reused",
+                    format!(
+                        "Passphrase was used by one of the last {} keys in this lineage",
+                        self.depth
+                    ),
+                )]
+            }
+            Ok(_) => Vec::new(),
+            Err(e) => vec![Verdict::deny(
+                self.name(),
+                "history_unavailable",
+                format!("Passphrase history could not be read: {}", e),
+            )],
+        }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ignite::test_support::new_key;
 
     #[test]
     fn expiration_policy_sets_defaults() {
         let policy = ExpirationPolicy::default();
-        let mut distro_key = sample_key(KeyType::Distro);
+        let mut distro_key = new_key(KeyType::Distro);
         policy.apply_key_defaults(&mut distro_key).unwrap();
         assert!(distro_key.metadata().expiration().is_some());
 
-        let master_key = sample_key(KeyType::Master);
+        let master_key = new_key(KeyType::Master);
         assert!(master_key.metadata().expiration().is_none());
     }
 
     #[test]
     fn expiration_policy_rejects_expired_keys() {
         let policy = ExpirationPolicy::default();
-        let mut ignition_key = sample_key(KeyType::Ignition);
+        let mut ignition_key = new_key(KeyType::Ignition);
         ignition_key
             .metadata_mut()
             .set_expiration(Some(Utc::now() - Duration::hours(1)));
 
-        assert!(policy.validate_key(&ignition_key).is_err());
+        let verdicts = policy.check_key(&ignition_key);
+        assert_eq!(verdicts.len(), 1);
+        assert_eq!(verdicts[0].outcome, Outcome::Deny);
+        assert_eq!(verdicts[0].code, "expired");
+    }
+
+    #[test]
+    fn expiration_policy_warns_before_expiry() {
+        let engine = PolicyEngine::with_defaults();
+        let mut distro_key = new_key(KeyType::Distro);
+        engine.apply_key_defaults(&mut distro_key).unwrap();
+        assert_eq!(engine.evaluate_key(&distro_key).outcome(), Outcome::Allow);
+
+        // Default distro window is 10% of the 7-day lifetime, so 12 hours out warns
+        let metadata = distro_key.metadata_mut();
+        metadata.creation_time = Utc::now() - Duration::days(6) - Duration::hours(12);
+        metadata.set_expiration(Some(Utc::now() + Duration::hours(12)));
+        let report = engine.validate_key(&distro_key).unwrap();
+        assert_eq!(report.outcome(), Outcome::Warn);
+        let warning = report.warnings().next().unwrap();
+        assert_eq!(
+            (warning.policy, warning.code),
+            ("expiration", "expiring_soon")
+        );
+        assert!(warning.message.contains("expires in 11 hours"));
     }
 
     #[test]
     fn passphrase_policy_enforces_rules() {
         let engine = PolicyEngine::with_defaults();
         assert!(engine
-            .validate_passphrase(KeyType::Ignition, "MySecure123!Pass")
+            .validate_passphrase(KeyType::Ignition, "Harbor-Velvet-93-Quill")
             .is_ok());
+        // Three character classes no longer make up for dictionary words
+        assert!(engine
+            .validate_passphrase(KeyType::Ignition, "MySecure123!Pass")
+            .is_err());
         assert!(engine
             .validate_passphrase(KeyType::Ignition, "short")
             .is_err());
@@ -317,6 +931,141 @@ mod tests {
             .is_err());
     }
 
+    #[test]
+    fn engine_reports_every_denial() {
+        let engine = PolicyEngine::with_defaults();
+        let report = engine.evaluate_passphrase(KeyType::Ignition, "abc\t");
+        let codes: Vec<_> = report.denials().map(|verdict| verdict.code).collect();
+        assert_eq!(codes, ["too_short", "control_characters", "low_entropy"]);
+        // Policies with nothing to say still show up as allowed
+        assert!(report
+            .verdicts
+            .iter()
+            .any(|verdict| verdict.policy == "expiration" && verdict.outcome == Outcome::Allow));
+
+        match report.into_result("validate_passphrase") {
+            Err(IgniteError::PolicyViolation { violations, .. }) => {
+                assert_eq!(violations.len(), 3)
+            }
+            other => panic!("expected policy violation, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn passphrase_policy_scores_entropy_and_breaches() {
+        let policy = PassphraseStrengthPolicy::new();
+        // Length alone no longer passes; shell metacharacters no longer fail
+        let codes = |policy: &PassphraseStrengthPolicy, passphrase: &str| {
+            policy
+                .check(passphrase)
+                .iter()
+                .map(|verdict| verdict.code)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(codes(&policy, "Password1990Password1990"), ["low_entropy"]);
+        assert_eq!(codes(&policy, "qwertyuiop1234567890"), ["low_entropy"]);
+        assert_eq!(codes(&policy, "vT9;qLm2&xWp\n"), ["control_characters"]);
+        assert!(codes(&policy, "vT9;qLm2&xWp").is_empty());
+
+        let mut filter = BreachFilter::with_capacity(10, 0.001);
+        filter.insert("vT9;qLm2&xWp");
+        let policy = policy.with_breach_filter(filter);
+        assert_eq!(codes(&policy, "vT9;qLm2&xWp"), ["breached"]);
+    }
+
+    #[test]
+    fn unreadable_breach_filter_fails_closed() {
+        let policy = PassphraseStrengthPolicy::new().with_loaded_breach_filter(Err(
+            IgniteError::crypto_error("load_breach_filter", "bad magic"),
+        ));
+        let verdicts = policy.check("vT9;qLm2&xWp");
+        assert_eq!(verdicts.len(), 1);
+        assert_eq!(verdicts[0].code, "breach_filter_unavailable");
+
+        let mut engine = PolicyEngine::new();
+        engine.register_policy(policy);
+        assert!(engine.check_setup().is_denied());
+        assert!(engine
+            .evaluate_passphrase(KeyType::Ignition, "vT9;qLm2&xWp")
+            .is_denied());
+        assert!(PassphraseStrengthPolicy::new().check_setup().is_empty());
+    }
+
+    #[test]
+    fn engine_follows_policy_config() {
+        let mut config = PolicyConfig::default();
+        config.policies.passphrase_reuse = false;
+        config.expiration.days.distro = Some(2);
+        config.expiration.days.master = Some(90);
+        config.passphrase.min_length = 20;
+        config.proofs.ttl_hours = 48;
+
+        let engine = PolicyEngine::from_config(&config);
+        assert_eq!(
+            engine.policy_names(),
+            vec!["expiration", "passphrase_strength"]
+        );
+        assert_eq!(engine.proof_ttl(), Duration::hours(48));
+
+        let mut master_key = new_key(KeyType::Master);
+        engine.apply_key_defaults(&mut master_key).unwrap();
+        let expiration = master_key.metadata().expiration().unwrap();
+        assert_eq!(
+            expiration - master_key.metadata().creation_time,
+            Duration::days(90)
+        );
+
+        assert!(engine
+            .validate_passphrase(KeyType::Ignition, "Harbor-Velvet-93-Quill")
+            .is_ok());
+        assert!(engine
+            .validate_passphrase(KeyType::Ignition, "Harbor-Velvet-93")
+            .is_err());
+    }
+
+    #[test]
+    fn explain_key_dry_runs_every_phase() {
+        let engine = PolicyEngine::with_defaults();
+        let key = new_key(KeyType::Distro);
+        let explanation = engine.explain_key(&key, Some("short"));
+
+        // The caller's key is untouched; the explained copy carries the defaults
+        assert!(key.metadata().expiration().is_none());
+        assert!(explanation.key.metadata().expiration().is_some());
+
+        let step = |phase: Phase, policy: &str| {
+            explanation
+                .steps
+                .iter()
+                .find(|step| step.phase == phase && step.policy == policy)
+                .unwrap()
+        };
+        let defaults = &step(Phase::ApplyKeyDefaults, "expiration").defaults;
+        assert_eq!(defaults.len(), 1);
+        assert_eq!(defaults[0].0, "expiration");
+        assert!(step(Phase::ApplyKeyDefaults, "passphrase_strength")
+            .defaults
+            .is_empty());
+        assert_eq!(
+            step(Phase::ValidateKey, "expiration").outcome,
+            Some(Outcome::Allow)
+        );
+        assert_eq!(
+            step(Phase::ValidatePassphrase, "passphrase_strength").outcome,
+            Some(Outcome::Deny)
+        );
+        assert_eq!(explanation.outcome(), Outcome::Deny);
+
+        // Without a passphrase that phase is skipped rather than judged
+        let explanation = engine.explain_key(&key, None);
+        assert!(explanation
+            .steps
+            .iter()
+            .filter(|step| step.phase == Phase::ValidatePassphrase)
+            .all(|step| step.outcome.is_none()));
+        assert_eq!(explanation.outcome(), Outcome::Allow);
+    }
+
     #[test]
     fn engine_allows_policy_registration() {
         struct NoOpPolicy;
@@ -329,7 +1078,7 @@ mod tests {
         let mut engine = PolicyEngine::new();
         engine.register_policy(NoOpPolicy);
 
-        let key = sample_key(KeyType::Master);
+        let key = new_key(KeyType::Master);
         assert!(engine.validate_key(&key).is_ok());
     }
 }
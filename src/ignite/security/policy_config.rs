@@ -0,0 +1,376 @@
+//! Policy configuration file.
+//!
+//! `PolicyEngine::load` builds its policies from TOML layered over the
+//! built-in defaults: first `policy.toml` under `config_root()` (operator
+//! defaults), then `metadata/policy.toml` in the vault (vault overrides). Any
+//! key left out of a layer keeps the value from the layer below, and unknown
+//! keys are rejected so a typo cannot silently fall back to a default.
+//!
+//! ```toml
+//! [policies]
+//! passphrase_reuse = false
+//!
+//! [expiration.days]
+//! ignition = 14
+//!
+//! [expiration.warning_days]
+//! distro = 2
+//!
+//! [passphrase]
+//! min_entropy_bits = 60
+//!
+//! [proofs]
+//! ttl_hours = 48
+//!
+//! [approvals]
+//! skull = 2
+//! master = 2
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use hub::data_ext::serde::{Deserialize, Serialize};
+
+use super::policy::PassphraseStrengthPolicy;
+use crate::ignite::authority::approvals::{APPROVAL_TTL_HOURS, DEFAULT_APPROVAL_THRESHOLD};
+use crate::ignite::authority::proofs::PROOF_TTL_HOURS;
+use crate::ignite::authority::renewal::RENEWAL_WINDOW_HOURS;
+use crate::ignite::authority::KeyType;
+use crate::ignite::error::{IgniteError, Result};
+use crate::ignite::utils;
+
+/// Effective policy settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PolicyConfig {
+    pub policies: EnabledPolicies,
+    pub expiration: ExpirationConfig,
+    pub passphrase: PassphraseConfig,
+    pub proofs: ProofConfig,
+    pub approvals: ApprovalConfig,
+}
+
+/// Which built-in policies `PolicyEngine` registers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EnabledPolicies {
+    pub expiration: bool,
+    pub passphrase_strength: bool,
+    pub passphrase_reuse: bool,
+}
+
+/// Expiry windows per key tier
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExpirationConfig {
+    /// Lifetime applied to new keys of each tier; unset or 0 never expires
+    pub days: TierDays,
+    /// Share of a key's lifetime before expiry in which it is flagged
+    pub warning_fraction: f64,
+    /// Fixed warning windows that replace `warning_fraction` for a tier
+    pub warning_days: TierDays,
+}
+
+/// Optional day count per key tier
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TierDays {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skull: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub master: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignition: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distro: Option<u32>,
+}
+
+/// Passphrase rules for Skull, Ignition and Distro keys
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PassphraseConfig {
+    /// Bounds in characters after NFKC normalization
+    pub min_length: usize,
+    pub max_length: usize,
+    pub min_entropy_bits: f64,
+    /// Reject passphrases found in the installed breach filter
+    pub breach_filter: bool,
+    /// How many previous passphrases of a lineage may not be reused
+    pub reuse_depth: usize,
+}
+
+/// Authority proof lifetimes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProofConfig {
+    pub ttl_hours: u32,
+    /// Proofs this close to expiry are picked up by `proof renew`
+    pub renewal_window_hours: u32,
+}
+
+/// M-of-N approval thresholds for Skull and Master operations
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ApprovalConfig {
+    /// Approvals required to create, rotate or revoke a Skull key, and to add
+    /// approvers once the registry has a quorum; 0 disables (explicit opt-out)
+    pub skull: usize,
+    /// Approvals required to create, rotate or revoke a Master key; 0 disables
+    pub master: usize,
+    /// Lifetime of operation requests and the approvals attached to them
+    pub ttl_hours: u32,
+}
+
+impl Default for EnabledPolicies {
+    fn default() -> Self {
+        Self {
+            expiration: true,
+            passphrase_strength: true,
+            passphrase_reuse: true,
+        }
+    }
+}
+
+impl Default for ExpirationConfig {
+    fn default() -> Self {
+        Self {
+            days: TierDays {
+                ignition: Some(30),
+                distro: Some(7),
+                ..Default::default()
+            },
+            warning_fraction: 0.1,
+            warning_days: TierDays::default(),
+        }
+    }
+}
+
+impl Default for PassphraseConfig {
+    fn default() -> Self {
+        Self {
+            min_length: 12,
+            max_length: 256,
+            min_entropy_bits: PassphraseStrengthPolicy::DEFAULT_MIN_ENTROPY_BITS,
+            breach_filter: true,
+            reuse_depth: 5,
+        }
+    }
+}
+
+impl Default for ProofConfig {
+    fn default() -> Self {
+        Self {
+            ttl_hours: PROOF_TTL_HOURS as u32,
+            renewal_window_hours: RENEWAL_WINDOW_HOURS as u32,
+        }
+    }
+}
+
+impl Default for ApprovalConfig {
+    fn default() -> Self {
+        Self {
+            skull: DEFAULT_APPROVAL_THRESHOLD,
+            master: DEFAULT_APPROVAL_THRESHOLD,
+            ttl_hours: APPROVAL_TTL_HOURS as u32,
+        }
+    }
+}
+
+impl ApprovalConfig {
+    /// Approvals required for operations on `key_type` keys
+    pub fn threshold(&self, key_type: KeyType) -> usize {
+        match key_type {
+            KeyType::Skull => self.skull,
+            KeyType::Master => self.master,
+            _ => 0,
+        }
+    }
+}
+
+impl TierDays {
+    pub fn get(&self, key_type: KeyType) -> Option<u32> {
+        match key_type {
+            KeyType::Skull => self.skull,
+            KeyType::Master => self.master,
+            KeyType::Repo => self.repo,
+            KeyType::Ignition => self.ignition,
+            KeyType::Distro => self.distro,
+        }
+    }
+}
+
+impl PolicyConfig {
+    /// Policy files consulted by `load`, lowest precedence first
+    pub fn search_paths() -> Vec<PathBuf> {
+        vec![utils::policy_config_path(), utils::policy_path()]
+    }
+
+    /// Merge every existing file from `search_paths` over the defaults
+    pub fn load() -> Result<Self> {
+        let layers: Vec<PathBuf> = Self::search_paths()
+            .into_iter()
+            .filter(|path| path.exists())
+            .collect();
+        Self::from_layers(&layers)
+    }
+
+    /// Merge `layers` in order over the defaults and validate the result
+    pub fn from_layers(layers: &[PathBuf]) -> Result<Self> {
+        let mut merged = toml::Value::try_from(Self::default())
+            .map_err(|e| policy_error(format!("cannot encode defaults: {}", e)))?;
+        for path in layers {
+            merge(&mut merged, read_layer(path)?);
+        }
+
+        let config: Self = merged
+            .try_into()
+            .map_err(|e| policy_error(format!("{}", e)))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string_pretty(self).map_err(|e| policy_error(e.to_string()))
+    }
+
+    /// Reject values that parse but cannot work together
+    pub fn validate(&self) -> Result<()> {
+        let expiration = &self.expiration;
+        if !(0.0..=1.0).contains(&expiration.warning_fraction) {
+            return Err(policy_error(
+                "expiration.warning_fraction must be between 0 and 1",
+            ));
+        }
+        for key_type in KeyType::all() {
+            if let (Some(warning), Some(days)) = (
+                expiration.warning_days.get(key_type),
+                expiration.days.get(key_type),
+            ) {
+                if days > 0 && warning >= days {
+                    return Err(policy_error(format!(
+                        "expiration.warning_days.{} must be shorter than expiration.days.{}",
+                        key_type, key_type
+                    )));
+                }
+            }
+        }
+
+        let passphrase = &self.passphrase;
+        if passphrase.min_length == 0 || passphrase.min_length > passphrase.max_length {
+            return Err(policy_error(
+                "passphrase.min_length must be between 1 and passphrase.max_length",
+            ));
+        }
+        if !passphrase.min_entropy_bits.is_finite() || passphrase.min_entropy_bits < 0.0 {
+            return Err(policy_error(
+                "passphrase.min_entropy_bits must be a non-negative number",
+            ));
+        }
+
+        if self.proofs.ttl_hours == 0 {
+            return Err(policy_error("proofs.ttl_hours must be at least 1"));
+        }
+        if self.proofs.renewal_window_hours >= self.proofs.ttl_hours {
+            return Err(policy_error(
+                "proofs.renewal_window_hours must be shorter than proofs.ttl_hours",
+            ));
+        }
+
+        if self.approvals.ttl_hours == 0 {
+            return Err(policy_error("approvals.ttl_hours must be at least 1"));
+        }
+
+        Ok(())
+    }
+}
+
+fn read_layer(path: &Path) -> Result<toml::Value> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| IgniteError::io_error("read_policy", path.to_path_buf(), e))?;
+    toml::from_str(&text).map_err(|e| policy_error(format!("{}: {}", path.display(), e)))
+}
+
+/// Overlay `layer` onto `base`, recursing into tables
+fn merge(base: &mut toml::Value, layer: toml::Value) {
+    match (base, layer) {
+        (toml::Value::Table(base), toml::Value::Table(layer)) => {
+            for (key, value) in layer {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, layer) => *base = layer,
+    }
+}
+
+fn policy_error(reason: impl Into<String>) -> IgniteError {
+    IgniteError::InvalidOperation {
+        operation: "load_policy".to_string(),
+        reason: reason.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_layer(dir: &TempDir, name: &str, text: &str) -> PathBuf {
+        let path = dir.path().join(name);
+        fs::write(&path, text).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_layers_merge_over_defaults() {
+        let dir = TempDir::new().unwrap();
+        let operator = write_layer(
+            &dir,
+            "operator.toml",
+            "[expiration.days]\nignition = 14\nmaster = 365\n\n[proofs]\nttl_hours = 48\n",
+        );
+        let vault = write_layer(
+            &dir,
+            "vault.toml",
+            "[policies]\npassphrase_reuse = false\n\n[expiration.days]\nignition = 10\n",
+        );
+
+        let config = PolicyConfig::from_layers(&[operator, vault]).unwrap();
+        assert_eq!(config.expiration.days.ignition, Some(10));
+        assert_eq!(config.expiration.days.master, Some(365));
+        assert_eq!(config.expiration.days.distro, Some(7));
+        assert_eq!(config.proofs.ttl_hours, 48);
+        assert!(!config.policies.passphrase_reuse);
+        assert!(config.policies.passphrase_strength);
+        assert_eq!(config.approvals.skull, DEFAULT_APPROVAL_THRESHOLD);
+        assert_eq!(config.approvals.master, DEFAULT_APPROVAL_THRESHOLD);
+
+        // Effective policy round-trips through its TOML rendering
+        let rendered = write_layer(&dir, "rendered.toml", &config.to_toml().unwrap());
+        assert_eq!(PolicyConfig::from_layers(&[rendered]).unwrap(), config);
+    }
+
+    #[test]
+    fn test_rejects_unknown_keys_and_bad_values() {
+        let dir = TempDir::new().unwrap();
+        for text in [
+            "[passphrase]\nmin_entropy = 60\n",
+            "[expiration]\nwarning_fraction = 1.5\n",
+            "[expiration.days]\ndistro = -1\n",
+            "[expiration.warning_days]\ndistro = 7\n",
+            "[proofs]\nttl_hours = 6\n",
+            "[approvals]\nttl_hours = 0\n",
+            "not toml",
+        ] {
+            let path = write_layer(&dir, "bad.toml", text);
+            assert!(PolicyConfig::from_layers(&[path]).is_err(), "{}", text);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Shamir secret sharing over GF(2^8).
+//!
+//! Each secret byte becomes the constant term of its own random polynomial of
+//! degree `threshold - 1`; share `x` (1..=255) holds the polynomial values at
+//! `x`. Any `threshold` shares recover the secret by Lagrange interpolation
+//! at zero, while fewer reveal nothing about it. Field arithmetic uses the
+//! AES polynomial `x^8 + x^4 + x^3 + x + 1`.
+
+use hub::random_ext::rand::{rng, Rng};
+use zeroize::Zeroizing;
+
+use crate::ignite::error::{IgniteError, Result};
+
+/// One share: its evaluation point and one field element per secret byte
+#[derive(Debug, Clone)]
+pub struct Share {
+    pub index: u8,
+    pub data: Zeroizing<Vec<u8>>,
+}
+
+/// Split `secret` into `shares` shares, any `threshold` of which recover it
+pub fn split(secret: &[u8], shares: u8, threshold: u8) -> Result<Vec<Share>> {
+    if threshold < 2 || threshold > shares {
+        return Err(IgniteError::InvalidOperation {
+            operation: "split_secret".to_string(),
+            reason: format!(
+                "Threshold must be between 2 and the share count ({}), got {}",
+                shares, threshold
+            ),
+        });
+    }
+
+    let mut random = rng();
+    let mut out: Vec<Share> = (1..=shares)
+        .map(|index| Share {
+            index,
+            data: Zeroizing::new(Vec::with_capacity(secret.len())),
+        })
+        .collect();
+
+    let mut coefficients = Zeroizing::new(vec![0u8; threshold as usize]);
+    for &byte in secret {
+        coefficients[0] = byte;
+        random.fill(&mut coefficients[1..]);
+        for share in &mut out {
+            share.data.push(evaluate(&coefficients, share.index));
+        }
+    }
+    Ok(out)
+}
+
+/// Recover the secret from shares; the caller supplies at least the threshold
+pub fn combine(shares: &[Share]) -> Result<Zeroizing<Vec<u8>>> {
+    let invalid = |reason: &str| IgniteError::InvalidOperation {
+        operation: "combine_shares".to_string(),
+        reason: reason.to_string(),
+    };
+    let Some(first) = shares.first() else {
+        return Err(invalid("No shares supplied"));
+    };
+    if shares.iter().any(|share| share.index == 0) {
+        return Err(invalid("Share index 0 is not valid"));
+    }
+    if shares
+        .iter()
+        .any(|share| share.data.len() != first.data.len())
+    {
+        return Err(invalid("Shares have different lengths"));
+    }
+    for (i, share) in shares.iter().enumerate() {
+        if shares[..i].iter().any(|other| other.index == share.index) {
+            return Err(invalid("The same share was supplied twice"));
+        }
+    }
+
+    // Lagrange basis polynomials evaluated at x = 0
+    let weights: Vec<u8> = shares
+        .iter()
+        .map(|share| {
+            shares
+                .iter()
+                .filter(|other| other.index != share.index)
+                .fold(1, |weight, other| {
+                    mul(weight, div(other.index, other.index ^ share.index))
+                })
+        })
+        .collect();
+
+    let mut secret = Zeroizing::new(vec![0u8; first.data.len()]);
+    for (share, weight) in shares.iter().zip(weights) {
+        for (byte, value) in secret.iter_mut().zip(share.data.iter()) {
+            *byte ^= mul(*value, weight);
+        }
+    }
+    Ok(secret)
+}
+
+/// Horner evaluation of `coefficients` (constant term first) at `x`
+fn evaluate(coefficients: &[u8], x: u8) -> u8 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0, |acc, &coefficient| mul(acc, x) ^ coefficient)
+}
+
+fn mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let carry = a & 0x80;
+        a <<= 1;
+        if carry != 0 {
+            a ^= 0x1b;
+        }
+        b >>= 1;
+    }
+    product
+}
+
+/// `a / b` for nonzero `b`, via `b^254 = b^-1`
+fn div(a: u8, b: u8) -> u8 {
+    let mut inverse = 1;
+    for _ in 0..254 {
+        inverse = mul(inverse, b);
+    }
+    mul(a, inverse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_any_threshold_subset_recovers_secret() {
+        let secret: Vec<u8> = (0..32).map(|i| i * 7 + 3).collect();
+        let shares = split(&secret, 5, 3).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        for subset in [[0, 1, 2], [4, 2, 0], [1, 3, 4]] {
+            let picked: Vec<Share> = subset.iter().map(|&i| shares[i].clone()).collect();
+            assert_eq!(combine(&picked).unwrap().as_slice(), secret.as_slice());
+        }
+
+        // Below threshold the result is unrelated to the secret
+        assert_ne!(combine(&shares[..2]).unwrap().as_slice(), secret.as_slice());
+        assert!(combine(&[shares[0].clone(), shares[0].clone()]).is_err());
+        assert!(split(&secret, 3, 4).is_err());
+        assert!(split(&secret, 3, 1).is_err());
+    }
+
+    #[test]
+    fn test_field_inverse() {
+        for b in 1..=255u8 {
+            assert_eq!(mul(div(1, b), b), 1);
+        }
+    }
+}
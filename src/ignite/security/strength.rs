@@ -0,0 +1,572 @@
+//! Entropy estimation for passphrases.
+//!
+//! Passphrases are NFKC-normalized and measured in characters. The estimate is
+//! the cheapest way to cover the whole string with either brute-forced
+//! characters or known patterns: dictionary words (including common passwords
+//! and the generator wordlist, with case and leet variants), keyboard walks,
+//! repeats, character sequences and dates. A passphrase built from patterns
+//! therefore scores what an attacker enumerating those patterns would need,
+//! not what its length and character classes suggest.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+
+use unicode_normalization::UnicodeNormalization;
+
+/// Wordlist shared with the passphrase generator (BIP-39 English, 2048 words)
+pub const WORDLIST: &str = include_str!("wordlist.txt");
+
+/// Most common passwords, most frequent first
+const COMMON_PASSWORDS: &[&str] = &[
+    "123456",
+    "password",
+    "12345678",
+    "qwerty",
+    "123456789",
+    "12345",
+    "1234",
+    "111111",
+    "1234567",
+    "dragon",
+    "123123",
+    "baseball",
+    "abc123",
+    "football",
+    "monkey",
+    "letmein",
+    "696969",
+    "shadow",
+    "master",
+    "666666",
+    "qwertyuiop",
+    "123321",
+    "mustang",
+    "1234567890",
+    "michael",
+    "654321",
+    "superman",
+    "1qaz2wsx",
+    "7777777",
+    "121212",
+    "000000",
+    "qazwsx",
+    "123qwe",
+    "killer",
+    "trustno1",
+    "jordan",
+    "jennifer",
+    "zxcvbnm",
+    "asdfgh",
+    "hunter",
+    "buster",
+    "soccer",
+    "harley",
+    "batman",
+    "andrew",
+    "tigger",
+    "sunshine",
+    "iloveyou",
+    "charlie",
+    "robert",
+    "thomas",
+    "hockey",
+    "ranger",
+    "daniel",
+    "starwars",
+    "klaster",
+    "112233",
+    "george",
+    "computer",
+    "michelle",
+    "jessica",
+    "pepper",
+    "zaq1zaq1",
+    "freedom",
+    "secret",
+    "summer",
+    "winter",
+    "spring",
+    "autumn",
+    "princess",
+    "admin",
+    "administrator",
+    "welcome",
+    "login",
+    "passw0rd",
+    "password1",
+    "password123",
+    "changeme",
+    "default",
+    "root",
+    "toor",
+    "pass",
+    "secure",
+    "security",
+    "private",
+    "access",
+    "hello",
+    "love",
+    "god",
+    "sex",
+    "money",
+    "flower",
+    "cheese",
+    "ignite",
+    "ignition",
+    "padlock",
+    "padlokk",
+    "correcthorsebatterystaple",
+];
+
+const KEYBOARD_ROWS: &[&str] = &[
+    "1234567890-=",
+    "qwertyuiop[]\\",
+    "asdfghjkl;'",
+    "zxcvbnm,./",
+];
+const SHIFTED_DIGITS: &str = "!@#$%^&*()_+";
+
+const MIN_TOKEN_LEN: usize = 3;
+const MAX_DICTIONARY_LEN: usize = 32;
+const MIN_YEAR: u32 = 1900;
+const MAX_YEAR: u32 = 2099;
+
+/// Kind of guessable structure found in a passphrase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternKind {
+    Dictionary,
+    KeyboardWalk,
+    Repeat,
+    Sequence,
+    Date,
+}
+
+impl PatternKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PatternKind::Dictionary => "dictionary word",
+            PatternKind::KeyboardWalk => "keyboard walk",
+            PatternKind::Repeat => "repeat",
+            PatternKind::Sequence => "sequence",
+            PatternKind::Date => "date",
+        }
+    }
+}
+
+impl fmt::Display for PatternKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A pattern the estimate charged for instead of brute force
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternMatch {
+    pub kind: PatternKind,
+    pub token: String,
+    /// Estimated guesses needed to hit this token by enumerating its pattern
+    pub guesses: f64,
+    start: usize,
+    end: usize,
+}
+
+impl PatternMatch {
+    fn new(kind: PatternKind, chars: &[char], start: usize, end: usize, guesses: f64) -> Self {
+        Self {
+            kind,
+            token: chars[start..end].iter().collect(),
+            guesses: guesses.max(1.0),
+            start,
+            end,
+        }
+    }
+
+    fn bits(&self) -> f64 {
+        self.guesses.log2()
+    }
+}
+
+/// Strength estimate for a normalized passphrase
+#[derive(Debug, Clone)]
+pub struct StrengthEstimate {
+    /// Length in characters after normalization
+    pub length: usize,
+    pub entropy_bits: f64,
+    /// Patterns used by the cheapest covering, in order
+    pub patterns: Vec<PatternMatch>,
+}
+
+impl StrengthEstimate {
+    /// Human-readable list of the patterns found, e.g. `dictionary word 'horse'`
+    pub fn describe_patterns(&self) -> String {
+        self.patterns
+            .iter()
+            .map(|m| format!("{} '{}'", m.kind, m.token))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Unicode NFKC normalization applied before any passphrase check or hash input
+pub fn normalize(passphrase: &str) -> String {
+    passphrase.nfkc().collect()
+}
+
+/// Estimate the entropy of `passphrase` in bits
+pub fn estimate(passphrase: &str) -> StrengthEstimate {
+    let chars: Vec<char> = normalize(passphrase).chars().collect();
+    let char_bits = (cardinality(&chars) as f64).log2();
+
+    let mut matches: Vec<Vec<PatternMatch>> = vec![Vec::new(); chars.len()];
+    for m in find_patterns(&chars, char_bits) {
+        matches[m.start].push(m);
+    }
+
+    // Cheapest covering of each prefix; `via` remembers the pattern that reached it
+    let mut best = vec![f64::INFINITY; chars.len() + 1];
+    let mut via: Vec<Option<&PatternMatch>> = vec![None; chars.len() + 1];
+    best[0] = 0.0;
+    for start in 0..chars.len() {
+        let brute = best[start] + char_bits;
+        if brute < best[start + 1] {
+            best[start + 1] = brute;
+            via[start + 1] = None;
+        }
+        for m in &matches[start] {
+            let cost = best[start] + m.bits();
+            if cost < best[m.end] {
+                best[m.end] = cost;
+                via[m.end] = Some(m);
+            }
+        }
+    }
+
+    let mut patterns = Vec::new();
+    let mut end = chars.len();
+    while end > 0 {
+        match via[end] {
+            Some(m) => {
+                patterns.push(m.clone());
+                end = m.start;
+            }
+            None => end -= 1,
+        }
+    }
+    patterns.reverse();
+
+    StrengthEstimate {
+        length: chars.len(),
+        entropy_bits: best[chars.len()],
+        patterns,
+    }
+}
+
+/// Size of the brute-force alphabet implied by the character classes present
+fn cardinality(chars: &[char]) -> usize {
+    let mut size = 0;
+    if chars.iter().any(|c| c.is_ascii_lowercase()) {
+        size += 26;
+    }
+    if chars.iter().any(|c| c.is_ascii_uppercase()) {
+        size += 26;
+    }
+    if chars.iter().any(|c| c.is_ascii_digit()) {
+        size += 10;
+    }
+    if chars
+        .iter()
+        .any(|c| c.is_ascii() && !c.is_ascii_alphanumeric())
+    {
+        size += 33;
+    }
+    if chars.iter().any(|c| !c.is_ascii()) {
+        size += 100;
+    }
+    size.max(2)
+}
+
+fn find_patterns(chars: &[char], char_bits: f64) -> Vec<PatternMatch> {
+    let mut found = Vec::new();
+    dictionary_matches(chars, &mut found);
+    keyboard_matches(chars, &mut found);
+    repeat_matches(chars, char_bits, &mut found);
+    sequence_matches(chars, &mut found);
+    date_matches(chars, &mut found);
+    found
+}
+
+/// Guess rank per dictionary entry; wordlist entries all cost the list size
+fn dictionary() -> &'static HashMap<&'static str, usize> {
+    static DICTIONARY: OnceLock<HashMap<&'static str, usize>> = OnceLock::new();
+    DICTIONARY.get_or_init(|| {
+        let words: Vec<&str> = WORDLIST.lines().collect();
+        let mut ranks: HashMap<&str, usize> = words.iter().map(|w| (*w, words.len())).collect();
+        for (rank, word) in COMMON_PASSWORDS.iter().enumerate() {
+            ranks.insert(word, rank + 1);
+        }
+        ranks
+    })
+}
+
+fn unleet(c: char) -> Option<char> {
+    match c {
+        '0' => Some('o'),
+        '1' | '!' => Some('i'),
+        '3' => Some('e'),
+        '4' | '@' => Some('a'),
+        '5' | '$' => Some('s'),
+        '7' => Some('t'),
+        _ => None,
+    }
+}
+
+fn dictionary_matches(chars: &[char], found: &mut Vec<PatternMatch>) {
+    let dictionary = dictionary();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let unleeted: Vec<char> = lower.iter().map(|c| unleet(*c).unwrap_or(*c)).collect();
+
+    for start in 0..chars.len() {
+        let limit = chars.len().min(start + MAX_DICTIONARY_LEN);
+        for end in (start + MIN_TOKEN_LEN)..=limit {
+            let plain: String = lower[start..end].iter().collect();
+            let variants = [(plain, 1.0), (unleeted[start..end].iter().collect(), 2.0)];
+            for (candidate, leet_factor) in variants {
+                if let Some(rank) = dictionary.get(candidate.as_str()) {
+                    let guesses = *rank as f64 * case_variations(&chars[start..end]) * leet_factor;
+                    found.push(PatternMatch::new(
+                        PatternKind::Dictionary,
+                        chars,
+                        start,
+                        end,
+                        guesses,
+                    ));
+                    break;
+                }
+            }
+        }
+    }
+}
+
+fn case_variations(token: &[char]) -> f64 {
+    let upper = token.iter().filter(|c| c.is_uppercase()).count();
+    if upper == 0 {
+        1.0
+    } else if upper == token.len() || (upper == 1 && token[0].is_uppercase()) {
+        2.0
+    } else {
+        2f64.powi(upper.min(token.len()) as i32)
+    }
+}
+
+fn key_position(c: char) -> Option<(usize, usize)> {
+    let c = c.to_ascii_lowercase();
+    let c = SHIFTED_DIGITS
+        .chars()
+        .position(|s| s == c)
+        .and_then(|i| KEYBOARD_ROWS[0].chars().nth(i))
+        .unwrap_or(c);
+    KEYBOARD_ROWS
+        .iter()
+        .enumerate()
+        .find_map(|(row, keys)| keys.chars().position(|k| k == c).map(|col| (row, col)))
+}
+
+fn keyboard_matches(chars: &[char], found: &mut Vec<PatternMatch>) {
+    let keys: usize = KEYBOARD_ROWS.iter().map(|row| row.len()).sum();
+    runs(chars, 4, |a, b| match (key_position(a), key_position(b)) {
+        (Some((row_a, col_a)), Some((row_b, col_b))) if row_a == row_b => {
+            Some(col_b as isize - col_a as isize)
+        }
+        _ => None,
+    })
+    .into_iter()
+    .for_each(|(start, end)| {
+        let guesses = (keys * 2 * (end - start)) as f64 * case_variations(&chars[start..end]);
+        found.push(PatternMatch::new(
+            PatternKind::KeyboardWalk,
+            chars,
+            start,
+            end,
+            guesses,
+        ));
+    });
+}
+
+fn sequence_matches(chars: &[char], found: &mut Vec<PatternMatch>) {
+    runs(chars, MIN_TOKEN_LEN, |a, b| {
+        let same_class = (a.is_ascii_digit() && b.is_ascii_digit())
+            || (a.is_ascii_alphabetic() && b.is_ascii_alphabetic());
+        same_class.then(|| b.to_ascii_lowercase() as isize - a.to_ascii_lowercase() as isize)
+    })
+    .into_iter()
+    .for_each(|(start, end)| {
+        let alphabet = if chars[start].is_ascii_digit() {
+            10
+        } else {
+            26
+        };
+        let guesses = (alphabet * 2 * (end - start)) as f64;
+        found.push(PatternMatch::new(
+            PatternKind::Sequence,
+            chars,
+            start,
+            end,
+            guesses,
+        ));
+    });
+}
+
+/// Maximal runs of at least `min_len` where every step is +1 or every step is -1
+fn runs(
+    chars: &[char],
+    min_len: usize,
+    step: impl Fn(char, char) -> Option<isize>,
+) -> Vec<(usize, usize)> {
+    let mut found = Vec::new();
+    let mut start = 0;
+    while start + 1 < chars.len() {
+        let direction = match step(chars[start], chars[start + 1]) {
+            Some(d) if d == 1 || d == -1 => d,
+            _ => {
+                start += 1;
+                continue;
+            }
+        };
+        let mut end = start + 2;
+        while end < chars.len() && step(chars[end - 1], chars[end]) == Some(direction) {
+            end += 1;
+        }
+        if end - start >= min_len {
+            found.push((start, end));
+        }
+        start = end - 1;
+    }
+    found
+}
+
+fn repeat_matches(chars: &[char], char_bits: f64, found: &mut Vec<PatternMatch>) {
+    for start in 0..chars.len() {
+        for unit in 1..=(chars.len() - start) / 2 {
+            let block = &chars[start..start + unit];
+            let mut end = start + unit;
+            while end + unit <= chars.len() && &chars[end..end + unit] == block {
+                end += unit;
+            }
+
+            let count = (end - start) / unit;
+            if count < 2 || end - start < MIN_TOKEN_LEN {
+                continue;
+            }
+            let guesses = 2f64.powf(char_bits * unit as f64) * count as f64;
+            found.push(PatternMatch::new(
+                PatternKind::Repeat,
+                chars,
+                start,
+                end,
+                guesses,
+            ));
+        }
+    }
+}
+
+fn date_matches(chars: &[char], found: &mut Vec<PatternMatch>) {
+    let years = (MAX_YEAR - MIN_YEAR + 1) as f64;
+    for start in 0..chars.len() {
+        for len in [4, 6, 8] {
+            let end = start + len;
+            if end > chars.len() || !chars[start..end].iter().all(|c| c.is_ascii_digit()) {
+                continue;
+            }
+            let digits: String = chars[start..end].iter().collect();
+            let guesses = match len {
+                4 if is_year(&digits) => years,
+                6 if is_short_date(&digits) => 100.0 * 366.0,
+                8 if is_long_date(&digits) => years * 366.0,
+                _ => continue,
+            };
+            found.push(PatternMatch::new(
+                PatternKind::Date,
+                chars,
+                start,
+                end,
+                guesses,
+            ));
+        }
+    }
+}
+
+fn number(digits: &str) -> u32 {
+    digits.parse().unwrap_or(0)
+}
+
+fn is_year(digits: &str) -> bool {
+    (MIN_YEAR..=MAX_YEAR).contains(&number(digits))
+}
+
+fn is_day_month(day: &str, month: &str) -> bool {
+    (1..=31).contains(&number(day)) && (1..=12).contains(&number(month))
+}
+
+/// yymmdd, ddmmyy or mmddyy
+fn is_short_date(digits: &str) -> bool {
+    let (a, b, c) = (&digits[0..2], &digits[2..4], &digits[4..6]);
+    is_day_month(c, b) || is_day_month(a, b) || is_day_month(b, a)
+}
+
+/// yyyymmdd, ddmmyyyy or mmddyyyy
+fn is_long_date(digits: &str) -> bool {
+    (is_year(&digits[0..4]) && is_day_month(&digits[6..8], &digits[4..6]))
+        || (is_year(&digits[4..8])
+            && (is_day_month(&digits[0..2], &digits[2..4])
+                || is_day_month(&digits[2..4], &digits[0..2])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(passphrase: &str) -> Vec<PatternKind> {
+        estimate(passphrase)
+            .patterns
+            .iter()
+            .map(|m| m.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_patterns_lower_entropy() {
+        let random = estimate("vT9#qLm2!xWp");
+        assert_eq!(random.length, 12);
+        assert!(random.patterns.is_empty());
+        assert!(random.entropy_bits > 75.0);
+
+        assert_eq!(kinds("P@ssw0rd"), vec![PatternKind::Dictionary]);
+        assert_eq!(kinds("qwerty"), vec![PatternKind::Dictionary]);
+        assert_eq!(kinds("asdfghjkl;"), vec![PatternKind::KeyboardWalk]);
+        assert_eq!(kinds("abcdefgh"), vec![PatternKind::Sequence]);
+        assert_eq!(kinds("xyzxyzxyz"), vec![PatternKind::Repeat]);
+        assert_eq!(kinds("19900517"), vec![PatternKind::Date]);
+
+        for weak in ["Password1990", "aaaaaaaaaaaaaaaa", "qwertyuiop1234"] {
+            let score = estimate(weak);
+            assert!(
+                score.entropy_bits < 30.0,
+                "{} scored {}",
+                weak,
+                score.entropy_bits
+            );
+        }
+    }
+
+    #[test]
+    fn test_length_counts_normalized_characters() {
+        // Fullwidth forms fold to ASCII under NFKC
+        assert_eq!(normalize("ＰＡＳＳ１"), "PASS1");
+        assert_eq!(estimate("ＰＡＳＳ１").length, 5);
+        assert_eq!(estimate("äöüß").length, 4);
+    }
+}
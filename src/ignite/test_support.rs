@@ -0,0 +1,132 @@
+//! Shared fixtures for unit tests.
+//!
+//! Tests that touch the vault run under `#[serial]` inside a
+//! `VaultEnvironment`, which points `IGNITE_DATA_ROOT` at a fresh temporary
+//! directory and sets a vault passphrase for the life of the test.
+
+use std::env;
+
+use hub::time_ext::chrono::{Duration, Utc};
+use tempfile::TempDir;
+
+use crate::ignite::authority::chain::{AuthorityKey, KeyMaterial, KeyType};
+use crate::ignite::authority::proof_archive::{self, RetirementEvent};
+use crate::ignite::authority::proofs::{AuthorityClaim, ProofPair};
+use crate::ignite::authority::storage;
+use crate::ignite::security::passphrase::{PASSPHRASE_ENV, VAULT_PASSPHRASE_ENV};
+
+/// Vault passphrase every `VaultEnvironment` seals keys under
+pub const VAULT_PASSPHRASE: &str = "test vault passphrase";
+
+/// Temporary vault root; the environment is restored when dropped
+pub struct VaultEnvironment {
+    _temp_dir: TempDir,
+}
+
+impl VaultEnvironment {
+    pub fn new() -> Self {
+        let temp_dir = TempDir::new().unwrap();
+        env::set_var("IGNITE_DATA_ROOT", temp_dir.path());
+        env::set_var(VAULT_PASSPHRASE_ENV, VAULT_PASSPHRASE);
+        Self {
+            _temp_dir: temp_dir,
+        }
+    }
+}
+
+impl Drop for VaultEnvironment {
+    fn drop(&mut self) {
+        env::remove_var("IGNITE_DATA_ROOT");
+        env::remove_var(PASSPHRASE_ENV);
+        env::remove_var(VAULT_PASSPHRASE_ENV);
+    }
+}
+
+/// Fresh unlinked Ed25519 key of `key_type`
+pub fn new_key(key_type: KeyType) -> AuthorityKey {
+    AuthorityKey::new(KeyMaterial::generate_ed25519(), key_type, None, None).unwrap()
+}
+
+/// Fresh key of `key_type` linked both ways to `parent`
+pub fn new_child(key_type: KeyType, parent: &mut AuthorityKey) -> AuthorityKey {
+    let mut key = new_key(key_type);
+    key.set_parent(parent.fingerprint().clone()).unwrap();
+    parent.add_child(key.fingerprint().clone()).unwrap();
+    key
+}
+
+/// Linked chain of fresh keys, one per entry of `key_types`, root first
+pub fn new_chain(key_types: &[KeyType]) -> Vec<AuthorityKey> {
+    let mut keys: Vec<AuthorityKey> = Vec::with_capacity(key_types.len());
+    for &key_type in key_types {
+        let key = match keys.last_mut() {
+            Some(parent) => new_child(key_type, parent),
+            None => new_key(key_type),
+        };
+        keys.push(key);
+    }
+    keys
+}
+
+/// Persist skull → master → repo → ignition and return them in order
+pub fn seed_vault() -> Vec<AuthorityKey> {
+    save_chain(&[
+        KeyType::Skull,
+        KeyType::Master,
+        KeyType::Repo,
+        KeyType::Ignition,
+    ])
+}
+
+/// Persist a fresh chain of `key_types` (see `new_chain`) and return it
+pub fn save_chain(key_types: &[KeyType]) -> Vec<AuthorityKey> {
+    let keys = new_chain(key_types);
+    for key in &keys {
+        storage::save_key(key).unwrap();
+    }
+    keys
+}
+
+/// Proof pair for `parent -> child` signed by both ends, expiring after `ttl`
+pub fn issue_proof(
+    parent: &AuthorityKey,
+    child: &AuthorityKey,
+    purpose: &str,
+    ttl: Duration,
+) -> ProofPair {
+    issue_proof_signed_by(parent, parent, child, purpose, ttl)
+}
+
+/// Proof pair for `parent -> child` whose claim is signed by `signer`
+pub fn issue_proof_signed_by(
+    signer: &AuthorityKey,
+    parent: &AuthorityKey,
+    child: &AuthorityKey,
+    purpose: &str,
+    ttl: Duration,
+) -> ProofPair {
+    let claim = AuthorityClaim::new(
+        parent.fingerprint().clone(),
+        child.fingerprint().clone(),
+        purpose,
+    );
+    ProofPair::issue(
+        &claim,
+        &signer.key_material().signing_key().unwrap(),
+        &child.key_material().signing_key().unwrap(),
+        Utc::now() + ttl,
+    )
+    .unwrap()
+}
+
+/// Activate a proof for every parent → child edge of `keys`, each expiring after `ttl`
+pub fn activate_proofs(keys: &[AuthorityKey], purpose: &str, ttl: Duration) {
+    for edge in keys.windows(2) {
+        proof_archive::activate(
+            &issue_proof(&edge[0], &edge[1], purpose, ttl),
+            &edge[1].fingerprint().short(),
+            RetirementEvent::Superseded,
+        )
+        .unwrap();
+    }
+}
@@ -47,6 +47,16 @@ pub fn proofs_dir() -> PathBuf {
     data_root().join("proofs")
 }
 
+/// Path to superseded proofs within vault
+pub fn proof_archive_dir() -> PathBuf {
+    proofs_dir().join("archive")
+}
+
+/// Path to the active-proof index within vault
+pub fn proof_index_path() -> PathBuf {
+    proofs_dir().join("index.json")
+}
+
 /// Path to manifests directory within vault
 pub fn manifests_dir() -> PathBuf {
     data_root().join("manifests")
@@ -57,6 +67,51 @@ pub fn metadata_dir() -> PathBuf {
     data_root().join("metadata")
 }
 
+/// Path to the append-only audit log within vault
+pub fn audit_log_path() -> PathBuf {
+    data_root().join("audit.jsonl")
+}
+
+/// Path to the record of the audit log's newest entry within vault
+pub fn audit_head_path() -> PathBuf {
+    data_root().join("audit.head")
+}
+
+/// Path to per-lineage passphrase histories within vault
+pub fn passphrase_history_dir() -> PathBuf {
+    metadata_dir().join("passphrase_history")
+}
+
+/// Path to the vault's policy overrides
+pub fn policy_path() -> PathBuf {
+    metadata_dir().join("policy.toml")
+}
+
+/// Path to the operator-wide policy file under the config root
+pub fn policy_config_path() -> PathBuf {
+    config_root().join("policy.toml")
+}
+
+/// Path to the installed breached-password filter within vault
+pub fn breach_filter_path() -> PathBuf {
+    metadata_dir().join("breach_filter.bin")
+}
+
+/// Path to operation requests and their approvals within vault
+pub fn approvals_dir() -> PathBuf {
+    data_root().join("approvals")
+}
+
+/// Path to the registry of approver public keys within vault
+pub fn approvers_path() -> PathBuf {
+    metadata_dir().join("approvers.json")
+}
+
+/// Path to tombstone registry within vault
+pub fn tombstones_dir() -> PathBuf {
+    metadata_dir().join("tombstones")
+}
+
 /// Ensure all vault directories exist
 pub fn ensure_vault_dirs() -> std::io::Result<()> {
     std::fs::create_dir_all(keys_dir())?;